@@ -22,6 +22,8 @@
 use super::account_restriction_flags_dto::*;
 use super::generator_utils::*;
 use super::unresolved_address_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for an account address restriction transaction.
 #[derive(Debug, Clone)]
@@ -68,6 +70,31 @@ impl AccountAddressRestrictionTransactionBodyBuilder {
         AccountAddressRestrictionTransactionBodyBuilder { restriction_flags, restriction_additions, restriction_deletions } // TransactionBody
     }
 
+    /// Runs the stateless checks of the node on the body.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the body is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = vec![];
+        if !(self.has_flag(AccountRestrictionFlagsDto::ADDRESS)
+            && !self.has_flag(AccountRestrictionFlagsDto::MOSAIC_ID)
+            && !self.has_flag(AccountRestrictionFlagsDto::TRANSACTION_TYPE)) {
+            failures.push(ValidationFailure::Failure_RestrictionAccount_Invalid_Restriction_Flags);
+        }
+        let values: Vec<_> = self.restriction_additions.iter().chain(self.restriction_deletions.iter()).collect();
+        if values.iter().enumerate().any(|(i, value)| values[..i].contains(value)) {
+            failures.push(ValidationFailure::Failure_RestrictionAccount_Redundant_Modification);
+        }
+        if values.len() > config.max_account_restriction_values {
+            failures.push(ValidationFailure::Failure_RestrictionAccount_Modification_Count_Exceeded);
+        }
+        failures
+    }
+
+    fn has_flag(&self, flag: AccountRestrictionFlagsDto) -> bool {
+        self.restriction_flags.contains(&flag)
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -29,6 +29,9 @@ use super::network_type_dto::*;
 use super::signature_dto::*;
 use super::timestamp_dto::*;
 use super::transaction_builder::*;
+use super::transaction_helper::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for a non-embedded account key link transaction.
 #[derive(Debug, Clone)]
@@ -76,6 +79,14 @@ impl AccountKeyLinkTransactionBuilder {
         self.body.link_action = link_action;   // MARKER1 AttributeKind.CUSTOM
     }
 
+    /// Runs the stateless checks of the node on the transaction.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.super_object.validate(config)
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -99,3 +110,20 @@ impl AccountKeyLinkTransactionBuilder {
     }
 }
 
+impl TransactionHelper for AccountKeyLinkTransactionBuilder {
+    fn box_clone(&self) -> Box<dyn TransactionHelper> {
+        Box::new((*self).clone())
+    }
+
+    fn get_size(&self) -> usize {
+        self.get_size()
+    }
+
+    fn serializer(&self) -> Vec<u8> {
+        self.serializer()
+    }
+
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.validate(config)
+    }
+}
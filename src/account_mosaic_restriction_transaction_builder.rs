@@ -29,7 +29,10 @@ use super::network_type_dto::*;
 use super::signature_dto::*;
 use super::timestamp_dto::*;
 use super::transaction_builder::*;
+use super::transaction_helper::*;
 use super::unresolved_mosaic_id_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for a non-embedded account mosaic restriction transaction.
 #[derive(Debug, Clone)]
@@ -77,6 +80,16 @@ impl AccountMosaicRestrictionTransactionBuilder {
     pub fn get_restriction_deletions(&self) -> Vec<UnresolvedMosaicIdDto> {
         self.body.restriction_deletions.clone()
     }
+    /// Runs the stateless checks of the node on the transaction.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = self.super_object.validate(config);
+        failures.append(&mut self.body.validate(config));
+        failures
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -100,3 +113,20 @@ impl AccountMosaicRestrictionTransactionBuilder {
     }
 }
 
+impl TransactionHelper for AccountMosaicRestrictionTransactionBuilder {
+    fn box_clone(&self) -> Box<dyn TransactionHelper> {
+        Box::new((*self).clone())
+    }
+
+    fn get_size(&self) -> usize {
+        self.get_size()
+    }
+
+    fn serializer(&self) -> Vec<u8> {
+        self.serializer()
+    }
+
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.validate(config)
+    }
+}
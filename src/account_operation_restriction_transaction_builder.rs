@@ -23,13 +23,15 @@ use super::account_operation_restriction_transaction_body_builder::*;
 use super::account_restriction_flags_dto::*;
 use super::amount_dto::*;
 use super::entity_type_dto::*;
-use super::entity_type_dto::*;
 use super::generator_utils::*;
 use super::key_dto::*;
 use super::network_type_dto::*;
 use super::signature_dto::*;
 use super::timestamp_dto::*;
 use super::transaction_builder::*;
+use super::transaction_helper::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for a non-embedded account operation restriction transaction.
 #[derive(Debug, Clone)]
@@ -77,6 +79,16 @@ impl AccountOperationRestrictionTransactionBuilder {
     pub fn get_restriction_deletions(&self) -> Vec<EntityTypeDto> {
         self.body.restriction_deletions.clone()
     }
+    /// Runs the stateless checks of the node on the transaction.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = self.super_object.validate(config);
+        failures.append(&mut self.body.validate(config));
+        failures
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -100,3 +112,20 @@ impl AccountOperationRestrictionTransactionBuilder {
     }
 }
 
+impl TransactionHelper for AccountOperationRestrictionTransactionBuilder {
+    fn box_clone(&self) -> Box<dyn TransactionHelper> {
+        Box::new((*self).clone())
+    }
+
+    fn get_size(&self) -> usize {
+        self.get_size()
+    }
+
+    fn serializer(&self) -> Vec<u8> {
+        self.serializer()
+    }
+
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.validate(config)
+    }
+}
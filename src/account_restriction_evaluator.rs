@@ -0,0 +1,230 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use std::fmt;
+
+use super::account_restriction_flags_dto::*;
+use super::account_restrictions_builder::*;
+use super::account_restrictions_info_builder::*;
+use super::address_dto::*;
+use super::aggregate_transaction_body_builder::*;
+use super::embedded_transaction_builder::*;
+use super::entity_type_dto::*;
+use super::mosaic_id_dto::*;
+use super::secret_lock_transaction_body_builder::*;
+use super::transaction_builder::*;
+use super::transfer_transaction_body_builder::*;
+use super::validation_failure::*;
+
+/// Account restriction rule blocking a transaction.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AccountRestrictionViolation {
+    /// Account does not allow interactions with the counterparty.
+    AddressInteraction { address: AddressDto, restriction_flags: Vec<AccountRestrictionFlagsDto>, counterparty_address: AddressDto },
+    /// Account does not allow receiving the mosaic.
+    MosaicTransfer { address: AddressDto, restriction_flags: Vec<AccountRestrictionFlagsDto>, mosaic_id: MosaicIdDto },
+    /// Account does not allow sending the transaction type.
+    OperationType { address: AddressDto, restriction_flags: Vec<AccountRestrictionFlagsDto>, entity_type: EntityTypeDto },
+}
+
+impl AccountRestrictionViolation {
+    /// Gets the address of the account owning the rule.
+    ///
+    /// # Returns
+    /// A AddressDto.
+    pub fn get_address(&self) -> AddressDto {
+        match self {
+            AccountRestrictionViolation::AddressInteraction { address, .. }
+            | AccountRestrictionViolation::MosaicTransfer { address, .. }
+            | AccountRestrictionViolation::OperationType { address, .. } => *address,
+        }
+    }
+
+    /// Gets the flags of the rule.
+    ///
+    /// # Returns
+    /// A list of AccountRestrictionFlagsDto.
+    pub fn get_restriction_flags(&self) -> Vec<AccountRestrictionFlagsDto> {
+        match self {
+            AccountRestrictionViolation::AddressInteraction { restriction_flags, .. }
+            | AccountRestrictionViolation::MosaicTransfer { restriction_flags, .. }
+            | AccountRestrictionViolation::OperationType { restriction_flags, .. } => restriction_flags.clone(),
+        }
+    }
+
+    /// Gets the failure the node reports for the violation.
+    ///
+    /// # Returns
+    /// A ValidationFailure.
+    pub fn get_failure(&self) -> ValidationFailure {
+        match self {
+            AccountRestrictionViolation::AddressInteraction { .. } => ValidationFailure::Failure_RestrictionAccount_Address_Interaction_Prohibited,
+            AccountRestrictionViolation::MosaicTransfer { .. } => ValidationFailure::Failure_RestrictionAccount_Mosaic_Transfer_Prohibited,
+            AccountRestrictionViolation::OperationType { .. } => ValidationFailure::Failure_RestrictionAccount_Operation_Type_Prohibited,
+        }
+    }
+}
+
+impl fmt::Display for AccountRestrictionViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let address = to_hex(&self.get_address().0);
+        let restriction_flags = self.get_restriction_flags();
+        match self {
+            AccountRestrictionViolation::AddressInteraction { counterparty_address, .. } => {
+                write!(f, "restriction {:?} of account {} rejects address {}", restriction_flags, address, to_hex(&counterparty_address.0))
+            }
+            AccountRestrictionViolation::MosaicTransfer { mosaic_id, .. } => {
+                write!(f, "restriction {:?} of account {} rejects mosaic {:016X}", restriction_flags, address, mosaic_id.get_mosaic_id())
+            }
+            AccountRestrictionViolation::OperationType { entity_type, .. } => {
+                write!(f, "restriction {:?} of account {} rejects transaction type {:?}", restriction_flags, address, entity_type)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AccountRestrictionViolation {}
+
+/// Evaluates account restrictions against transactions, like the account restriction validators of the node.
+#[derive(Debug, Clone, Copy)]
+pub struct AccountRestrictionEvaluator<'a> {
+    /// Restrictions of the accounts involved in the evaluated transactions.
+    /// Accounts without restrictions allow everything.
+    pub restrictions: &'a [AccountRestrictionsBuilder],
+}
+
+impl<'a> AccountRestrictionEvaluator<'a> {
+    /// Checks that the sender and the recipient allow interacting with each other.
+    /// sender_address: Address of the sender.
+    /// recipient_address: Address of the recipient.
+    /// # Returns
+    /// The blocking rule, or None when the interaction is allowed.
+    pub fn check_address_interaction(&self, sender_address: &AddressDto, recipient_address: &AddressDto) -> Option<AccountRestrictionViolation> {
+        if sender_address == recipient_address {
+            return None;
+        }
+        let incoming_flags = AccountRestrictionFlagsDto::ADDRESS.get_value();
+        let outgoing_flags = incoming_flags | AccountRestrictionFlagsDto::OUTGOING.get_value();
+        let get_addresses = |info: &AccountRestrictionsInfoBuilder| info.get_address_restrictions().map(|values| values.get_restriction_values());
+        if let Some(restriction_flags) = self.find_blocking_rule(sender_address, outgoing_flags, recipient_address, get_addresses) {
+            return Some(AccountRestrictionViolation::AddressInteraction { address: *sender_address, restriction_flags, counterparty_address: *recipient_address });
+        }
+        self.find_blocking_rule(recipient_address, incoming_flags, sender_address, get_addresses).map(|restriction_flags| {
+            AccountRestrictionViolation::AddressInteraction { address: *recipient_address, restriction_flags, counterparty_address: *sender_address }
+        })
+    }
+
+    /// Checks that the recipient allows receiving a mosaic.
+    /// recipient_address: Address of the recipient.
+    /// mosaic_id: Id of the mosaic.
+    /// # Returns
+    /// The blocking rule, or None when the mosaic is allowed.
+    pub fn check_mosaic_transfer(&self, recipient_address: &AddressDto, mosaic_id: &MosaicIdDto) -> Option<AccountRestrictionViolation> {
+        let flags = AccountRestrictionFlagsDto::MOSAIC_ID.get_value();
+        let get_mosaic_ids = |info: &AccountRestrictionsInfoBuilder| info.get_mosaic_id_restrictions().map(|values| values.get_restriction_values());
+        self.find_blocking_rule(recipient_address, flags, mosaic_id, get_mosaic_ids).map(|restriction_flags| {
+            AccountRestrictionViolation::MosaicTransfer { address: *recipient_address, restriction_flags, mosaic_id: *mosaic_id }
+        })
+    }
+
+    /// Checks that the sender allows sending a transaction type.
+    /// sender_address: Address of the sender.
+    /// entity_type: Type of the transaction.
+    /// # Returns
+    /// The blocking rule, or None when the transaction type is allowed.
+    pub fn check_operation_type(&self, sender_address: &AddressDto, entity_type: EntityTypeDto) -> Option<AccountRestrictionViolation> {
+        let flags = AccountRestrictionFlagsDto::TRANSACTION_TYPE.get_value() | AccountRestrictionFlagsDto::OUTGOING.get_value();
+        let get_entity_types = |info: &AccountRestrictionsInfoBuilder| info.get_transaction_type_restrictions().map(|values| values.get_restriction_values());
+        self.find_blocking_rule(sender_address, flags, &entity_type, get_entity_types).map(|restriction_flags| {
+            AccountRestrictionViolation::OperationType { address: *sender_address, restriction_flags, entity_type }
+        })
+    }
+
+    /// Evaluates a transaction and the inner transactions of aggregates.
+    /// Recipients and mosaics given as namespace aliases are skipped and must be resolved by the caller.
+    /// payload: Serialized top-level transaction.
+    /// # Returns
+    /// The rules blocking the transaction, empty when it is allowed.
+    pub fn evaluate(&self, payload: &[u8]) -> Vec<AccountRestrictionViolation> {
+        let mut violations = vec![];
+        let header = TransactionBuilder::from_binary(payload);
+        let signer_address = AddressDto::from_public_key(header.network, &header.signer_public_key);
+        let body = &payload[header.get_size()..];
+        match header._type {
+            EntityTypeDto::AGGREGATE_COMPLETE_TRANSACTION | EntityTypeDto::AGGREGATE_BONDED_TRANSACTION => {
+                violations.extend(self.check_operation_type(&signer_address, header._type));
+                for transaction in AggregateTransactionBodyBuilder::from_binary(body).transactions.iter() {
+                    let transaction_payload = transaction.serializer();
+                    let transaction_header = EmbeddedTransactionBuilder::from_binary(&transaction_payload);
+                    let transaction_signer_address = AddressDto::from_public_key(transaction_header.network, &transaction_header.signer_public_key);
+                    let transaction_body = &transaction_payload[transaction_header.get_size()..];
+                    self.evaluate_body(&mut violations, &transaction_signer_address, transaction_header._type, transaction_body);
+                }
+            }
+            _ => self.evaluate_body(&mut violations, &signer_address, header._type, body),
+        }
+        violations
+    }
+
+    fn evaluate_body(&self, violations: &mut Vec<AccountRestrictionViolation>, signer_address: &AddressDto, entity_type: EntityTypeDto, body: &[u8]) {
+        violations.extend(self.check_operation_type(signer_address, entity_type));
+        match entity_type {
+            EntityTypeDto::TRANSFER_TRANSACTION => {
+                let body = TransferTransactionBodyBuilder::from_binary(body);
+                if let Some(recipient_address) = body.recipient_address.as_address() {
+                    violations.extend(self.check_address_interaction(signer_address, &recipient_address));
+                    for mosaic in body.mosaics.iter() {
+                        if let Some(mosaic_id) = mosaic.get_mosaic_id().as_mosaic_id() {
+                            violations.extend(self.check_mosaic_transfer(&recipient_address, &mosaic_id));
+                        }
+                    }
+                }
+            }
+            EntityTypeDto::SECRET_LOCK_TRANSACTION => {
+                let body = SecretLockTransactionBodyBuilder::from_binary(body);
+                if let Some(recipient_address) = body.recipient_address.as_address() {
+                    violations.extend(self.check_address_interaction(signer_address, &recipient_address));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Finds the rule of an account with the given flags, ignoring the block flag, that rejects a value.
+    /// Rules without values allow everything.
+    fn find_blocking_rule<T: PartialEq>(&self, address: &AddressDto, flags: u16, value: &T, get_values: impl Fn(&AccountRestrictionsInfoBuilder) -> Option<Vec<T>>) -> Option<Vec<AccountRestrictionFlagsDto>> {
+        let block_flag = AccountRestrictionFlagsDto::BLOCK.get_value();
+        let restrictions = self.restrictions.iter().find(|restrictions| restrictions.get_address() == *address)?;
+        restrictions.get_restrictions().iter().find_map(|info| {
+            let restriction_flags = AccountRestrictionFlagsDto::flags_to_int(info.get_restriction_flags());
+            if restriction_flags & !block_flag != flags {
+                return None;
+            }
+            let values = get_values(info).unwrap_or_default();
+            let is_allowed = values.is_empty() || values.contains(value) != (restriction_flags & block_flag != 0);
+            if is_allowed { None } else { Some(info.get_restriction_flags()) }
+        })
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02X}", byte)).collect()
+}
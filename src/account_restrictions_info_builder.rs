@@ -81,7 +81,7 @@ impl AccountRestrictionsInfoBuilder {
     /// # Returns
     /// A Address restrictions.
     pub fn get_address_restrictions(&self) -> Option<AccountRestrictionAddressValueBuilder> {
-        if self.restriction_flags.iter().any(|&i| i == AccountRestrictionFlagsDto::ADDRESS) {
+        if !self.restriction_flags.contains(&AccountRestrictionFlagsDto::ADDRESS) {
             panic!("restrictionFlags is not set to ADDRESS.")
         };
         self.address_restrictions.clone()
@@ -92,7 +92,7 @@ impl AccountRestrictionsInfoBuilder {
     /// # Returns
     /// A Mosaic identifier restrictions.
     pub fn get_mosaic_id_restrictions(&self) -> Option<AccountRestrictionMosaicValueBuilder> {
-        if self.restriction_flags.iter().any(|&i| i == AccountRestrictionFlagsDto::MOSAIC_ID) {
+        if !self.restriction_flags.contains(&AccountRestrictionFlagsDto::MOSAIC_ID) {
             panic!("restrictionFlags is not set to MOSAIC_ID.")
         };
         self.mosaic_id_restrictions.clone()
@@ -103,7 +103,7 @@ impl AccountRestrictionsInfoBuilder {
     /// # Returns
     /// A Transaction type restrictions.
     pub fn get_transaction_type_restrictions(&self) -> Option<AccountRestrictionTransactionTypeValueBuilder> {
-        if self.restriction_flags.iter().any(|&i| i == AccountRestrictionFlagsDto::TRANSACTION_TYPE) {
+        if !self.restriction_flags.contains(&AccountRestrictionFlagsDto::TRANSACTION_TYPE) {
             panic!("restrictionFlags is not set to TRANSACTION_TYPE.")
         };
         self.transaction_type_restrictions.clone()
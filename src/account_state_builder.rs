@@ -23,11 +23,13 @@ use super::account_key_type_flags_dto::*;
 use super::account_state_format_dto::*;
 use super::account_type_dto::*;
 use super::address_dto::*;
+use super::amount_dto::*;
 use super::generator_utils::*;
 use super::height_activity_buckets_builder::*;
 use super::height_dto::*;
 use super::importance_snapshot_builder::*;
 use super::key_dto::*;
+use super::mosaic_id_dto::*;
 use super::mosaic_builder::*;
 use super::pinned_voting_key_builder::*;
 use super::state_header_builder::*;
@@ -204,7 +206,7 @@ impl AccountStateBuilder {
     /// # Returns
     /// A Linked account public key.
     pub fn get_linked_public_key(&self) -> Option<KeyDto> {
-        if self.supplemental_public_keys_mask.iter().any(|&i| i == AccountKeyTypeFlagsDto::LINKED) {
+        if !self.supplemental_public_keys_mask.contains(&AccountKeyTypeFlagsDto::LINKED) {
             panic!("supplementalPublicKeysMask is not set to LINKED.")
         };
         self.linked_public_key.clone()
@@ -215,7 +217,7 @@ impl AccountStateBuilder {
     /// # Returns
     /// A Node public key.
     pub fn get_node_public_key(&self) -> Option<KeyDto> {
-        if self.supplemental_public_keys_mask.iter().any(|&i| i == AccountKeyTypeFlagsDto::NODE) {
+        if !self.supplemental_public_keys_mask.contains(&AccountKeyTypeFlagsDto::NODE) {
             panic!("supplementalPublicKeysMask is not set to NODE.")
         };
         self.node_public_key.clone()
@@ -226,7 +228,7 @@ impl AccountStateBuilder {
     /// # Returns
     /// A Vrf public key.
     pub fn get_vrf_public_key(&self) -> Option<KeyDto> {
-        if self.supplemental_public_keys_mask.iter().any(|&i| i == AccountKeyTypeFlagsDto::VRF) {
+        if !self.supplemental_public_keys_mask.contains(&AccountKeyTypeFlagsDto::VRF) {
             panic!("supplementalPublicKeysMask is not set to VRF.")
         };
         self.vrf_public_key.clone()
@@ -270,6 +272,14 @@ impl AccountStateBuilder {
         self.balances.clone() // ARRAY or FILL_ARRAY
     }
 
+    /// Gets the balance of a mosaic.
+    /// mosaic_id: Id of the mosaic.
+    /// # Returns
+    /// The balance, zero when the account does not hold the mosaic.
+    pub fn get_balance(&self, mosaic_id: &MosaicIdDto) -> AmountDto {
+        self.balances.iter().find(|balance| balance.get_mosaic_id() == *mosaic_id).map_or(AmountDto(0), |balance| balance.get_amount())
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -19,10 +19,15 @@
  * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
  */
 
+use ripemd160::Ripemd160;
+use sha3::{Digest, Sha3_256};
+
 use super::generator_utils::*;
+use super::key_dto::*;
+use super::network_type_dto::*;
 
 /// Address.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct AddressDto(pub [u8; 24]);
 
 impl AddressDto {
@@ -44,6 +49,21 @@ impl AddressDto {
         self.0
     }
 
+    /// Derives the address of an account from its public key.
+    /// network: Network of the account.
+    /// public_key: Public key of the account.
+    /// # Returns
+    /// A `AddressDto`.
+    pub fn from_public_key(network: NetworkTypeDto, public_key: &KeyDto) -> Self {
+        let public_key_hash = Ripemd160::digest(&Sha3_256::digest(&public_key.0));
+        let mut address = [0u8; 24];
+        address[0] = network.get_value();
+        address[1..21].copy_from_slice(&public_key_hash);
+        let checksum = Sha3_256::digest(&address[..21]);
+        address[21..].copy_from_slice(&checksum[..3]);
+        Self(address)
+    }
+
     /// Serializes an type to bytes.
     ///
     /// # Returns
@@ -21,6 +21,7 @@
 
 use super::address_key_value_builder::*;
 use super::generator_utils::*;
+use super::mosaic_restriction_key_dto::*;
 
 /// Binary layout for mosaic address restriction key-value set.
 #[derive(Debug, Clone)]
@@ -57,6 +58,14 @@ impl AddressKeyValueSetBuilder {
         self.keys.clone() // ARRAY or FILL_ARRAY
     }
 
+    /// Gets the value set for a key.
+    /// key: Restriction key.
+    /// # Returns
+    /// The value, or None when the key is not set.
+    pub fn get_value(&self, key: &MosaicRestrictionKeyDto) -> Option<u64> {
+        self.keys.iter().find(|key_value| key_value.get_key() == *key).map(|key_value| key_value.get_value())
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
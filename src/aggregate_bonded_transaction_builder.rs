@@ -22,6 +22,7 @@
 use super::aggregate_transaction_body_builder::*;
 use super::amount_dto::*;
 use super::cosignature_builder::*;
+use super::detached_cosignature_builder::*;
 use super::embedded_transaction_helper::*;
 use super::entity_type_dto::*;
 use super::generator_utils::*;
@@ -31,6 +32,9 @@ use super::network_type_dto::*;
 use super::signature_dto::*;
 use super::timestamp_dto::*;
 use super::transaction_builder::*;
+use super::transaction_helper::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for an aggregate bonded transaction.
 #[derive(Debug, Clone)]
@@ -78,6 +82,80 @@ impl AggregateBondedTransactionBuilder {
     pub fn get_cosignatures(&self) -> Vec<CosignatureBuilder> {
         self.body.cosignatures.clone()
     }
+
+    /// Appends an inner transaction and refreshes the transactions hash.
+    /// Existing cosignatures are dropped since the aggregate hash changes.
+    /// transaction: Embedded transaction.
+    /// # Returns
+    /// An AggregateError if the transaction is an aggregate.
+    pub fn push_transaction(&mut self, transaction: Box<dyn EmbeddedTransactionHelper>) -> Result<(), AggregateError> {
+        self.body.push_transaction(transaction)
+    }
+
+    /// Inserts an inner transaction and refreshes the transactions hash.
+    /// Existing cosignatures are dropped since the aggregate hash changes.
+    /// index: Position of the transaction.
+    /// transaction: Embedded transaction.
+    /// # Returns
+    /// An AggregateError if the index is out of bounds or the transaction is an aggregate.
+    pub fn insert_transaction(&mut self, index: usize, transaction: Box<dyn EmbeddedTransactionHelper>) -> Result<(), AggregateError> {
+        self.body.insert_transaction(index, transaction)
+    }
+
+    /// Replaces an inner transaction and refreshes the transactions hash.
+    /// Existing cosignatures are dropped since the aggregate hash changes.
+    /// index: Position of the transaction.
+    /// transaction: Embedded transaction.
+    /// # Returns
+    /// The replaced transaction or an AggregateError if the index is out of bounds or the transaction is an aggregate.
+    pub fn replace_transaction(&mut self, index: usize, transaction: Box<dyn EmbeddedTransactionHelper>) -> Result<Box<dyn EmbeddedTransactionHelper>, AggregateError> {
+        self.body.replace_transaction(index, transaction)
+    }
+
+    /// Removes an inner transaction and refreshes the transactions hash.
+    /// Existing cosignatures are dropped since the aggregate hash changes.
+    /// index: Position of the transaction.
+    /// # Returns
+    /// The removed transaction or an AggregateError if the index is out of bounds.
+    pub fn remove_transaction(&mut self, index: usize) -> Result<Box<dyn EmbeddedTransactionHelper>, AggregateError> {
+        self.body.remove_transaction(index)
+    }
+
+    /// Replaces all cosignatures, sorting them by signer public key.
+    /// cosignatures: Cosignatures.
+    /// # Returns
+    /// An AggregateError if the aggregate signer or a cosigner appears more than once.
+    pub fn set_cosignatures(&mut self, cosignatures: Vec<CosignatureBuilder>) -> Result<(), AggregateError> {
+        self.body.set_cosignatures(&self.super_object.signer_public_key, cosignatures)
+    }
+    /// Gets the hash of the aggregate transaction, which is what cosigners sign.
+    /// generation_hash_seed: Network generation hash seed.
+    /// # Returns
+    /// A Transaction hash.
+    pub fn get_transaction_hash(&self, generation_hash_seed: &Hash256Dto) -> Hash256Dto {
+        TransactionBuilder::calculate_transaction_hash(&self.serializer(), generation_hash_seed)
+    }
+
+    /// Attaches detached cosignatures after checking them against the aggregate hash.
+    /// generation_hash_seed: Network generation hash seed.
+    /// cosignatures: Detached cosignatures to attach.
+    /// # Returns
+    /// An AggregateError if a cosignature is invalid or redundant.
+    pub fn add_cosignatures(&mut self, generation_hash_seed: &Hash256Dto, cosignatures: &[DetachedCosignatureBuilder]) -> Result<(), AggregateError> {
+        let parent_hash = self.get_transaction_hash(generation_hash_seed);
+        self.body.add_cosignatures(&parent_hash, &self.super_object.signer_public_key, cosignatures)
+    }
+
+    /// Runs the stateless checks of the node on the transaction.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = self.super_object.validate(config);
+        failures.append(&mut self.body.validate(config, &self.super_object.signer_public_key));
+        failures
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -101,3 +179,20 @@ impl AggregateBondedTransactionBuilder {
     }
 }
 
+impl TransactionHelper for AggregateBondedTransactionBuilder {
+    fn box_clone(&self) -> Box<dyn TransactionHelper> {
+        Box::new((*self).clone())
+    }
+
+    fn get_size(&self) -> usize {
+        self.get_size()
+    }
+
+    fn serializer(&self) -> Vec<u8> {
+        self.serializer()
+    }
+
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.validate(config)
+    }
+}
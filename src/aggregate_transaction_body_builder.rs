@@ -19,11 +19,48 @@
  * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
  */
 
+use std::fmt;
+
 use super::cosignature_builder::*;
+use super::detached_cosignature_builder::*;
 use super::embedded_transaction_builder::*;
 use super::embedded_transaction_helper::*;
+use super::entity_type_dto::*;
 use super::generator_utils::*;
 use super::hash256_dto::*;
+use super::key_dto::*;
+use super::merkle_hash::*;
+use super::validation_config::*;
+use super::validation_failure::*;
+
+/// Errors raised when modifying an aggregate transaction.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AggregateError {
+    /// Cosignature signs a different aggregate transaction.
+    ParentHashMismatch(KeyDto),
+    /// Cosignature signature does not match the aggregate hash.
+    InvalidSignature(KeyDto),
+    /// Cosigner already signed the aggregate transaction.
+    DuplicateCosignature(KeyDto),
+    /// Aggregate transactions cannot be embedded in an aggregate.
+    NestedAggregate(EntityTypeDto),
+    /// Transaction index is out of bounds.
+    InvalidIndex(usize),
+}
+
+impl fmt::Display for AggregateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AggregateError::ParentHashMismatch(key) => write!(f, "cosignature of {:?} signs a different aggregate", key),
+            AggregateError::InvalidSignature(key) => write!(f, "cosignature of {:?} has an invalid signature", key),
+            AggregateError::DuplicateCosignature(key) => write!(f, "{:?} already signed the aggregate", key),
+            AggregateError::NestedAggregate(entity_type) => write!(f, "{:?} cannot be embedded in an aggregate", entity_type),
+            AggregateError::InvalidIndex(index) => write!(f, "no inner transaction at index {}", index),
+        }
+    }
+}
+
+impl std::error::Error for AggregateError {}
 
 /// Binary layout for an aggregate transaction.
 #[derive(Debug, Clone)]
@@ -85,6 +122,129 @@ impl AggregateTransactionBodyBuilder {
         AggregateTransactionBodyBuilder { transactions_hash, transactions, cosignatures } // TransactionBody
     }
 
+    /// Calculates the merkle root of the inner transaction hashes.
+    /// # Returns
+    /// A transactions hash.
+    pub fn calculate_transactions_hash(&self) -> Hash256Dto {
+        let hashes: Vec<Hash256Dto> = self.transactions.iter()
+            .map(|transaction| EmbeddedTransactionBuilder::calculate_transaction_hash(&transaction.serializer()))
+            .collect();
+        calculate_merkle_hash(&hashes)
+    }
+
+    fn check_embeddable(transaction: &dyn EmbeddedTransactionHelper) -> Result<(), AggregateError> {
+        let header = EmbeddedTransactionBuilder::from_binary(&transaction.serializer());
+        match header._type {
+            EntityTypeDto::AGGREGATE_COMPLETE_TRANSACTION | EntityTypeDto::AGGREGATE_BONDED_TRANSACTION => {
+                Err(AggregateError::NestedAggregate(header._type))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Refreshes the transactions hash after the inner transactions changed.
+    /// Cosignatures sign the previous aggregate hash, so they are dropped.
+    fn on_transactions_changed(&mut self) {
+        self.transactions_hash = self.calculate_transactions_hash();
+        self.cosignatures.clear();
+    }
+
+    /// Appends an inner transaction.
+    /// transaction: Embedded transaction.
+    /// # Returns
+    /// An AggregateError if the transaction is an aggregate.
+    pub fn push_transaction(&mut self, transaction: Box<dyn EmbeddedTransactionHelper>) -> Result<(), AggregateError> {
+        self.insert_transaction(self.transactions.len(), transaction)
+    }
+
+    /// Inserts an inner transaction at a position.
+    /// index: Position of the transaction.
+    /// transaction: Embedded transaction.
+    /// # Returns
+    /// An AggregateError if the index is out of bounds or the transaction is an aggregate.
+    pub fn insert_transaction(&mut self, index: usize, transaction: Box<dyn EmbeddedTransactionHelper>) -> Result<(), AggregateError> {
+        if index > self.transactions.len() {
+            return Err(AggregateError::InvalidIndex(index));
+        }
+        Self::check_embeddable(transaction.as_ref())?;
+        self.transactions.insert(index, transaction);
+        self.on_transactions_changed();
+        Ok(())
+    }
+
+    /// Replaces the inner transaction at a position.
+    /// index: Position of the transaction.
+    /// transaction: Embedded transaction.
+    /// # Returns
+    /// The replaced transaction or an AggregateError if the index is out of bounds or the transaction is an aggregate.
+    pub fn replace_transaction(&mut self, index: usize, transaction: Box<dyn EmbeddedTransactionHelper>) -> Result<Box<dyn EmbeddedTransactionHelper>, AggregateError> {
+        if index >= self.transactions.len() {
+            return Err(AggregateError::InvalidIndex(index));
+        }
+        Self::check_embeddable(transaction.as_ref())?;
+        let replaced = std::mem::replace(&mut self.transactions[index], transaction);
+        self.on_transactions_changed();
+        Ok(replaced)
+    }
+
+    /// Removes the inner transaction at a position.
+    /// index: Position of the transaction.
+    /// # Returns
+    /// The removed transaction or an AggregateError if the index is out of bounds.
+    pub fn remove_transaction(&mut self, index: usize) -> Result<Box<dyn EmbeddedTransactionHelper>, AggregateError> {
+        if index >= self.transactions.len() {
+            return Err(AggregateError::InvalidIndex(index));
+        }
+        let removed = self.transactions.remove(index);
+        self.on_transactions_changed();
+        Ok(removed)
+    }
+
+    /// Replaces all cosignatures, sorting them by signer public key.
+    /// signer_public_key: Public key of the aggregate transaction signer.
+    /// cosignatures: Cosignatures.
+    /// # Returns
+    /// An AggregateError if the aggregate signer or a cosigner appears more than once.
+    pub fn set_cosignatures(&mut self, signer_public_key: &KeyDto, mut cosignatures: Vec<CosignatureBuilder>) -> Result<(), AggregateError> {
+        if cosignatures.iter().any(|c| c.get_signer_public_key() == *signer_public_key) {
+            return Err(AggregateError::DuplicateCosignature(*signer_public_key));
+        }
+        cosignatures.sort_by_key(|c| c.get_signer_public_key());
+        if let Some(pair) = cosignatures.windows(2).find(|pair| pair[0].get_signer_public_key() == pair[1].get_signer_public_key()) {
+            return Err(AggregateError::DuplicateCosignature(pair[0].get_signer_public_key()));
+        }
+        self.cosignatures = cosignatures;
+        Ok(())
+    }
+
+    /// Attaches detached cosignatures, keeping cosignatures sorted by signer public key.
+    /// Either all cosignatures are attached or none is.
+    /// parent_hash: Hash of the aggregate transaction.
+    /// signer_public_key: Public key of the aggregate transaction signer.
+    /// cosignatures: Detached cosignatures to attach.
+    /// # Returns
+    /// An AggregateError if a cosignature is invalid or redundant.
+    pub fn add_cosignatures(&mut self, parent_hash: &Hash256Dto, signer_public_key: &KeyDto, cosignatures: &[DetachedCosignatureBuilder]) -> Result<(), AggregateError> {
+        let mut signers: Vec<KeyDto> = self.cosignatures.iter().map(|c| c.get_signer_public_key()).collect();
+        signers.push(*signer_public_key);
+        for cosignature in cosignatures {
+            let signer = cosignature.get_signer_public_key();
+            if cosignature.get_parent_hash() != *parent_hash {
+                return Err(AggregateError::ParentHashMismatch(signer));
+            }
+            if !cosignature.verify() {
+                return Err(AggregateError::InvalidSignature(signer));
+            }
+            if signers.contains(&signer) {
+                return Err(AggregateError::DuplicateCosignature(signer));
+            }
+            signers.push(signer);
+        }
+        self.cosignatures.extend(cosignatures.iter().map(|c| c.get_cosignature()));
+        self.cosignatures.sort_by_key(|c| c.get_signer_public_key());
+        Ok(())
+    }
+
     /// Serializes an embeded transaction with correct padding.
     /// # Returns
     /// A Serialized embedded transaction.
@@ -113,6 +273,32 @@ impl AggregateTransactionBodyBuilder {
         }
         alignment - size % alignment
     }
+    /// Runs the stateless checks of the node on the body and its inner transactions.
+    /// config: Network limits.
+    /// signer_public_key: Public key of the aggregate signer.
+    /// # Returns
+    /// A list of validation failures, empty when the body is valid.
+    pub fn validate(&self, config: &ValidationConfig, signer_public_key: &KeyDto) -> Vec<ValidationFailure> {
+        let mut failures = vec![];
+        if self.transactions.is_empty() {
+            failures.push(ValidationFailure::Failure_Aggregate_No_Transactions);
+        } else if self.transactions.len() > config.max_transactions_per_aggregate {
+            failures.push(ValidationFailure::Failure_Aggregate_Too_Many_Transactions);
+        }
+        if self.cosignatures.len() > config.max_cosignatures_per_aggregate {
+            failures.push(ValidationFailure::Failure_Aggregate_Too_Many_Cosignatures);
+        }
+        let mut cosigners: Vec<KeyDto> = self.cosignatures.iter().map(|cosignature| cosignature.get_signer_public_key()).collect();
+        cosigners.push(*signer_public_key);
+        if cosigners.iter().enumerate().any(|(i, cosigner)| cosigners[..i].contains(cosigner)) {
+            failures.push(ValidationFailure::Failure_Aggregate_Redundant_Cosignatures);
+        }
+        for transaction in self.transactions.iter() {
+            failures.append(&mut transaction.validate(config));
+        }
+        failures
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -0,0 +1,211 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use super::account_address_restriction_transaction_body_builder::*;
+use super::account_metadata_transaction_body_builder::*;
+use super::account_mosaic_restriction_transaction_body_builder::*;
+use super::address_dto::*;
+use super::address_resolution_statement_builder::*;
+use super::aggregate_transaction_body_builder::*;
+use super::embedded_transaction_builder::*;
+use super::entity_type_dto::*;
+use super::hash_lock_transaction_body_builder::*;
+use super::mosaic_address_restriction_transaction_body_builder::*;
+use super::mosaic_global_restriction_transaction_body_builder::*;
+use super::mosaic_id_dto::*;
+use super::mosaic_metadata_transaction_body_builder::*;
+use super::mosaic_resolution_statement_builder::*;
+use super::mosaic_supply_change_transaction_body_builder::*;
+use super::multisig_account_modification_transaction_body_builder::*;
+use super::namespace_metadata_transaction_body_builder::*;
+use super::receipt_source_builder::*;
+use super::secret_lock_transaction_body_builder::*;
+use super::secret_proof_transaction_body_builder::*;
+use super::transaction_builder::*;
+use super::transfer_transaction_body_builder::*;
+use super::unresolved_address_dto::*;
+use super::unresolved_mosaic_id_dto::*;
+
+/// Resolved values of a transaction or an inner transaction of a block.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedTransaction {
+    /// Index of the transaction within the block, starting at 1.
+    pub primary_id: u32,
+    /// Index of the inner transaction within its aggregate, starting at 1, or 0 for top-level transactions.
+    pub secondary_id: u32,
+    /// Transaction type.
+    pub entity_type: EntityTypeDto,
+    /// Unresolved addresses of the transaction and their resolved address, None when no statement resolves the alias.
+    pub addresses: Vec<(UnresolvedAddressDto, Option<AddressDto>)>,
+    /// Unresolved mosaic ids of the transaction and their resolved mosaic id, None when no statement resolves the alias.
+    pub mosaic_ids: Vec<(UnresolvedMosaicIdDto, Option<MosaicIdDto>)>,
+}
+
+/// Resolves the namespace aliases used by the transactions of a block from the resolution statements of the block.
+#[derive(Debug, Clone, Copy)]
+pub struct AliasResolver<'a> {
+    /// Address resolution statements of the block.
+    pub address_resolution_statements: &'a [AddressResolutionStatementBuilder],
+    /// Mosaic resolution statements of the block.
+    pub mosaic_resolution_statements: &'a [MosaicResolutionStatementBuilder],
+}
+
+impl<'a> AliasResolver<'a> {
+    /// Resolves an address used by a transaction.
+    /// The entry applying is the last one recorded at or before the transaction.
+    /// address: Unresolved address.
+    /// primary_id: Index of the transaction within the block, starting at 1.
+    /// secondary_id: Index of the inner transaction, starting at 1, or 0 for top-level transactions.
+    /// # Returns
+    /// The address, or None when no statement resolves the alias.
+    pub fn resolve_address(&self, address: &UnresolvedAddressDto, primary_id: u32, secondary_id: u32) -> Option<AddressDto> {
+        if !address.is_alias() {
+            return address.as_address();
+        }
+        let statement = self.address_resolution_statements.iter().find(|statement| statement.get_unresolved() == *address)?;
+        let entries = statement.get_resolution_entries();
+        let entry = find_entry(&entries, primary_id, secondary_id, |entry| entry.get_source())?;
+        Some(entry.get_resolved())
+    }
+
+    /// Resolves a mosaic id used by a transaction.
+    /// The entry applying is the last one recorded at or before the transaction.
+    /// mosaic_id: Unresolved mosaic id.
+    /// primary_id: Index of the transaction within the block, starting at 1.
+    /// secondary_id: Index of the inner transaction, starting at 1, or 0 for top-level transactions.
+    /// # Returns
+    /// The mosaic id, or None when no statement resolves the alias.
+    pub fn resolve_mosaic_id(&self, mosaic_id: &UnresolvedMosaicIdDto, primary_id: u32, secondary_id: u32) -> Option<MosaicIdDto> {
+        if !mosaic_id.is_alias() {
+            return mosaic_id.as_mosaic_id();
+        }
+        let statement = self.mosaic_resolution_statements.iter().find(|statement| statement.get_unresolved() == *mosaic_id)?;
+        let entries = statement.get_resolution_entries();
+        let entry = find_entry(&entries, primary_id, secondary_id, |entry| entry.get_source())?;
+        Some(entry.get_resolved())
+    }
+
+    /// Resolves the addresses and mosaic ids used by the transactions of a block.
+    /// Aggregates are listed before their inner transactions and carry no values themselves.
+    /// payloads: Serialized transactions of the block, in block order.
+    /// # Returns
+    /// The resolved values of every transaction and inner transaction.
+    pub fn resolve_transactions(&self, payloads: &[Vec<u8>]) -> Vec<ResolvedTransaction> {
+        let mut resolved_transactions = vec![];
+        for (index, payload) in payloads.iter().enumerate() {
+            let primary_id = index as u32 + 1;
+            let header = TransactionBuilder::from_binary(payload);
+            let body = &payload[header.get_size()..];
+            resolved_transactions.push(self.resolve_body(primary_id, 0, header._type, body));
+            if let EntityTypeDto::AGGREGATE_COMPLETE_TRANSACTION | EntityTypeDto::AGGREGATE_BONDED_TRANSACTION = header._type {
+                for (inner_index, transaction) in AggregateTransactionBodyBuilder::from_binary(body).transactions.iter().enumerate() {
+                    let transaction_payload = transaction.serializer();
+                    let transaction_header = EmbeddedTransactionBuilder::from_binary(&transaction_payload);
+                    let transaction_body = &transaction_payload[transaction_header.get_size()..];
+                    resolved_transactions.push(self.resolve_body(primary_id, inner_index as u32 + 1, transaction_header._type, transaction_body));
+                }
+            }
+        }
+        resolved_transactions
+    }
+
+    fn resolve_body(&self, primary_id: u32, secondary_id: u32, entity_type: EntityTypeDto, body: &[u8]) -> ResolvedTransaction {
+        let (addresses, mosaic_ids) = get_unresolved_values(entity_type, body);
+        ResolvedTransaction {
+            primary_id,
+            secondary_id,
+            entity_type,
+            addresses: addresses.into_iter().map(|address| (address, self.resolve_address(&address, primary_id, secondary_id))).collect(),
+            mosaic_ids: mosaic_ids.into_iter().map(|mosaic_id| (mosaic_id, self.resolve_mosaic_id(&mosaic_id, primary_id, secondary_id))).collect(),
+        }
+    }
+}
+
+/// Finds the entry with the latest source that is not after the given source.
+fn find_entry<T>(entries: &[T], primary_id: u32, secondary_id: u32, get_source: impl Fn(&T) -> ReceiptSourceBuilder) -> Option<&T> {
+    entries.iter()
+        .map(|entry| {
+            let source = get_source(entry);
+            ((source.get_primary_id(), source.get_secondary_id()), entry)
+        })
+        .filter(|(source, _)| *source <= (primary_id, secondary_id))
+        .max_by_key(|(source, _)| *source)
+        .map(|(_, entry)| entry)
+}
+
+/// Gets the unresolved addresses and mosaic ids of a transaction body.
+fn get_unresolved_values(entity_type: EntityTypeDto, body: &[u8]) -> (Vec<UnresolvedAddressDto>, Vec<UnresolvedMosaicIdDto>) {
+    match entity_type {
+        EntityTypeDto::TRANSFER_TRANSACTION => {
+            let body = TransferTransactionBodyBuilder::from_binary(body);
+            (vec![body.recipient_address], body.mosaics.iter().map(|mosaic| mosaic.get_mosaic_id()).collect())
+        }
+        EntityTypeDto::HASH_LOCK_TRANSACTION => {
+            let body = HashLockTransactionBodyBuilder::from_binary(body);
+            (vec![], vec![body.mosaic.get_mosaic_id()])
+        }
+        EntityTypeDto::SECRET_LOCK_TRANSACTION => {
+            let body = SecretLockTransactionBodyBuilder::from_binary(body);
+            (vec![body.recipient_address], vec![body.mosaic.get_mosaic_id()])
+        }
+        EntityTypeDto::SECRET_PROOF_TRANSACTION => {
+            let body = SecretProofTransactionBodyBuilder::from_binary(body);
+            (vec![body.recipient_address], vec![])
+        }
+        EntityTypeDto::ACCOUNT_METADATA_TRANSACTION => {
+            let body = AccountMetadataTransactionBodyBuilder::from_binary(body);
+            (vec![body.target_address], vec![])
+        }
+        EntityTypeDto::MOSAIC_METADATA_TRANSACTION => {
+            let body = MosaicMetadataTransactionBodyBuilder::from_binary(body);
+            (vec![body.target_address], vec![body.target_mosaic_id])
+        }
+        EntityTypeDto::NAMESPACE_METADATA_TRANSACTION => {
+            let body = NamespaceMetadataTransactionBodyBuilder::from_binary(body);
+            (vec![body.target_address], vec![])
+        }
+        EntityTypeDto::MOSAIC_SUPPLY_CHANGE_TRANSACTION => {
+            let body = MosaicSupplyChangeTransactionBodyBuilder::from_binary(body);
+            (vec![], vec![body.mosaic_id])
+        }
+        EntityTypeDto::MULTISIG_ACCOUNT_MODIFICATION_TRANSACTION => {
+            let body = MultisigAccountModificationTransactionBodyBuilder::from_binary(body);
+            ([body.address_additions, body.address_deletions].concat(), vec![])
+        }
+        EntityTypeDto::ACCOUNT_ADDRESS_RESTRICTION_TRANSACTION => {
+            let body = AccountAddressRestrictionTransactionBodyBuilder::from_binary(body);
+            ([body.restriction_additions, body.restriction_deletions].concat(), vec![])
+        }
+        EntityTypeDto::ACCOUNT_MOSAIC_RESTRICTION_TRANSACTION => {
+            let body = AccountMosaicRestrictionTransactionBodyBuilder::from_binary(body);
+            (vec![], [body.restriction_additions, body.restriction_deletions].concat())
+        }
+        EntityTypeDto::MOSAIC_ADDRESS_RESTRICTION_TRANSACTION => {
+            let body = MosaicAddressRestrictionTransactionBodyBuilder::from_binary(body);
+            (vec![body.target_address], vec![body.mosaic_id])
+        }
+        EntityTypeDto::MOSAIC_GLOBAL_RESTRICTION_TRANSACTION => {
+            let body = MosaicGlobalRestrictionTransactionBodyBuilder::from_binary(body);
+            (vec![], vec![body.mosaic_id, body.reference_mosaic_id])
+        }
+        _ => (vec![], vec![]),
+    }
+}
@@ -0,0 +1,222 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use std::fmt;
+
+use super::amount_dto::*;
+use super::block_duration_dto::*;
+use super::entity_type_dto::*;
+use super::hash256_dto::*;
+use super::key_dto::*;
+use super::lock_hash_algorithm_dto::*;
+use super::network_type_dto::*;
+use super::secret_lock_transaction_body_builder::*;
+use super::secret_lock_transaction_builder::*;
+use super::secret_proof_transaction_body_builder::*;
+use super::secret_proof_transaction_builder::*;
+use super::signature_dto::*;
+use super::timestamp_dto::*;
+use super::transaction_builder::*;
+use super::unresolved_address_dto::*;
+use super::unresolved_mosaic_builder::*;
+use super::unresolved_mosaic_id_dto::*;
+
+/// Version of the secret lock and secret proof transactions.
+const TRANSACTION_VERSION: u8 = 1;
+
+/// Errors raised when preparing an atomic swap.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AtomicSwapError {
+    /// Initiator lock does not outlive the participant lock by the required ratio.
+    UnsafeDurations { initiator: u64, participant: u64 },
+    /// Proof does not hash into the swap secret.
+    InvalidProof,
+}
+
+impl fmt::Display for AtomicSwapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AtomicSwapError::UnsafeDurations { initiator, participant } => write!(
+                f,
+                "initiator lock of {} blocks must last at least {} times the participant lock of {} blocks",
+                initiator,
+                AtomicSwap::MIN_DURATION_RATIO,
+                participant
+            ),
+            AtomicSwapError::InvalidProof => write!(f, "proof does not unlock the swap secret"),
+        }
+    }
+}
+
+impl std::error::Error for AtomicSwapError {}
+
+/// One side of an atomic swap: a mosaic locked on a network for a recipient.
+#[derive(Debug, Clone)]
+pub struct AtomicSwapLeg {
+    /// Network the lock is announced on.
+    pub network: NetworkTypeDto,
+    /// Public key of the account locking the mosaic.
+    pub sender_public_key: KeyDto,
+    /// Address of the account claiming the mosaic.
+    pub recipient_address: UnresolvedAddressDto,
+    /// Locked mosaic identifier.
+    pub mosaic_id: UnresolvedMosaicIdDto,
+    /// Locked mosaic amount.
+    pub amount: AmountDto,
+    /// Number of blocks before the mosaic returns to the sender.
+    pub duration: BlockDurationDto,
+}
+
+impl AtomicSwapLeg {
+    fn create_lock(&self, hash_algorithm: LockHashAlgorithmDto, secret: Hash256Dto, fee: AmountDto, deadline: TimestampDto) -> SecretLockTransactionBuilder {
+        let mosaic = UnresolvedMosaicBuilder::from_binary(&[self.mosaic_id.serializer(), self.amount.serializer()].concat());
+        SecretLockTransactionBuilder {
+            super_object: create_transaction(self.network, self.sender_public_key, EntityTypeDto::SECRET_LOCK_TRANSACTION, fee, deadline),
+            body: SecretLockTransactionBodyBuilder {
+                recipient_address: self.recipient_address,
+                secret,
+                mosaic,
+                duration: self.duration,
+                hash_algorithm,
+            },
+        }
+    }
+}
+
+/// Cross-chain atomic swap (hashed timelock contract) between an initiator and a participant.
+/// The initiator knows the proof and locks first with the longer duration; the participant locks
+/// with the same secret. The initiator claims the participant lock, revealing the proof, which the
+/// participant then uses to claim the initiator lock before it expires.
+/// Durations are compared in blocks, so both networks are expected to have similar block times.
+#[derive(Debug, Clone)]
+pub struct AtomicSwap {
+    /// Hash algorithm tying the proof to the secret.
+    pub hash_algorithm: LockHashAlgorithmDto,
+    /// Secret shared by both locks.
+    pub secret: Hash256Dto,
+    /// Lock announced by the initiator.
+    pub initiator: AtomicSwapLeg,
+    /// Lock announced by the participant.
+    pub participant: AtomicSwapLeg,
+}
+
+impl AtomicSwap {
+    /// Minimum ratio between the initiator and participant lock durations.
+    pub const MIN_DURATION_RATIO: u64 = 2;
+
+    /// Creates an atomic swap after checking that the lock durations are safely asymmetric.
+    /// The initiator lock must last at least `MIN_DURATION_RATIO` times the participant lock,
+    /// so the participant always has time to claim once the proof is revealed.
+    /// hash_algorithm: Hash algorithm tying the proof to the secret.
+    /// secret: Secret shared by both locks.
+    /// initiator: Lock announced by the initiator.
+    /// participant: Lock announced by the participant.
+    /// # Returns
+    /// An AtomicSwap.
+    pub fn create(hash_algorithm: LockHashAlgorithmDto, secret: Hash256Dto, initiator: AtomicSwapLeg, participant: AtomicSwapLeg) -> Result<Self, AtomicSwapError> {
+        let initiator_duration = initiator.duration.get_block_duration();
+        let participant_duration = participant.duration.get_block_duration();
+        if participant_duration == 0 || initiator_duration / Self::MIN_DURATION_RATIO < participant_duration {
+            return Err(AtomicSwapError::UnsafeDurations { initiator: initiator_duration, participant: participant_duration });
+        }
+
+        Ok(AtomicSwap { hash_algorithm, secret, initiator, participant })
+    }
+
+    /// Creates an atomic swap from a proof known by the initiator.
+    /// hash_algorithm: Hash algorithm tying the proof to the secret.
+    /// proof: Proof data kept private until the initiator claims.
+    /// initiator: Lock announced by the initiator.
+    /// participant: Lock announced by the participant.
+    /// # Returns
+    /// An AtomicSwap.
+    pub fn from_proof(hash_algorithm: LockHashAlgorithmDto, proof: &[u8], initiator: AtomicSwapLeg, participant: AtomicSwapLeg) -> Result<Self, AtomicSwapError> {
+        Self::create(hash_algorithm, hash_algorithm.hash(proof), initiator, participant)
+    }
+
+    /// Creates the unsigned secret lock announced by the initiator.
+    /// fee: Transaction fee.
+    /// deadline: Transaction deadline.
+    /// # Returns
+    /// A SecretLockTransactionBuilder.
+    pub fn create_initiator_lock(&self, fee: AmountDto, deadline: TimestampDto) -> SecretLockTransactionBuilder {
+        self.initiator.create_lock(self.hash_algorithm, self.secret, fee, deadline)
+    }
+
+    /// Creates the unsigned secret lock announced by the participant.
+    /// fee: Transaction fee.
+    /// deadline: Transaction deadline.
+    /// # Returns
+    /// A SecretLockTransactionBuilder.
+    pub fn create_participant_lock(&self, fee: AmountDto, deadline: TimestampDto) -> SecretLockTransactionBuilder {
+        self.participant.create_lock(self.hash_algorithm, self.secret, fee, deadline)
+    }
+
+    /// Creates the unsigned secret proof claiming the participant lock, which reveals the proof.
+    /// signer_public_key: Public key of the account announcing the proof.
+    /// proof: Proof data.
+    /// fee: Transaction fee.
+    /// deadline: Transaction deadline.
+    /// # Returns
+    /// A SecretProofTransactionBuilder.
+    pub fn create_initiator_proof(&self, signer_public_key: KeyDto, proof: &[u8], fee: AmountDto, deadline: TimestampDto) -> Result<SecretProofTransactionBuilder, AtomicSwapError> {
+        self.create_proof(&self.participant, signer_public_key, proof, fee, deadline)
+    }
+
+    /// Creates the unsigned secret proof claiming the initiator lock once the proof is revealed.
+    /// signer_public_key: Public key of the account announcing the proof.
+    /// proof: Proof data.
+    /// fee: Transaction fee.
+    /// deadline: Transaction deadline.
+    /// # Returns
+    /// A SecretProofTransactionBuilder.
+    pub fn create_participant_proof(&self, signer_public_key: KeyDto, proof: &[u8], fee: AmountDto, deadline: TimestampDto) -> Result<SecretProofTransactionBuilder, AtomicSwapError> {
+        self.create_proof(&self.initiator, signer_public_key, proof, fee, deadline)
+    }
+
+    fn create_proof(&self, lock: &AtomicSwapLeg, signer_public_key: KeyDto, proof: &[u8], fee: AmountDto, deadline: TimestampDto) -> Result<SecretProofTransactionBuilder, AtomicSwapError> {
+        if !self.hash_algorithm.verify(&self.secret, proof) {
+            return Err(AtomicSwapError::InvalidProof);
+        }
+
+        Ok(SecretProofTransactionBuilder {
+            super_object: create_transaction(lock.network, signer_public_key, EntityTypeDto::SECRET_PROOF_TRANSACTION, fee, deadline),
+            body: SecretProofTransactionBodyBuilder {
+                recipient_address: lock.recipient_address,
+                secret: self.secret,
+                hash_algorithm: self.hash_algorithm,
+                proof: proof.to_vec(),
+            },
+        })
+    }
+}
+
+fn create_transaction(network: NetworkTypeDto, signer_public_key: KeyDto, _type: EntityTypeDto, fee: AmountDto, deadline: TimestampDto) -> TransactionBuilder {
+    TransactionBuilder {
+        signature: SignatureDto([0u8; 64]),
+        signer_public_key,
+        version: TRANSACTION_VERSION,
+        network,
+        _type,
+        fee,
+        deadline,
+    }
+}
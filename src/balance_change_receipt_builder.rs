@@ -85,6 +85,7 @@ impl BalanceChangeReceiptBuilder {
     /// A Serialized bytes.
     pub fn serializer(&self) -> Vec<u8> {
         let mut buf: Vec<u8> = vec![];
+        buf.append(&mut (self.get_size() as u32).to_le_bytes().to_vec());
         buf.append(&mut self.super_object.serializer());
         buf.append(&mut self.mosaic.serializer()); // kind:CUSTOM
         buf.append(&mut self.target_address.serializer()); // kind:CUSTOM
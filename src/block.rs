@@ -0,0 +1,442 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use std::fmt;
+
+use sha3::{Digest, Sha3_256};
+
+use super::address_dto::*;
+use super::amount_dto::*;
+use super::block_fee_multiplier_dto::*;
+use super::block_header_builder::*;
+use super::difficulty_dto::*;
+use super::entity_type_dto::*;
+use super::generator_utils::*;
+use super::hash256_dto::*;
+use super::height_dto::*;
+use super::importance_block_footer_builder::*;
+use super::importance_block_header_builder::*;
+use super::key_dto::*;
+use super::key_pair::*;
+use super::merkle_hash::*;
+use super::nemesis_block_header_builder::*;
+use super::network_type_dto::*;
+use super::normal_block_header_builder::*;
+use super::proof_gamma_dto::*;
+use super::proof_scalar_dto::*;
+use super::proof_verification_hash_dto::*;
+use super::signature_dto::*;
+use super::timestamp_dto::*;
+use super::transaction_builder::*;
+use super::transaction_helper::*;
+use super::validation_config::*;
+use super::validation_failure::*;
+use super::vrf_proof_builder::*;
+
+/// Alignment of the transactions of a block.
+const TRANSACTION_ALIGNMENT: usize = 8;
+
+/// Offset of the first byte covered by the block signature (size, reserved and signature are skipped).
+const VERIFIABLE_DATA_OFFSET: usize = 72;
+
+/// Block header of any type, decoded with the layout matching its entity type.
+#[derive(Debug, Clone)]
+pub enum BlockHeader {
+    /// Header of the nemesis block.
+    Nemesis(NemesisBlockHeaderBuilder),
+    /// Header of a block that is not an importance block.
+    Normal(NormalBlockHeaderBuilder),
+    /// Header of an importance block.
+    Importance(ImportanceBlockHeaderBuilder),
+}
+
+impl BlockHeader {
+    /// Creates a block header from binary payload, dispatching on its entity type.
+    /// payload: Byte payload to use to serialize the object.
+    /// # Returns
+    /// A BlockHeader.
+    pub fn from_binary(payload: &[u8]) -> Self {
+        let header = BlockHeaderBuilder::from_binary(payload);
+        match header.get_type() {
+            EntityTypeDto::NEMESIS_BLOCK_HEADER => BlockHeader::Nemesis(NemesisBlockHeaderBuilder::from_binary(payload)),
+            EntityTypeDto::NORMAL_BLOCK_HEADER => BlockHeader::Normal(NormalBlockHeaderBuilder::from_binary(payload)),
+            EntityTypeDto::IMPORTANCE_BLOCK_HEADER => BlockHeader::Importance(ImportanceBlockHeaderBuilder::from_binary(payload)),
+            entity_type => panic!("Invalid block type ({:?})", entity_type),
+        }
+    }
+
+    /// Gets the fields shared by all block headers.
+    ///
+    /// # Returns
+    /// A BlockHeaderBuilder.
+    pub fn get_block_header(&self) -> &BlockHeaderBuilder {
+        match self {
+            BlockHeader::Nemesis(header) => &header.super_object,
+            BlockHeader::Normal(header) => &header.super_object,
+            BlockHeader::Importance(header) => &header.super_object,
+        }
+    }
+
+    /// Gets the importance footer of nemesis and importance block headers.
+    ///
+    /// # Returns
+    /// An importance block footer, or None for normal blocks.
+    pub fn get_importance_block_footer(&self) -> Option<&ImportanceBlockFooterBuilder> {
+        match self {
+            BlockHeader::Nemesis(header) => Some(&header.importance_block_footer),
+            BlockHeader::Normal(_) => None,
+            BlockHeader::Importance(header) => Some(&header.importance_block_footer),
+        }
+    }
+
+    fn get_block_header_mut(&mut self) -> &mut BlockHeaderBuilder {
+        match self {
+            BlockHeader::Nemesis(header) => &mut header.super_object,
+            BlockHeader::Normal(header) => &mut header.super_object,
+            BlockHeader::Importance(header) => &mut header.super_object,
+        }
+    }
+
+    /// Gets the part of the header that is covered by the harvester signature.
+    /// It starts at the signer public key and includes the importance footer.
+    ///
+    /// # Returns
+    /// A verifiable data.
+    pub fn get_verifiable_data(&self) -> Vec<u8> {
+        self.serializer()[VERIFIABLE_DATA_OFFSET..].to_vec()
+    }
+
+    /// Signs the header with the harvester key, which also becomes the signer of the block.
+    /// key_pair: Harvester signing key pair.
+    pub fn sign(&mut self, key_pair: &KeyPair) {
+        self.get_block_header_mut().signer_public_key = key_pair.get_public_key();
+        let signature = key_pair.sign(&self.get_verifiable_data());
+        self.get_block_header_mut().signature = signature;
+    }
+
+    /// Verifies the harvester signature of the header.
+    ///
+    /// # Returns
+    /// True if the signature is valid for the signer public key.
+    pub fn verify_signature(&self) -> bool {
+        let header = self.get_block_header();
+        verify_signature(&header.signer_public_key, &self.get_verifiable_data(), &header.signature)
+    }
+
+    /// Calculates the block hash the way Catapult does.
+    ///
+    /// # Returns
+    /// A block hash.
+    pub fn calculate_hash(&self) -> Hash256Dto {
+        let header = self.get_block_header();
+        let mut hasher = Sha3_256::new();
+        hasher.update(&header.signature.0[..SignatureDto::LENGTH / 2]);
+        hasher.update(header.signer_public_key.0);
+        hasher.update(self.get_verifiable_data());
+        Hash256Dto::from_binary(&hasher.finalize())
+    }
+
+    /// Runs the stateless checks of the node on the block header.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the header is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        match self {
+            BlockHeader::Nemesis(header) => header.validate(config),
+            BlockHeader::Normal(header) => header.validate(config),
+            BlockHeader::Importance(header) => header.validate(config),
+        }
+    }
+
+    /// Gets the size of the type.
+    ///
+    /// Returns:
+    /// A size in bytes.
+    pub fn get_size(&self) -> usize {
+        match self {
+            BlockHeader::Nemesis(header) => header.get_size(),
+            BlockHeader::Normal(header) => header.get_size(),
+            BlockHeader::Importance(header) => header.get_size(),
+        }
+    }
+
+    /// Serializes self to bytes.
+    ///
+    /// # Returns
+    /// A Serialized bytes.
+    pub fn serializer(&self) -> Vec<u8> {
+        match self {
+            BlockHeader::Nemesis(header) => header.serializer(),
+            BlockHeader::Normal(header) => header.serializer(),
+            BlockHeader::Importance(header) => header.serializer(),
+        }
+    }
+}
+
+/// Block with its header, footer and top-level transactions.
+#[derive(Debug, Clone)]
+pub struct Block {
+    /// Block header, including the importance footer of nemesis and importance blocks.
+    pub header: BlockHeader,
+    /// Top-level transactions, each padded to 8 bytes in the payload.
+    pub transactions: Vec<Box<dyn TransactionHelper>>,
+}
+
+impl Block {
+    /// Creates an instance of Block from binary payload.
+    /// The payload is cut to the size stored in the block header.
+    /// payload: Byte payload to use to serialize the object.
+    /// # Returns
+    /// A Block.
+    pub fn from_binary(payload: &[u8]) -> Self {
+        let size = u32::from_le_bytes(fixed_bytes::<4>(payload)) as usize;
+        let header = BlockHeader::from_binary(payload);
+        let mut _bytes = &payload[header.get_size()..size];
+        let mut transactions = vec![];
+        while !_bytes.is_empty() {
+            let item = load_transaction_from_binary(_bytes);
+            _bytes = &_bytes[get_aligned_size(item.get_size()).min(_bytes.len())..];
+            transactions.push(item);
+        }
+        Block { header, transactions }
+    }
+
+    /// Signs the block with the harvester key.
+    /// The transactions are covered through the transactions hash of the header.
+    /// key_pair: Harvester signing key pair.
+    pub fn sign(&mut self, key_pair: &KeyPair) {
+        self.header.sign(key_pair)
+    }
+
+    /// Calculates the block hash.
+    ///
+    /// # Returns
+    /// A block hash.
+    pub fn calculate_hash(&self) -> Hash256Dto {
+        self.header.calculate_hash()
+    }
+
+    /// Runs the stateless checks of the node on the block header and its transactions.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the block is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = self.header.validate(config);
+        for transaction in &self.transactions {
+            failures.extend(transaction.validate(config));
+        }
+        failures
+    }
+
+    /// Gets the size of the type.
+    ///
+    /// Returns:
+    /// A size in bytes.
+    pub fn get_size(&self) -> usize {
+        let mut size = self.header.get_size();
+        size += self.transactions.iter().map(|item| get_aligned_size(item.get_size())).sum::<usize>();
+        size
+    }
+
+    /// Serializes self to bytes.
+    /// The size field of the header covers the whole block.
+    ///
+    /// # Returns
+    /// A Serialized bytes.
+    pub fn serializer(&self) -> Vec<u8> {
+        let mut buf = self.header.serializer();
+        buf[..4].copy_from_slice(&(self.get_size() as u32).to_le_bytes());
+        for transaction in &self.transactions {
+            let mut transaction_payload = transaction.serializer();
+            transaction_payload.resize(get_aligned_size(transaction_payload.len()), 0);
+            buf.append(&mut transaction_payload);
+        }
+        buf
+    }
+}
+
+/// Creates the blocks of a harvester on top of a chain.
+#[derive(Debug, Clone)]
+pub struct BlockFactory {
+    /// Network of the blocks.
+    pub network: NetworkTypeDto,
+    /// Network generation hash seed, used to hash the transactions.
+    pub generation_hash_seed: Hash256Dto,
+    /// Number of blocks between importance blocks.
+    pub importance_grouping: u64,
+}
+
+impl BlockFactory {
+    /// Creates an unsigned block following a previous block.
+    /// Height, previous block hash and transactions hash are filled; the difficulty is copied from the previous block.
+    /// Blocks on an importance grouping boundary get an importance footer with zero values that the caller sets
+    /// together with the generation hash proof, receipts hash and state hash before signing.
+    /// previous_block_header: Header of the previous block.
+    /// timestamp: Block timestamp.
+    /// transactions: Top-level transactions of the block.
+    /// beneficiary_address: Beneficiary of the harvesting fees.
+    /// fee_multiplier: Fee multiplier applied to the transactions.
+    /// # Returns
+    /// A Block.
+    pub fn create_block(&self, previous_block_header: &BlockHeader, timestamp: TimestampDto, transactions: Vec<Box<dyn TransactionHelper>>, beneficiary_address: AddressDto, fee_multiplier: BlockFeeMultiplierDto) -> Block {
+        let previous_header = previous_block_header.get_block_header();
+        let height = HeightDto(previous_header.height.get_height() + 1);
+        let transaction_hashes: Vec<Hash256Dto> = transactions.iter()
+            .map(|transaction| TransactionBuilder::calculate_transaction_hash(&transaction.serializer(), &self.generation_hash_seed))
+            .collect();
+        let is_importance_block = height.get_height().checked_rem(self.importance_grouping) == Some(0);
+        let super_object = BlockHeaderBuilder {
+            signature: SignatureDto([0; 64]),
+            signer_public_key: KeyDto([0; 32]),
+            version: 1,
+            network: self.network,
+            _type: if is_importance_block { EntityTypeDto::IMPORTANCE_BLOCK_HEADER } else { EntityTypeDto::NORMAL_BLOCK_HEADER },
+            height,
+            timestamp,
+            difficulty: previous_header.difficulty,
+            generation_hash_proof: VrfProofBuilder {
+                gamma: ProofGammaDto([0; 32]),
+                verification_hash: ProofVerificationHashDto([0; 16]),
+                scalar: ProofScalarDto([0; 32]),
+            },
+            previous_block_hash: previous_block_header.calculate_hash(),
+            transactions_hash: calculate_merkle_hash(&transaction_hashes),
+            receipts_hash: Hash256Dto([0; 32]),
+            state_hash: Hash256Dto([0; 32]),
+            beneficiary_address,
+            fee_multiplier,
+        };
+        let header = if is_importance_block {
+            let importance_block_footer = ImportanceBlockFooterBuilder {
+                voting_eligible_accounts_count: 0,
+                harvesting_eligible_accounts_count: 0,
+                total_voting_balance: AmountDto(0),
+                previous_importance_block_hash: Hash256Dto([0; 32]),
+            };
+            BlockHeader::Importance(ImportanceBlockHeaderBuilder { super_object, importance_block_footer })
+        } else {
+            BlockHeader::Normal(NormalBlockHeaderBuilder { super_object })
+        };
+        Block { header, transactions }
+    }
+}
+
+/// Block header that breaks a verified chain of headers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockHeaderChainFailure {
+    /// Height of the rejected header.
+    pub height: HeightDto,
+    /// Check the header fails.
+    pub failure: ValidationFailure,
+}
+
+impl fmt::Display for BlockHeaderChainFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "block at height {} fails {}", self.height.get_height(), self.failure)
+    }
+}
+
+/// Verifies a sequence of block headers fetched from an untrusted source.
+/// Every header must be on the network and carry a valid harvester signature. Each following header must link the
+/// previous block hash at the next height with a timestamp that does not go back, and importance blocks must point
+/// at the previous importance block of the sequence, when there is one.
+/// network: Expected network of the headers.
+/// headers: Block headers ordered by height.
+/// # Returns
+/// The hashes of the headers, or the first header breaking the chain.
+pub fn verify_block_header_chain(network: NetworkTypeDto, headers: &[BlockHeader]) -> Result<Vec<Hash256Dto>, BlockHeaderChainFailure> {
+    let mut hashes: Vec<Hash256Dto> = Vec::with_capacity(headers.len());
+    let mut previous_importance_block_hash: Option<Hash256Dto> = None;
+    for (index, block_header) in headers.iter().enumerate() {
+        let header = block_header.get_block_header();
+        let reject = |failure| BlockHeaderChainFailure { height: header.height, failure };
+        if header.network != network {
+            return Err(reject(ValidationFailure::Failure_Core_Wrong_Network));
+        }
+        if !block_header.verify_signature() {
+            return Err(reject(ValidationFailure::Failure_Signature_Not_Verifiable));
+        }
+        if index > 0 {
+            let previous_header = headers[index - 1].get_block_header();
+            if header.height.get_height() != previous_header.height.get_height() + 1 || header.previous_block_hash != hashes[index - 1] {
+                return Err(reject(ValidationFailure::Failure_Chain_Unlinked));
+            }
+            if header.timestamp.get_timestamp() < previous_header.timestamp.get_timestamp() {
+                return Err(reject(ValidationFailure::Failure_Chain_Timestamp_Decreasing));
+            }
+        }
+        let hash = block_header.calculate_hash();
+        if let Some(footer) = block_header.get_importance_block_footer() {
+            if matches!(previous_importance_block_hash, Some(expected_hash) if footer.previous_importance_block_hash != expected_hash) {
+                return Err(reject(ValidationFailure::Failure_Chain_Importance_Block_Unlinked));
+            }
+            previous_importance_block_hash = Some(hash);
+        }
+        hashes.push(hash);
+    }
+    Ok(hashes)
+}
+
+/// Calculates the difficulty of the block following a window of blocks the way Catapult does.
+/// The average difficulty of the last `max_difficulty_blocks` blocks is scaled by the ratio of the targeted and
+/// actual time between them. The change is limited to 5% of the last difficulty and the result to the node bounds.
+/// headers: Previous blocks ordered by height, ending with the parent block.
+/// config: Network settings.
+/// # Returns
+/// A DifficultyDto, the default difficulty when there are fewer than two blocks.
+pub fn calculate_difficulty(headers: &[BlockHeaderBuilder], config: &ValidationConfig) -> DifficultyDto {
+    let window = &headers[headers.len().saturating_sub(config.max_difficulty_blocks)..];
+    if window.len() < 2 {
+        return DifficultyDto::DEFAULT;
+    }
+
+    let history_size = window.len() as u128;
+    let average_difficulty = window.iter().map(|header| header.difficulty.get_difficulty() as u128).sum::<u128>() / history_size;
+    let first_timestamp = window[0].timestamp.get_timestamp();
+    let last_timestamp = window[window.len() - 1].timestamp.get_timestamp();
+    let time_diff = last_timestamp.saturating_sub(first_timestamp) as u128;
+    let target_time_diff = config.block_generation_target_time as u128 * (history_size - 1);
+    let mut difficulty = match time_diff {
+        0 => u128::MAX,
+        _ => average_difficulty * target_time_diff / time_diff,
+    };
+
+    let last_difficulty = window[window.len() - 1].difficulty.get_difficulty() as u128;
+    if 19 * last_difficulty > difficulty.saturating_mul(20) {
+        difficulty = 19 * last_difficulty / 20;
+    } else if 21 * last_difficulty < difficulty.saturating_mul(20) {
+        difficulty = 21 * last_difficulty / 20;
+    }
+    let difficulty = difficulty.clamp(DifficultyDto::MIN.0 as u128, DifficultyDto::MAX.0 as u128);
+    DifficultyDto(difficulty as u64)
+}
+
+/// Creates a block from binary payload, picking the header layout from the block type.
+/// payload: Byte payload to use to serialize the object.
+/// # Returns
+/// A Block.
+pub fn load_block_from_binary(payload: &[u8]) -> Block {
+    Block::from_binary(payload)
+}
+
+fn get_aligned_size(size: usize) -> usize {
+    size + (TRANSACTION_ALIGNMENT - size % TRANSACTION_ALIGNMENT) % TRANSACTION_ALIGNMENT
+}
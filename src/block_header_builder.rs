@@ -30,41 +30,43 @@ use super::key_dto::*;
 use super::network_type_dto::*;
 use super::signature_dto::*;
 use super::timestamp_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 use super::vrf_proof_builder::*;
 
 /// Binary layout for a block header.
 #[derive(Debug, Clone)]
 pub struct BlockHeaderBuilder {
     /// Entity signature.
-    signature: SignatureDto,
+    pub signature: SignatureDto,
     /// Entity signer's public key.
-    signer_public_key: KeyDto,
+    pub signer_public_key: KeyDto,
     /// Entity version.
-    version: u8,
+    pub version: u8,
     /// Entity network.
-    network: NetworkTypeDto,
+    pub network: NetworkTypeDto,
     /// Entity type.
-    _type: EntityTypeDto,
+    pub _type: EntityTypeDto,
     /// Block height.
-    height: HeightDto,
+    pub height: HeightDto,
     /// Number of milliseconds elapsed since creation of nemesis block.
-    timestamp: TimestampDto,
+    pub timestamp: TimestampDto,
     /// Block difficulty.
-    difficulty: DifficultyDto,
+    pub difficulty: DifficultyDto,
     /// Generation hash proof.
-    generation_hash_proof: VrfProofBuilder,
+    pub generation_hash_proof: VrfProofBuilder,
     /// Previous block hash.
-    previous_block_hash: Hash256Dto,
+    pub previous_block_hash: Hash256Dto,
     /// Hash of the transactions in this block.
-    transactions_hash: Hash256Dto,
+    pub transactions_hash: Hash256Dto,
     /// Hash of the receipts generated by this block.
-    receipts_hash: Hash256Dto,
+    pub receipts_hash: Hash256Dto,
     /// Hash of the global chain state at this block.
-    state_hash: Hash256Dto,
+    pub state_hash: Hash256Dto,
     /// Beneficiary address designated by harvester.
-    beneficiary_address: AddressDto,
+    pub beneficiary_address: AddressDto,
     /// Fee multiplier applied to block transactions.
-    fee_multiplier: BlockFeeMultiplierDto,
+    pub fee_multiplier: BlockFeeMultiplierDto,
 }
 
 
@@ -74,10 +76,12 @@ impl BlockHeaderBuilder {
     /// # Returns
     /// A BlockHeaderBuilder.
     pub fn from_binary(_bytes: &[u8]) -> Self {
+        let _bytes = _bytes[8..].to_vec(); // size and verifiable_entity_header__reserved1
         let signature = SignatureDto::from_binary(&_bytes); // kind:CUSTOM1
         let mut _bytes = _bytes[signature.get_size()..].to_vec();
         let signer_public_key = KeyDto::from_binary(&_bytes); // kind:CUSTOM1
         let mut _bytes = _bytes[signer_public_key.get_size()..].to_vec();
+        _bytes = _bytes[4..].to_vec(); // entity_body__reserved1
         let buf = fixed_bytes::<1>(&_bytes);
         let version = u8::from_le_bytes(buf); // kind:SIMPLE
         let _bytes = (&_bytes[1..]).to_vec();
@@ -228,6 +232,29 @@ impl BlockHeaderBuilder {
         self.fee_multiplier.clone()
     }
 
+    /// Runs the stateless checks of the node on the block header.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the header is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = vec![];
+        if self.network != config.network {
+            failures.push(ValidationFailure::Failure_Core_Wrong_Network);
+        }
+        let height = self.height.get_height();
+        let expected_type = if height == 1 {
+            EntityTypeDto::NEMESIS_BLOCK_HEADER
+        } else if height.checked_rem(config.importance_grouping) == Some(0) {
+            EntityTypeDto::IMPORTANCE_BLOCK_HEADER
+        } else {
+            EntityTypeDto::NORMAL_BLOCK_HEADER
+        };
+        if self._type != expected_type {
+            failures.push(ValidationFailure::Failure_Core_Unexpected_Block_Type);
+        }
+        failures
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -261,11 +288,11 @@ impl BlockHeaderBuilder {
     /// A Serialized bytes.
     pub fn serializer(&self) -> Vec<u8> {
         let mut buf: Vec<u8> = vec![];
-        buf.append(&mut self.get_size().to_le_bytes().to_vec()); // kind:SIMPLE
-        buf.append(&mut 4u16.to_le_bytes().to_vec());
+        // Ignored serialization: size AttributeKind.SIMPLE
+        buf.append(&mut [0u8; 4].to_vec()); // kind:SIMPLE and is_reserved
         buf.append(&mut self.signature.serializer()); // kind:CUSTOM
         buf.append(&mut self.signer_public_key.serializer()); // kind:CUSTOM
-        buf.append(&mut 4u16.to_le_bytes().to_vec());
+        buf.append(&mut [0u8; 4].to_vec()); // kind:SIMPLE and is_reserved
         buf.append(&mut self.get_version().to_le_bytes().to_vec()); // kind:SIMPLE
         buf.append(&mut self.network.serializer()); // kind:CUSTOM
         buf.append(&mut self._type.serializer()); // kind:CUSTOM
@@ -0,0 +1,157 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use super::address_resolution_statement_builder::*;
+use super::block_header_builder::*;
+use super::generator_utils::*;
+use super::hash256_dto::*;
+use super::merkle_hash::*;
+use super::mosaic_resolution_statement_builder::*;
+use super::transaction_statement_builder::*;
+
+/// Binary layout for the statements of a block, as stored by the node next to the block.
+#[derive(Debug, Clone)]
+pub struct BlockStatementBuilder {
+    /// Transaction statements.
+    transaction_statements: Vec<TransactionStatementBuilder>,
+    /// Address resolution statements.
+    address_resolution_statements: Vec<AddressResolutionStatementBuilder>,
+    /// Mosaic resolution statements.
+    mosaic_resolution_statements: Vec<MosaicResolutionStatementBuilder>,
+}
+
+
+impl BlockStatementBuilder {
+    /// Creates an instance of BlockStatementBuilder from binary payload.
+    /// payload: Byte payload to use to serialize the object.
+    /// # Returns
+    /// A BlockStatementBuilder.
+    pub fn from_binary(_bytes: &[u8]) -> Self {
+        let mut _bytes = _bytes.to_vec();
+        let buf = fixed_bytes::<4>(&_bytes);
+        let transaction_statements_count = u32::from_le_bytes(buf); // kind:SIZE_FIELD
+        _bytes = _bytes[4..].to_vec();
+        let mut transaction_statements: Vec<TransactionStatementBuilder> = vec![]; // kind:ARRAY
+        for _ in 0..transaction_statements_count {
+            let item = TransactionStatementBuilder::from_binary(&_bytes);
+            _bytes = _bytes[item.get_size()..].to_vec();
+            transaction_statements.push(item);
+        }
+        let buf = fixed_bytes::<4>(&_bytes);
+        let address_resolution_statements_count = u32::from_le_bytes(buf); // kind:SIZE_FIELD
+        _bytes = _bytes[4..].to_vec();
+        let mut address_resolution_statements: Vec<AddressResolutionStatementBuilder> = vec![]; // kind:ARRAY
+        for _ in 0..address_resolution_statements_count {
+            let item = AddressResolutionStatementBuilder::from_binary(&_bytes);
+            _bytes = _bytes[item.get_size()..].to_vec();
+            address_resolution_statements.push(item);
+        }
+        let buf = fixed_bytes::<4>(&_bytes);
+        let mosaic_resolution_statements_count = u32::from_le_bytes(buf); // kind:SIZE_FIELD
+        _bytes = _bytes[4..].to_vec();
+        let mut mosaic_resolution_statements: Vec<MosaicResolutionStatementBuilder> = vec![]; // kind:ARRAY
+        for _ in 0..mosaic_resolution_statements_count {
+            let item = MosaicResolutionStatementBuilder::from_binary(&_bytes);
+            _bytes = _bytes[item.get_size()..].to_vec();
+            mosaic_resolution_statements.push(item);
+        }
+        BlockStatementBuilder { transaction_statements, address_resolution_statements, mosaic_resolution_statements }
+    }
+
+    /// Gets transaction statements.
+    ///
+    /// # Returns
+    /// A Transaction statements.
+    pub fn get_transaction_statements(&self) -> Vec<TransactionStatementBuilder> {
+        self.transaction_statements.clone() // ARRAY or FILL_ARRAY
+    }
+
+    /// Gets address resolution statements.
+    ///
+    /// # Returns
+    /// A Address resolution statements.
+    pub fn get_address_resolution_statements(&self) -> Vec<AddressResolutionStatementBuilder> {
+        self.address_resolution_statements.clone() // ARRAY or FILL_ARRAY
+    }
+
+    /// Gets mosaic resolution statements.
+    ///
+    /// # Returns
+    /// A Mosaic resolution statements.
+    pub fn get_mosaic_resolution_statements(&self) -> Vec<MosaicResolutionStatementBuilder> {
+        self.mosaic_resolution_statements.clone() // ARRAY or FILL_ARRAY
+    }
+
+    /// Calculates the merkle root of the statement hashes, which is the receipts hash of the block.
+    /// Transaction statements come first, then address and mosaic resolution statements.
+    /// # Returns
+    /// A receipts hash.
+    pub fn compute_merkle_root(&self) -> Hash256Dto {
+        let mut hashes: Vec<Hash256Dto> = self.transaction_statements.iter().map(|statement| statement.calculate_hash()).collect();
+        hashes.extend(self.address_resolution_statements.iter().map(|statement| statement.calculate_hash()));
+        hashes.extend(self.mosaic_resolution_statements.iter().map(|statement| statement.calculate_hash()));
+        calculate_merkle_hash(&hashes)
+    }
+
+    /// Verifies the statements belong to a block.
+    /// block_header: Header of the block.
+    /// # Returns
+    /// True if the merkle root of the statements is the receipts hash of the block.
+    pub fn verify(&self, block_header: &BlockHeaderBuilder) -> bool {
+        self.compute_merkle_root() == block_header.get_receipts_hash()
+    }
+
+    /// Gets the size of the type.
+    ///
+    /// Returns:
+    /// A size in bytes.
+    pub fn get_size(&self) -> usize {
+        let mut size = 0;
+        size += 4; // transaction_statements_count;
+        size += self.transaction_statements.iter().map(|item| item.get_size()).sum::<usize>(); // array or fill_array;
+        size += 4; // address_resolution_statements_count;
+        size += self.address_resolution_statements.iter().map(|item| item.get_size()).sum::<usize>(); // array or fill_array;
+        size += 4; // mosaic_resolution_statements_count;
+        size += self.mosaic_resolution_statements.iter().map(|item| item.get_size()).sum::<usize>(); // array or fill_array;
+        size
+    }
+
+    /// Serializes self to bytes.
+    ///
+    /// # Returns
+    /// A Serialized bytes.
+    pub fn serializer(&self) -> Vec<u8> {
+        let mut buf: Vec<u8> = vec![];
+        buf.append(&mut (self.transaction_statements.len() as u32).to_le_bytes().to_vec()); // kind:SIZE_FIELD
+        for i in &self.transaction_statements {
+            buf.append(&mut i.serializer()); // kind:ARRAY|FILL_ARRAY
+        }
+        buf.append(&mut (self.address_resolution_statements.len() as u32).to_le_bytes().to_vec()); // kind:SIZE_FIELD
+        for i in &self.address_resolution_statements {
+            buf.append(&mut i.serializer()); // kind:ARRAY|FILL_ARRAY
+        }
+        buf.append(&mut (self.mosaic_resolution_statements.len() as u32).to_le_bytes().to_vec()); // kind:SIZE_FIELD
+        for i in &self.mosaic_resolution_statements {
+            buf.append(&mut i.serializer()); // kind:ARRAY|FILL_ARRAY
+        }
+        buf
+    }
+}
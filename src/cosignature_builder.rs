@@ -20,7 +20,9 @@
  */
 
 use super::generator_utils::*;
+use super::hash256_dto::*;
 use super::key_dto::*;
+use super::key_pair::*;
 use super::signature_dto::*;
 
 /// Cosignature attached to an aggregate transaction.
@@ -36,6 +38,18 @@ pub struct CosignatureBuilder {
 
 
 impl CosignatureBuilder {
+    const VERSION: u64 = 0;
+
+    /// Creates a cosignature by signing the hash of an aggregate transaction.
+    /// key_pair: Cosigner key pair.
+    /// parent_hash: Hash of the aggregate transaction to cosign.
+    /// # Returns
+    /// A CosignatureBuilder.
+    pub fn create(key_pair: &KeyPair, parent_hash: &Hash256Dto) -> Self {
+        let signature = key_pair.sign(&parent_hash.0);
+        CosignatureBuilder { version: Self::VERSION, signer_public_key: key_pair.get_public_key(), signature }
+    }
+
     /// Creates an instance of CosignatureBuilder from binary payload.
     /// payload: Byte payload to use to serialize the object.
     /// # Returns
@@ -75,6 +89,14 @@ impl CosignatureBuilder {
         self.signature.clone()
     }
 
+    /// Checks the cosignature against the hash of an aggregate transaction.
+    /// parent_hash: Hash of the aggregate transaction.
+    /// # Returns
+    /// true if the signature was made by the cosigner over the hash.
+    pub fn verify(&self, parent_hash: &Hash256Dto) -> bool {
+        verify_signature(&self.signer_public_key, &parent_hash.0, &self.signature)
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -23,6 +23,7 @@ use super::cosignature_builder::*;
 use super::generator_utils::*;
 use super::hash256_dto::*;
 use super::key_dto::*;
+use super::key_pair::*;
 use super::signature_dto::*;
 
 /// Cosignature detached from an aggregate transaction.
@@ -36,6 +37,16 @@ pub struct DetachedCosignatureBuilder {
 
 
 impl DetachedCosignatureBuilder {
+    /// Creates a detached cosignature by signing the hash of an aggregate transaction.
+    /// key_pair: Cosigner key pair.
+    /// parent_hash: Hash of the aggregate transaction to cosign.
+    /// # Returns
+    /// A DetachedCosignatureBuilder.
+    pub fn create(key_pair: &KeyPair, parent_hash: Hash256Dto) -> Self {
+        let super_object = CosignatureBuilder::create(key_pair, &parent_hash);
+        DetachedCosignatureBuilder { super_object, parent_hash }
+    }
+
     /// Creates an instance of DetachedCosignatureBuilder from binary payload.
     /// payload: Byte payload to use to serialize the object.
     /// # Returns
@@ -48,6 +59,30 @@ impl DetachedCosignatureBuilder {
         DetachedCosignatureBuilder { super_object, parent_hash }
     }
 
+    /// Gets cosignature.
+    ///
+    /// # Returns
+    /// A Cosignature.
+    pub fn get_cosignature(&self) -> CosignatureBuilder {
+        self.super_object.clone()
+    }
+
+    /// Gets cosigner public key.
+    ///
+    /// # Returns
+    /// A Cosigner public key.
+    pub fn get_signer_public_key(&self) -> KeyDto {
+        self.super_object.get_signer_public_key()
+    }
+
+    /// Gets cosigner signature.
+    ///
+    /// # Returns
+    /// A Cosigner signature.
+    pub fn get_signature(&self) -> SignatureDto {
+        self.super_object.get_signature()
+    }
+
     /// Gets hash of the aggregate transaction that is signed by this cosignature.
     ///
     /// # Returns
@@ -56,6 +91,14 @@ impl DetachedCosignatureBuilder {
         self.parent_hash.clone()
     }
 
+    /// Checks that the cosignature signs its parent hash.
+    ///
+    /// # Returns
+    /// true if the signature was made by the cosigner over the parent hash.
+    pub fn verify(&self) -> bool {
+        self.super_object.verify(&self.parent_hash)
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
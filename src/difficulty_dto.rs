@@ -22,11 +22,17 @@
 use super::generator_utils::*;
 
 /// Difficulty.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct DifficultyDto(pub u64);
 
 impl DifficultyDto {
     pub const LENGTH: usize = std::mem::size_of::<Self>();
+    /// Smallest difficulty accepted by the node.
+    pub const MIN: DifficultyDto = DifficultyDto(10_000_000_000_000);
+    /// Difficulty of blocks without enough history.
+    pub const DEFAULT: DifficultyDto = DifficultyDto(100_000_000_000_000);
+    /// Largest difficulty accepted by the node.
+    pub const MAX: DifficultyDto = DifficultyDto(1_000_000_000_000_000);
 
     /// Gets the size of the type.
     ///
@@ -25,8 +25,13 @@ use super::embedded_transaction_helper::*;
 use super::entity_type_dto::*;
 use super::generator_utils::*;
 use super::key_dto::*;
+use super::metadata_entry_builder::*;
+use super::metadata_value_update::*;
 use super::network_type_dto::*;
+use super::scoped_metadata_key_dto::*;
 use super::unresolved_address_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for an embedded account metadata transaction.
 #[derive(Debug, Clone)]
@@ -71,8 +76,17 @@ impl EmbeddedAccountMetadataTransactionBuilder {
     pub fn get_scoped_metadata_key(&self) -> u64 {
         self.body.scoped_metadata_key.clone()
     }
-    pub fn set_scoped_metadata_key(&mut self, scoped_metadata_key: u64) {
-        self.body.scoped_metadata_key = scoped_metadata_key;   // MARKER1 AttributeKind.SIMPLE
+    pub fn set_scoped_metadata_key<K: Into<ScopedMetadataKeyDto>>(&mut self, scoped_metadata_key: K) {
+        self.body.scoped_metadata_key = scoped_metadata_key.into().get_scoped_metadata_key();   // MARKER1 AttributeKind.SIMPLE
+    }
+
+    /// Sets the scoped metadata key from a string key, hashed like the SDKs do.
+    /// scoped_metadata_key: String key.
+    /// # Returns
+    /// None when the key is empty.
+    pub fn set_scoped_metadata_key_str(&mut self, scoped_metadata_key: &str) -> Option<()> {
+        self.set_scoped_metadata_key(ScopedMetadataKeyDto::from_str_key(scoped_metadata_key)?);
+        Some(())
     }
 
 
@@ -91,6 +105,33 @@ impl EmbeddedAccountMetadataTransactionBuilder {
         self.body.value = value;   // MARKER1 AttributeKind.BUFFER
     }
 
+    /// Sets the value size delta and value turning the current value into a new value.
+    /// current: Current metadata entry or None when the key has no value yet.
+    /// new_value: Desired value.
+    /// # Returns
+    /// An error when a value or the size delta does not fit 16 bits.
+    pub fn set_value_update(&mut self, current: Option<&MetadataEntryBuilder>, new_value: &[u8]) -> Result<(), MetadataValueError> {
+        self.body.set_value_update(current, new_value)
+    }
+
+    /// Gets the value size delta and value as an update.
+    ///
+    /// # Returns
+    /// A Metadata value update.
+    pub fn get_value_update(&self) -> MetadataValueUpdate {
+        self.body.get_value_update()
+    }
+
+    /// Runs the stateless checks of the node on the transaction.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = self.super_object.validate(config);
+        failures.append(&mut self.body.validate(config));
+        failures
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -126,5 +167,9 @@ impl EmbeddedTransactionHelper for EmbeddedAccountMetadataTransactionBuilder {
     fn serializer(&self) -> Vec<u8> {
         self.serializer()
     }
+
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.validate(config)
+    }
 }
 
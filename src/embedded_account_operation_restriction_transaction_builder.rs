@@ -24,10 +24,11 @@ use super::account_restriction_flags_dto::*;
 use super::embedded_transaction_builder::*;
 use super::embedded_transaction_helper::*;
 use super::entity_type_dto::*;
-use super::entity_type_dto::*;
 use super::generator_utils::*;
 use super::key_dto::*;
 use super::network_type_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for an embedded account operation restriction transaction.
 #[derive(Debug, Clone)]
@@ -76,6 +77,16 @@ impl EmbeddedAccountOperationRestrictionTransactionBuilder {
     pub fn get_restriction_deletions(&self) -> Vec<EntityTypeDto> {
         self.body.restriction_deletions.clone()
     }
+    /// Runs the stateless checks of the node on the transaction.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = self.super_object.validate(config);
+        failures.append(&mut self.body.validate(config));
+        failures
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -111,5 +122,9 @@ impl EmbeddedTransactionHelper for EmbeddedAccountOperationRestrictionTransactio
     fn serializer(&self) -> Vec<u8> {
         self.serializer()
     }
+
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.validate(config)
+    }
 }
 
@@ -28,6 +28,8 @@ use super::mosaic_address_restriction_transaction_body_builder::*;
 use super::network_type_dto::*;
 use super::unresolved_address_dto::*;
 use super::unresolved_mosaic_id_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for an embedded mosaic address restriction transaction.
 #[derive(Debug, Clone)]
@@ -100,6 +102,14 @@ impl EmbeddedMosaicAddressRestrictionTransactionBuilder {
         self.body.target_address = target_address;   // MARKER1 AttributeKind.CUSTOM
     }
 
+    /// Runs the stateless checks of the node on the transaction.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.super_object.validate(config)
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -135,5 +145,9 @@ impl EmbeddedTransactionHelper for EmbeddedMosaicAddressRestrictionTransactionBu
     fn serializer(&self) -> Vec<u8> {
         self.serializer()
     }
+
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.validate(config)
+    }
 }
 
@@ -29,6 +29,8 @@ use super::mosaic_alias_transaction_body_builder::*;
 use super::mosaic_id_dto::*;
 use super::namespace_id_dto::*;
 use super::network_type_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for an embedded mosaic alias transaction.
 #[derive(Debug, Clone)]
@@ -85,6 +87,14 @@ impl EmbeddedMosaicAliasTransactionBuilder {
         self.body.alias_action = alias_action;   // MARKER1 AttributeKind.CUSTOM
     }
 
+    /// Runs the stateless checks of the node on the transaction.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.super_object.validate(config)
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -120,5 +130,9 @@ impl EmbeddedTransactionHelper for EmbeddedMosaicAliasTransactionBuilder {
     fn serializer(&self) -> Vec<u8> {
         self.serializer()
     }
+
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.validate(config)
+    }
 }
 
@@ -30,6 +30,8 @@ use super::mosaic_flags_dto::*;
 use super::mosaic_id_dto::*;
 use super::mosaic_nonce_dto::*;
 use super::network_type_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for an embedded mosaic definition transaction.
 #[derive(Debug, Clone)]
@@ -102,6 +104,16 @@ impl EmbeddedMosaicDefinitionTransactionBuilder {
         self.body.divisibility = divisibility;   // MARKER1 AttributeKind.SIMPLE
     }
 
+    /// Runs the stateless checks of the node on the transaction.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = self.super_object.validate(config);
+        failures.append(&mut self.body.validate(config));
+        failures
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -137,5 +149,9 @@ impl EmbeddedTransactionHelper for EmbeddedMosaicDefinitionTransactionBuilder {
     fn serializer(&self) -> Vec<u8> {
         self.serializer()
     }
+
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.validate(config)
+    }
 }
 
@@ -28,6 +28,8 @@ use super::mosaic_global_restriction_transaction_body_builder::*;
 use super::mosaic_restriction_type_dto::*;
 use super::network_type_dto::*;
 use super::unresolved_mosaic_id_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for an embedded mosaic global restriction transaction.
 #[derive(Debug, Clone)]
@@ -116,6 +118,14 @@ impl EmbeddedMosaicGlobalRestrictionTransactionBuilder {
         self.body.new_restriction_type = new_restriction_type;   // MARKER1 AttributeKind.CUSTOM
     }
 
+    /// Runs the stateless checks of the node on the transaction.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.super_object.validate(config)
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -151,5 +161,9 @@ impl EmbeddedTransactionHelper for EmbeddedMosaicGlobalRestrictionTransactionBui
     fn serializer(&self) -> Vec<u8> {
         self.serializer()
     }
+
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.validate(config)
+    }
 }
 
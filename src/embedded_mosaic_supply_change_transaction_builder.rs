@@ -29,6 +29,8 @@ use super::mosaic_supply_change_action_dto::*;
 use super::mosaic_supply_change_transaction_body_builder::*;
 use super::network_type_dto::*;
 use super::unresolved_mosaic_id_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for an embedded mosaic supply change transaction.
 #[derive(Debug, Clone)]
@@ -85,6 +87,16 @@ impl EmbeddedMosaicSupplyChangeTransactionBuilder {
         self.body.action = action;   // MARKER1 AttributeKind.CUSTOM
     }
 
+    /// Runs the stateless checks of the node on the transaction.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = self.super_object.validate(config);
+        failures.append(&mut self.body.validate(config));
+        failures
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -120,5 +132,9 @@ impl EmbeddedTransactionHelper for EmbeddedMosaicSupplyChangeTransactionBuilder
     fn serializer(&self) -> Vec<u8> {
         self.serializer()
     }
+
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.validate(config)
+    }
 }
 
@@ -27,6 +27,8 @@ use super::key_dto::*;
 use super::multisig_account_modification_transaction_body_builder::*;
 use super::network_type_dto::*;
 use super::unresolved_address_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for an embedded multisig account modification transaction.
 #[derive(Debug, Clone)]
@@ -83,6 +85,16 @@ impl EmbeddedMultisigAccountModificationTransactionBuilder {
     pub fn get_address_deletions(&self) -> Vec<UnresolvedAddressDto> {
         self.body.address_deletions.clone()
     }
+    /// Runs the stateless checks of the node on the transaction.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = self.super_object.validate(config);
+        failures.append(&mut self.body.validate(config));
+        failures
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -118,5 +130,9 @@ impl EmbeddedTransactionHelper for EmbeddedMultisigAccountModificationTransactio
     fn serializer(&self) -> Vec<u8> {
         self.serializer()
     }
+
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.validate(config)
+    }
 }
 
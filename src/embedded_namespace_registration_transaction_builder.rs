@@ -29,6 +29,8 @@ use super::namespace_id_dto::*;
 use super::namespace_registration_transaction_body_builder::*;
 use super::namespace_registration_type_dto::*;
 use super::network_type_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for an embedded namespace registration transaction.
 #[derive(Debug, Clone)]
@@ -101,6 +103,16 @@ impl EmbeddedNamespaceRegistrationTransactionBuilder {
         self.body.name = name;   // MARKER1 AttributeKind.BUFFER
     }
 
+    /// Runs the stateless checks of the node on the transaction.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = self.super_object.validate(config);
+        failures.append(&mut self.body.validate(config));
+        failures
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -136,5 +148,9 @@ impl EmbeddedTransactionHelper for EmbeddedNamespaceRegistrationTransactionBuild
     fn serializer(&self) -> Vec<u8> {
         self.serializer()
     }
+
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.validate(config)
+    }
 }
 
@@ -27,6 +27,8 @@ use super::key_dto::*;
 use super::link_action_dto::*;
 use super::network_type_dto::*;
 use super::node_key_link_transaction_body_builder::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for an embedded node key link transaction.
 #[derive(Debug, Clone)]
@@ -75,6 +77,14 @@ impl EmbeddedNodeKeyLinkTransactionBuilder {
         self.body.link_action = link_action;   // MARKER1 AttributeKind.CUSTOM
     }
 
+    /// Runs the stateless checks of the node on the transaction.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.super_object.validate(config)
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -110,5 +120,9 @@ impl EmbeddedTransactionHelper for EmbeddedNodeKeyLinkTransactionBuilder {
     fn serializer(&self) -> Vec<u8> {
         self.serializer()
     }
+
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.validate(config)
+    }
 }
 
@@ -31,6 +31,8 @@ use super::network_type_dto::*;
 use super::secret_lock_transaction_body_builder::*;
 use super::unresolved_address_dto::*;
 use super::unresolved_mosaic_builder::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for an embedded secret lock transaction.
 #[derive(Debug, Clone)]
@@ -103,6 +105,16 @@ impl EmbeddedSecretLockTransactionBuilder {
         self.body.hash_algorithm = hash_algorithm;   // MARKER1 AttributeKind.CUSTOM
     }
 
+    /// Runs the stateless checks of the node on the transaction.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = self.super_object.validate(config);
+        failures.append(&mut self.body.validate(config));
+        failures
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -138,5 +150,9 @@ impl EmbeddedTransactionHelper for EmbeddedSecretLockTransactionBuilder {
     fn serializer(&self) -> Vec<u8> {
         self.serializer()
     }
+
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.validate(config)
+    }
 }
 
@@ -19,6 +19,7 @@
  * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
  */
 
+use super::embedded_secret_lock_transaction_builder::*;
 use super::embedded_transaction_builder::*;
 use super::embedded_transaction_helper::*;
 use super::entity_type_dto::*;
@@ -29,6 +30,8 @@ use super::lock_hash_algorithm_dto::*;
 use super::network_type_dto::*;
 use super::secret_proof_transaction_body_builder::*;
 use super::unresolved_address_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for an embedded secret proof transaction.
 #[derive(Debug, Clone)]
@@ -93,6 +96,24 @@ impl EmbeddedSecretProofTransactionBuilder {
         self.body.proof = proof;   // MARKER1 AttributeKind.BUFFER
     }
 
+    /// Checks that the proof unlocks a secret lock.
+    /// secret_lock: Secret lock transaction.
+    /// # Returns
+    /// True if the proof unlocks the secret lock.
+    pub fn matches(&self, secret_lock: &EmbeddedSecretLockTransactionBuilder) -> bool {
+        self.body.matches(&secret_lock.body)
+    }
+
+    /// Runs the stateless checks of the node on the transaction.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = self.super_object.validate(config);
+        failures.append(&mut self.body.validate(config));
+        failures
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -128,5 +149,9 @@ impl EmbeddedTransactionHelper for EmbeddedSecretProofTransactionBuilder {
     fn serializer(&self) -> Vec<u8> {
         self.serializer()
     }
+
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.validate(config)
+    }
 }
 
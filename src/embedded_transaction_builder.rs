@@ -19,10 +19,15 @@
  * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
  */
 
+use sha3::{Digest, Sha3_256};
+
 use super::entity_type_dto::*;
 use super::generator_utils::*;
+use super::hash256_dto::*;
 use super::key_dto::*;
 use super::network_type_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for an embedded transaction.
 #[derive(Debug, Clone)]
@@ -64,6 +69,21 @@ impl EmbeddedTransactionBuilder {
         EmbeddedTransactionBuilder { signer_public_key, version, network, _type }
     }
 
+    /// Runs the stateless checks of the node on the embedded transaction header.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the header is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = vec![];
+        if self.network != config.network {
+            failures.push(ValidationFailure::Failure_Core_Wrong_Network);
+        }
+        if let EntityTypeDto::AGGREGATE_COMPLETE_TRANSACTION | EntityTypeDto::AGGREGATE_BONDED_TRANSACTION = self._type {
+            failures.push(ValidationFailure::Failure_Aggregate_Nested_Aggregate);
+        }
+        failures
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -95,5 +115,12 @@ impl EmbeddedTransactionBuilder {
         buf.append(&mut self._type.serializer()); // kind:CUSTOM
         buf
     }
-}
 
+    /// Calculates the hash of a serialized embedded transaction (padding excluded).
+    /// payload: Serialized embedded transaction.
+    /// # Returns
+    /// An embedded transaction hash.
+    pub fn calculate_transaction_hash(payload: &[u8]) -> Hash256Dto {
+        Hash256Dto::from_binary(&Sha3_256::digest(payload))
+    }
+}
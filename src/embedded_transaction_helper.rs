@@ -22,6 +22,8 @@
 use std::fmt::Debug;
 
 use crate::embedded_transaction_builder::EmbeddedTransactionBuilder;
+use crate::validation_config::ValidationConfig;
+use crate::validation_failure::ValidationFailure;
 
 use super::embedded_account_address_restriction_transaction_builder::EmbeddedAccountAddressRestrictionTransactionBuilder;
 use super::embedded_account_key_link_transaction_builder::EmbeddedAccountKeyLinkTransactionBuilder;
@@ -51,6 +53,12 @@ pub trait EmbeddedTransactionHelper: Debug + Send + Sync {
     fn box_clone(&self) -> Box<dyn EmbeddedTransactionHelper>;
     fn get_size(&self) -> usize;
     fn serializer(&self) -> Vec<u8>;
+
+    /// Runs the stateless checks of the node on the embedded transaction.
+    /// Only the header is checked unless the implementation overrides it.
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        EmbeddedTransactionBuilder::from_binary(&self.serializer()).validate(config)
+    }
 }
 
 impl Clone for Box<dyn EmbeddedTransactionHelper + 'static> {
@@ -27,6 +27,8 @@ use super::generator_utils::*;
 use super::key_dto::*;
 use super::link_action_dto::*;
 use super::network_type_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 use super::voting_key_dto::*;
 use super::voting_key_link_transaction_body_builder::*;
 
@@ -93,6 +95,16 @@ impl EmbeddedVotingKeyLinkTransactionBuilder {
         self.body.link_action = link_action;   // MARKER1 AttributeKind.CUSTOM
     }
 
+    /// Runs the stateless checks of the node on the transaction.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = self.super_object.validate(config);
+        failures.append(&mut self.body.validate(config));
+        failures
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -128,5 +140,9 @@ impl EmbeddedTransactionHelper for EmbeddedVotingKeyLinkTransactionBuilder {
     fn serializer(&self) -> Vec<u8> {
         self.serializer()
     }
+
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.validate(config)
+    }
 }
 
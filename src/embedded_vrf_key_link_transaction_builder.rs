@@ -26,6 +26,8 @@ use super::generator_utils::*;
 use super::key_dto::*;
 use super::link_action_dto::*;
 use super::network_type_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 use super::vrf_key_link_transaction_body_builder::*;
 
 /// Binary layout for an embedded vrf key link transaction.
@@ -75,6 +77,14 @@ impl EmbeddedVrfKeyLinkTransactionBuilder {
         self.body.link_action = link_action;   // MARKER1 AttributeKind.CUSTOM
     }
 
+    /// Runs the stateless checks of the node on the transaction.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.super_object.validate(config)
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -110,5 +120,9 @@ impl EmbeddedTransactionHelper for EmbeddedVrfKeyLinkTransactionBuilder {
     fn serializer(&self) -> Vec<u8> {
         self.serializer()
     }
+
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.validate(config)
+    }
 }
 
@@ -0,0 +1,87 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use num_derive::{FromPrimitive, ToPrimitive};
+use num_traits::{FromPrimitive, ToPrimitive};
+use strum_macros::EnumIter;
+
+/// Enumeration of validation result facilities.
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, FromPrimitive, ToPrimitive, EnumIter)]
+pub enum FacilityCodeDto {
+    /// Base facility of the generic results.
+    Base = 0x00,
+
+    /// Aggregate facility.
+    Aggregate = 0x41,
+
+    /// Core facility.
+    Core = 0x43,
+
+    /// Metadata facility.
+    Metadata = 0x44,
+
+    /// Hash lock facility.
+    LockHash = 0x48,
+
+    /// Account link facility.
+    AccountLink = 0x4C,
+
+    /// Mosaic facility.
+    Mosaic = 0x4D,
+
+    /// Namespace facility.
+    Namespace = 0x4E,
+
+    /// Account restriction facility.
+    RestrictionAccount = 0x50,
+
+    /// Mosaic restriction facility.
+    RestrictionMosaic = 0x51,
+
+    /// Secret lock facility.
+    LockSecret = 0x52,
+
+    /// Signature facility.
+    Signature = 0x53,
+
+    /// Transfer facility.
+    Transfer = 0x54,
+
+    /// Multisig facility.
+    Multisig = 0x55,
+
+    /// Chain facility.
+    Chain = 0xFF,
+
+}
+
+impl FacilityCodeDto {
+    /// Gets the value of the enum.
+    ///
+    /// # Returns
+    ///
+    /// A u8
+    pub fn get_value(&self) -> u8 {
+        self.to_u8().unwrap()
+    }
+}
@@ -22,7 +22,7 @@
 use super::generator_utils::*;
 
 /// Hash256.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Hash256Dto(pub [u8; 32]);
 
 impl Hash256Dto {
@@ -22,7 +22,7 @@
 use super::generator_utils::*;
 
 /// Hash512.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Hash512Dto(pub [u8; 64]);
 
 impl Hash512Dto {
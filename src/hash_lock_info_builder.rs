@@ -20,6 +20,7 @@
  */
 
 use super::address_dto::*;
+use super::aggregate_bonded_transaction_builder::*;
 use super::generator_utils::*;
 use super::hash256_dto::*;
 use super::height_dto::*;
@@ -106,6 +107,16 @@ impl HashLockInfoBuilder {
         self.hash.clone()
     }
 
+    /// Checks that the lock was created for an aggregate bonded transaction.
+    /// Only the hash is compared; the lock status is not checked.
+    /// aggregate: Aggregate bonded transaction.
+    /// generation_hash_seed: Network generation hash seed.
+    /// # Returns
+    /// True if the lock hash is the aggregate hash.
+    pub fn matches(&self, aggregate: &AggregateBondedTransactionBuilder, generation_hash_seed: &Hash256Dto) -> bool {
+        self.hash == aggregate.get_transaction_hash(generation_hash_seed)
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
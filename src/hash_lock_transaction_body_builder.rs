@@ -23,6 +23,8 @@ use super::block_duration_dto::*;
 use super::generator_utils::*;
 use super::hash256_dto::*;
 use super::unresolved_mosaic_builder::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for a hash lock transaction.
 #[derive(Debug, Clone)]
@@ -52,6 +54,20 @@ impl HashLockTransactionBodyBuilder {
         HashLockTransactionBodyBuilder { mosaic, duration, hash } // TransactionBody
     }
 
+    /// Runs the stateless checks of the node on the body.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the body is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = vec![];
+        let duration = self.duration.get_block_duration();
+        if duration == 0 || duration > config.max_hash_lock_duration {
+            failures.push(ValidationFailure::Failure_LockHash_Invalid_Duration);
+        }
+        failures
+    }
+
+
     /// Gets the size of the type.
     ///
     /// Returns:
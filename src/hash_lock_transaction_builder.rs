@@ -19,6 +19,7 @@
  * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
  */
 
+use super::aggregate_bonded_transaction_builder::*;
 use super::amount_dto::*;
 use super::block_duration_dto::*;
 use super::entity_type_dto::*;
@@ -30,7 +31,10 @@ use super::network_type_dto::*;
 use super::signature_dto::*;
 use super::timestamp_dto::*;
 use super::transaction_builder::*;
+use super::transaction_helper::*;
 use super::unresolved_mosaic_builder::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for a non-embedded hash lock transaction.
 #[derive(Debug, Clone)]
@@ -45,6 +49,31 @@ impl HashLockTransactionBuilder {
     const VERSION: u8 = 1;
     const ENTITY_TYPE: u16 = 0x4148;
 
+    /// Default amount of currency locked per aggregate bonded transaction (10 units with divisibility 6).
+    pub const LOCKED_FUNDS_PER_AGGREGATE: u64 = 10_000_000;
+
+    /// Creates an unsigned hash lock for an aggregate bonded transaction.
+    /// The lock is signed by the aggregate signer on the aggregate network and shares its deadline;
+    /// the fee is left at zero.
+    /// aggregate: Aggregate bonded transaction to lock.
+    /// generation_hash_seed: Network generation hash seed.
+    /// mosaic: Locked currency mosaic, usually `LOCKED_FUNDS_PER_AGGREGATE` units.
+    /// duration: Number of blocks for which the lock is valid.
+    /// # Returns
+    /// A HashLockTransactionBuilder.
+    pub fn for_aggregate(aggregate: &AggregateBondedTransactionBuilder, generation_hash_seed: &Hash256Dto, mosaic: UnresolvedMosaicBuilder, duration: BlockDurationDto) -> Self {
+        let super_object = TransactionBuilder {
+            signature: SignatureDto([0u8; 64]),
+            signer_public_key: aggregate.super_object.signer_public_key,
+            version: Self::VERSION,
+            network: aggregate.super_object.network,
+            _type: EntityTypeDto::HASH_LOCK_TRANSACTION,
+            fee: AmountDto(0),
+            deadline: aggregate.super_object.deadline,
+        };
+        let hash = aggregate.get_transaction_hash(generation_hash_seed);
+        HashLockTransactionBuilder { super_object, body: HashLockTransactionBodyBuilder { mosaic, duration, hash } }
+    }
 
     /// Creates an instance of HashLockTransactionBuilder from binary payload.
     /// payload: Byte payload to use to serialize the object.
@@ -86,6 +115,16 @@ impl HashLockTransactionBuilder {
         self.body.hash = hash;   // MARKER1 AttributeKind.CUSTOM
     }
 
+    /// Runs the stateless checks of the node on the transaction.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = self.super_object.validate(config);
+        failures.append(&mut self.body.validate(config));
+        failures
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -109,3 +148,20 @@ impl HashLockTransactionBuilder {
     }
 }
 
+impl TransactionHelper for HashLockTransactionBuilder {
+    fn box_clone(&self) -> Box<dyn TransactionHelper> {
+        Box::new((*self).clone())
+    }
+
+    fn get_size(&self) -> usize {
+        self.get_size()
+    }
+
+    fn serializer(&self) -> Vec<u8> {
+        self.serializer()
+    }
+
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.validate(config)
+    }
+}
@@ -22,7 +22,7 @@
 use super::generator_utils::*;
 
 /// Height.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct HeightDto(pub u64);
 
 impl HeightDto {
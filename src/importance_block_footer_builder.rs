@@ -27,13 +27,13 @@ use super::hash256_dto::*;
 #[derive(Debug, Clone)]
 pub struct ImportanceBlockFooterBuilder {
     /// Number of voting eligible accounts.
-    voting_eligible_accounts_count: u32,
+    pub voting_eligible_accounts_count: u32,
     /// Number of harvesting eligible accounts.
-    harvesting_eligible_accounts_count: u64,
+    pub harvesting_eligible_accounts_count: u64,
     /// Total balance eligible for voting.
-    total_voting_balance: AmountDto,
+    pub total_voting_balance: AmountDto,
     /// Previous importance block hash.
-    previous_importance_block_hash: Hash256Dto,
+    pub previous_importance_block_hash: Hash256Dto,
 }
 
 
@@ -33,15 +33,17 @@ use super::key_dto::*;
 use super::network_type_dto::*;
 use super::signature_dto::*;
 use super::timestamp_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 use super::vrf_proof_builder::*;
 
 /// Binary layout for an importance block header.
 #[derive(Debug, Clone)]
 pub struct ImportanceBlockHeaderBuilder {
     /// Block header.
-    super_object: BlockHeaderBuilder,
+    pub super_object: BlockHeaderBuilder,
     /// Importance block footer.
-    importance_block_footer: ImportanceBlockFooterBuilder,
+    pub importance_block_footer: ImportanceBlockFooterBuilder,
 }
 
 
@@ -90,6 +92,14 @@ impl ImportanceBlockHeaderBuilder {
         self.importance_block_footer.get_previous_importance_block_hash()
     }
 
+    /// Runs the stateless checks of the node on the block header.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the header is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.super_object.validate(config)
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -106,6 +116,7 @@ impl ImportanceBlockHeaderBuilder {
     /// A Serialized bytes.
     pub fn serializer(&self) -> Vec<u8> {
         let mut buf: Vec<u8> = vec![];
+        buf.append(&mut (self.get_size() as u32).to_le_bytes().to_vec());
         buf.append(&mut self.super_object.serializer());
         buf.append(&mut self.importance_block_footer.serializer()); // kind:CUSTOM
         buf
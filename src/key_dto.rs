@@ -22,7 +22,7 @@
 use super::generator_utils::*;
 
 /// Key.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct KeyDto(pub [u8; 32]);
 
 impl KeyDto {
@@ -0,0 +1,103 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use std::convert::TryFrom;
+
+use ed25519_dalek::{PublicKey, SecretKey, Signer};
+use rand::rngs::OsRng;
+
+use super::key_dto::*;
+use super::signature_dto::*;
+
+/// Ed25519 key pair used to sign entities.
+#[derive(Debug)]
+pub struct KeyPair {
+    /// Underlying ed25519 key pair.
+    key_pair: ed25519_dalek::Keypair,
+}
+
+impl KeyPair {
+    /// Creates an instance of KeyPair from a private key.
+    /// private_key: Private key (ed25519 seed).
+    /// # Returns
+    /// A KeyPair.
+    pub fn from_private_key(private_key: [u8; 32]) -> Self {
+        let secret = SecretKey::from_bytes(&private_key).unwrap();
+        let public = PublicKey::from(&secret);
+        KeyPair { key_pair: ed25519_dalek::Keypair { secret, public } }
+    }
+
+    /// Creates a KeyPair from a random private key.
+    ///
+    /// # Returns
+    /// A KeyPair.
+    pub fn random() -> Self {
+        KeyPair { key_pair: ed25519_dalek::Keypair::generate(&mut OsRng) }
+    }
+
+    /// Gets private key.
+    ///
+    /// # Returns
+    /// A Private key.
+    pub fn get_private_key(&self) -> [u8; 32] {
+        self.key_pair.secret.to_bytes()
+    }
+
+    /// Gets public key.
+    ///
+    /// # Returns
+    /// A Public key.
+    pub fn get_public_key(&self) -> KeyDto {
+        KeyDto(self.key_pair.public.to_bytes())
+    }
+
+    /// Signs data with the private key.
+    /// data: Data to sign.
+    /// # Returns
+    /// A Signature.
+    pub fn sign(&self, data: &[u8]) -> SignatureDto {
+        SignatureDto(self.key_pair.sign(data).to_bytes())
+    }
+}
+
+impl Clone for KeyPair {
+    fn clone(&self) -> Self {
+        Self::from_private_key(self.get_private_key())
+    }
+}
+
+/// Verifies a signature.
+/// public_key: Public key of the signer.
+/// data: Signed data.
+/// signature: Signature to verify.
+/// # Returns
+/// true if the signature is valid for the data and the public key.
+pub fn verify_signature(public_key: &KeyDto, data: &[u8], signature: &SignatureDto) -> bool {
+    let public = match PublicKey::from_bytes(&public_key.0) {
+        Ok(public) => public,
+        Err(_) => return false,
+    };
+    let signature = match ed25519_dalek::Signature::try_from(&signature.0[..]) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+    public.verify_strict(data, &signature).is_ok()
+}
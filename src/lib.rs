@@ -12,6 +12,7 @@ pub mod account_mosaic_restriction_transaction_builder;
 pub mod account_operation_restriction_transaction_body_builder;
 pub mod account_operation_restriction_transaction_builder;
 pub mod account_restriction_address_value_builder;
+pub mod account_restriction_evaluator;
 pub mod account_restriction_flags_dto;
 pub mod account_restriction_mosaic_value_builder;
 pub mod account_restriction_transaction_type_value_builder;
@@ -31,12 +32,16 @@ pub mod aggregate_bonded_transaction_builder;
 pub mod aggregate_complete_transaction_builder;
 pub mod aggregate_transaction_body_builder;
 pub mod alias_action_dto;
+pub mod alias_resolver;
 pub mod amount_dto;
+pub mod atomic_swap;
 pub mod balance_change_receipt_builder;
 pub mod balance_transfer_receipt_builder;
+pub mod block;
 pub mod block_duration_dto;
 pub mod block_fee_multiplier_dto;
 pub mod block_header_builder;
+pub mod block_statement_builder;
 pub mod cosignature_builder;
 pub mod detached_cosignature_builder;
 pub mod difficulty_dto;
@@ -65,6 +70,7 @@ pub mod embedded_transfer_transaction_builder;
 pub mod embedded_voting_key_link_transaction_builder;
 pub mod embedded_vrf_key_link_transaction_builder;
 pub mod entity_type_dto;
+pub mod facility_code_dto;
 pub mod finalization_epoch_dto;
 pub mod finalization_point_dto;
 pub mod finalization_round_builder;
@@ -87,12 +93,18 @@ pub mod importance_height_dto;
 pub mod importance_snapshot_builder;
 pub mod inflation_receipt_builder;
 pub mod key_dto;
+pub mod key_pair;
 pub mod link_action_dto;
 pub mod lock_hash_algorithm_dto;
 pub mod lock_status_dto;
+pub mod merkle_hash;
+#[cfg(feature = "crypto")]
+pub mod message_cipher;
+pub mod message_type_dto;
 pub mod metadata_entry_builder;
 pub mod metadata_type_dto;
 pub mod metadata_value_builder;
+pub mod metadata_value_update;
 pub mod mosaic_address_restriction_entry_builder;
 pub mod mosaic_address_restriction_transaction_body_builder;
 pub mod mosaic_address_restriction_transaction_builder;
@@ -117,6 +129,7 @@ pub mod mosaic_resolution_entry_builder;
 pub mod mosaic_resolution_statement_builder;
 pub mod mosaic_restriction_entry_builder;
 pub mod mosaic_restriction_entry_type_dto;
+pub mod mosaic_restriction_evaluator;
 pub mod mosaic_restriction_key_dto;
 pub mod mosaic_restriction_type_dto;
 pub mod mosaic_supply_change_action_dto;
@@ -141,14 +154,18 @@ pub mod network_type_dto;
 pub mod node_key_link_transaction_body_builder;
 pub mod node_key_link_transaction_builder;
 pub mod normal_block_header_builder;
+#[cfg(feature = "crypto")]
+pub mod persistent_harvesting_delegation;
 pub mod pinned_voting_key_builder;
 pub mod proof_gamma_dto;
 pub mod proof_scalar_dto;
 pub mod proof_verification_hash_dto;
+pub mod receipt;
 pub mod receipt_builder;
 pub mod receipt_source_builder;
 pub mod receipt_type_dto;
 pub mod restriction_rule_builder;
+pub mod result_severity_dto;
 pub mod root_namespace_history_builder;
 pub mod scoped_metadata_key_dto;
 pub mod secret_lock_info_builder;
@@ -158,16 +175,26 @@ pub mod secret_proof_transaction_body_builder;
 pub mod secret_proof_transaction_builder;
 pub mod signature_dto;
 pub mod state_header_builder;
+pub mod state_provider;
+pub mod stateful_validator;
 pub mod timestamp_dto;
 pub mod transaction_builder;
+pub mod transaction_helper;
+pub mod transaction_statement_builder;
+pub mod transfer_message;
 pub mod transfer_transaction_body_builder;
 pub mod transfer_transaction_builder;
 pub mod unresolved_address_dto;
 pub mod unresolved_mosaic_builder;
 pub mod unresolved_mosaic_id_dto;
+pub mod validation_config;
+pub mod validation_failure;
+pub mod validation_result_dto;
 pub mod voting_key_dto;
 pub mod voting_key_link_transaction_body_builder;
 pub mod voting_key_link_transaction_builder;
 pub mod vrf_key_link_transaction_body_builder;
 pub mod vrf_key_link_transaction_builder;
+#[cfg(feature = "crypto")]
+pub mod vrf;
 pub mod vrf_proof_builder;
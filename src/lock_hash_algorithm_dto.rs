@@ -21,9 +21,15 @@
 
 use num_derive::{FromPrimitive, ToPrimitive};
 use num_traits::{FromPrimitive, ToPrimitive};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use ripemd160::Ripemd160;
+use sha2::Sha256;
+use sha3::{Digest, Sha3_256};
 use strum_macros::EnumIter;
 
 use super::generator_utils::*;
+use super::hash256_dto::*;
 
 /// Enumeration of lock hash algorithms.
 #[allow(non_camel_case_types)]
@@ -44,6 +50,9 @@ pub enum LockHashAlgorithmDto {
 impl LockHashAlgorithmDto {
     pub const LENGTH: usize = std::mem::size_of::<Self>();
 
+    /// Size of the proofs created by `generate_proof`.
+    pub const PROOF_SIZE: usize = 32;
+
     /// Gets the size of the type.
     ///
     /// # Returns
@@ -82,4 +91,41 @@ impl LockHashAlgorithmDto {
     pub fn serializer(&self) -> Vec<u8> {
         self.get_value().to_le_bytes().to_vec()
     }
+
+    /// Hashes a proof into the secret used by secret lock transactions.
+    /// HASH_160 results are 20 bytes long and are padded with zeros to 32 bytes.
+    /// proof: Proof data.
+    /// # Returns
+    /// A Secret.
+    pub fn hash(&self, proof: &[u8]) -> Hash256Dto {
+        let mut secret = [0u8; Hash256Dto::LENGTH];
+        match self {
+            LockHashAlgorithmDto::SHA3_256 => secret.copy_from_slice(&Sha3_256::digest(proof)),
+            LockHashAlgorithmDto::HASH_160 => {
+                let hash = Ripemd160::digest(&Sha256::digest(proof));
+                secret[..hash.len()].copy_from_slice(&hash);
+            }
+            LockHashAlgorithmDto::HASH_256 => secret.copy_from_slice(&Sha256::digest(&Sha256::digest(proof))),
+        }
+        Hash256Dto(secret)
+    }
+
+    /// Creates a random proof.
+    ///
+    /// # Returns
+    /// A Proof of `PROOF_SIZE` bytes.
+    pub fn generate_proof() -> Vec<u8> {
+        let mut proof = vec![0u8; Self::PROOF_SIZE];
+        OsRng.fill_bytes(&mut proof);
+        proof
+    }
+
+    /// Checks that a proof hashes into a secret.
+    /// secret: Secret.
+    /// proof: Proof data.
+    /// # Returns
+    /// True if the proof unlocks the secret.
+    pub fn verify(&self, secret: &Hash256Dto, proof: &[u8]) -> bool {
+        self.hash(proof) == *secret
+    }
 }
@@ -0,0 +1,49 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use sha3::{Digest, Sha3_256};
+
+use super::hash256_dto::*;
+
+/// Calculates the merkle root of a list of hashes the way Catapult does.
+/// When a level has an odd number of hashes, the last one is paired with itself.
+/// hashes: Leaf hashes.
+/// # Returns
+/// A merkle root (zero hash when there are no leaves).
+pub fn calculate_merkle_hash(hashes: &[Hash256Dto]) -> Hash256Dto {
+    if hashes.is_empty() {
+        return Hash256Dto([0u8; 32]);
+    }
+
+    let mut level = hashes.to_vec();
+    while level.len() > 1 {
+        if level.len() % 2 == 1 {
+            level.push(level[level.len() - 1]);
+        }
+        level = level.chunks(2).map(|pair| {
+            let mut hasher = Sha3_256::new();
+            hasher.update(pair[0].0);
+            hasher.update(pair[1].0);
+            Hash256Dto::from_binary(&hasher.finalize())
+        }).collect();
+    }
+    level[0]
+}
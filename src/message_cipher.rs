@@ -0,0 +1,163 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use std::fmt;
+
+use aes_gcm::aead::{AeadInPlace, NewAead};
+use aes_gcm::Aes256Gcm;
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use curve25519_dalek::scalar::Scalar;
+use hkdf::Hkdf;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use sha2::{Digest, Sha256, Sha512};
+
+use super::generator_utils::*;
+use super::key_dto::*;
+use super::key_pair::*;
+
+/// Size of the AES-GCM authentication tag.
+pub const AES_TAG_SIZE: usize = 16;
+/// Size of the AES-GCM initialization vector.
+pub const AES_IV_SIZE: usize = 12;
+
+const HKDF_INFO: &[u8] = b"catapult";
+
+/// Errors raised when encrypting or decrypting messages.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MessageCipherError {
+    /// Public key is not a valid curve point.
+    InvalidPublicKey(KeyDto),
+    /// Encrypted payload is malformed.
+    InvalidPayload,
+    /// Payload could not be authenticated with the shared key.
+    DecryptionFailed,
+    /// Message is not of the expected type.
+    UnexpectedMessageType,
+}
+
+impl fmt::Display for MessageCipherError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MessageCipherError::InvalidPublicKey(key) => write!(f, "{:?} is not a valid public key", key),
+            MessageCipherError::InvalidPayload => write!(f, "encrypted payload is malformed"),
+            MessageCipherError::DecryptionFailed => write!(f, "encrypted payload could not be authenticated"),
+            MessageCipherError::UnexpectedMessageType => write!(f, "message is not of the expected type"),
+        }
+    }
+}
+
+impl std::error::Error for MessageCipherError {}
+
+/// Derives the AES key shared by a key pair and another account.
+/// The ed25519 scalar of the key pair is multiplied by the other public key and
+/// the compressed point is expanded with HKDF-SHA256 (zero salt, "catapult" info).
+/// key_pair: Key pair of one party.
+/// other_public_key: Public key of the other party.
+/// # Returns
+/// A Shared key.
+pub fn derive_shared_key(key_pair: &KeyPair, other_public_key: &KeyDto) -> Result<[u8; 32], MessageCipherError> {
+    let point = CompressedEdwardsY(other_public_key.0)
+        .decompress()
+        .ok_or(MessageCipherError::InvalidPublicKey(*other_public_key))?;
+
+    let hash = Sha512::digest(&key_pair.get_private_key());
+    let mut scalar_bytes = [0u8; 32];
+    scalar_bytes.copy_from_slice(&hash[..32]);
+    scalar_bytes[0] &= 0xF8;
+    scalar_bytes[31] &= 0x7F;
+    scalar_bytes[31] |= 0x40;
+    let shared_secret = (Scalar::from_bits(scalar_bytes) * point).compress();
+
+    let mut shared_key = [0u8; 32];
+    Hkdf::<Sha256>::new(Some(&[0u8; 32]), shared_secret.as_bytes())
+        .expand(HKDF_INFO, &mut shared_key)
+        .map_err(|_| MessageCipherError::InvalidPayload)?;
+    Ok(shared_key)
+}
+
+/// Encrypts data with AES-256-GCM using a random initialization vector.
+/// key: Encryption key.
+/// data: Data to encrypt.
+/// # Returns
+/// A tag, initialization vector and cipher text concatenation.
+pub fn encrypt_aes_gcm(key: &[u8; 32], data: &[u8]) -> Vec<u8> {
+    let mut iv = [0u8; AES_IV_SIZE];
+    OsRng.fill_bytes(&mut iv);
+    encrypt_aes_gcm_with_iv(key, &iv, data)
+}
+
+/// Encrypts data with AES-256-GCM using a given initialization vector.
+/// An initialization vector must never be reused with the same key.
+/// key: Encryption key.
+/// iv: Initialization vector.
+/// data: Data to encrypt.
+/// # Returns
+/// A tag, initialization vector and cipher text concatenation.
+pub fn encrypt_aes_gcm_with_iv(key: &[u8; 32], iv: &[u8; AES_IV_SIZE], data: &[u8]) -> Vec<u8> {
+    let cipher = Aes256Gcm::new(key.into());
+    let mut cipher_text = data.to_vec();
+    let tag = cipher
+        .encrypt_in_place_detached(iv.into(), &[], &mut cipher_text)
+        .expect("AES-GCM encryption cannot fail for transfer message sizes");
+    [tag.to_vec(), iv.to_vec(), cipher_text].concat()
+}
+
+/// Decrypts data encrypted by `encrypt_aes_gcm`.
+/// key: Encryption key.
+/// payload: Tag, initialization vector and cipher text concatenation.
+/// # Returns
+/// A Decrypted data.
+pub fn decrypt_aes_gcm(key: &[u8; 32], payload: &[u8]) -> Result<Vec<u8>, MessageCipherError> {
+    if payload.len() < AES_TAG_SIZE + AES_IV_SIZE {
+        return Err(MessageCipherError::InvalidPayload);
+    }
+    let tag = fixed_bytes::<AES_TAG_SIZE>(payload);
+    let iv = fixed_bytes::<AES_IV_SIZE>(&payload[AES_TAG_SIZE..]);
+    let cipher = Aes256Gcm::new(key.into());
+    let mut data = payload[AES_TAG_SIZE + AES_IV_SIZE..].to_vec();
+    cipher
+        .decrypt_in_place_detached((&iv).into(), &[], &mut data, (&tag).into())
+        .map_err(|_| MessageCipherError::DecryptionFailed)?;
+    Ok(data)
+}
+
+/// Encrypts a message for a recipient.
+/// key_pair: Sender key pair.
+/// recipient_public_key: Recipient public key.
+/// message: Clear message.
+/// # Returns
+/// A tag, initialization vector and cipher text concatenation.
+pub fn encrypt_message(key_pair: &KeyPair, recipient_public_key: &KeyDto, message: &[u8]) -> Result<Vec<u8>, MessageCipherError> {
+    let shared_key = derive_shared_key(key_pair, recipient_public_key)?;
+    Ok(encrypt_aes_gcm(&shared_key, message))
+}
+
+/// Decrypts a message exchanged with another account.
+/// key_pair: Key pair of the sender or the recipient.
+/// other_public_key: Public key of the other party.
+/// payload: Tag, initialization vector and cipher text concatenation.
+/// # Returns
+/// A Clear message.
+pub fn decrypt_message(key_pair: &KeyPair, other_public_key: &KeyDto, payload: &[u8]) -> Result<Vec<u8>, MessageCipherError> {
+    let shared_key = derive_shared_key(key_pair, other_public_key)?;
+    decrypt_aes_gcm(&shared_key, payload)
+}
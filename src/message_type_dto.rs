@@ -0,0 +1,85 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use num_derive::{FromPrimitive, ToPrimitive};
+use num_traits::{FromPrimitive, ToPrimitive};
+use strum_macros::EnumIter;
+
+use super::generator_utils::*;
+
+/// Enumeration of transfer message types.
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, FromPrimitive, ToPrimitive, EnumIter)]
+pub enum MessageTypeDto {
+    /// Plain message.
+    PLAIN_MESSAGE = 0,
+
+    /// Encrypted message.
+    ENCRYPTED_MESSAGE = 1,
+
+    /// Persistent harvesting delegation message.
+    PERSISTENT_HARVESTING_DELEGATION_MESSAGE = 254,
+
+}
+
+impl MessageTypeDto {
+    pub const LENGTH: usize = std::mem::size_of::<Self>();
+
+    /// Gets the size of the type.
+    ///
+    /// # Returns
+    ///
+    /// A usize.
+    pub fn get_size(&self) -> usize {
+        Self::LENGTH
+    }
+
+    /// Gets the value of the enum.
+    ///
+    /// # Returns
+    ///
+    /// A u8
+    pub fn get_value(&self) -> u8 {
+        self.to_u8().unwrap()
+    }
+
+
+    /// Creates an `MessageTypeDto` from a slice.
+    ///
+    /// # Returns
+    ///
+    /// A `MessageTypeDto`.
+    pub fn from_binary(src: &[u8]) -> Self {
+        // assert_eq!(src.len(), Self::LENGTH);
+        let buf = fixed_bytes::<{ Self::LENGTH }>(src);
+        Self::from_u8(u8::from_le_bytes(buf)).unwrap()
+    }
+
+    /// Serializes an type to bytes.
+    ///
+    /// # Returns
+    ///
+    /// A Serialized bytes.
+    pub fn serializer(&self) -> Vec<u8> {
+        self.get_value().to_le_bytes().to_vec()
+    }
+}
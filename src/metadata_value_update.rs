@@ -0,0 +1,141 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use std::cmp;
+use std::fmt;
+
+use super::metadata_entry_builder::*;
+
+/// Errors raised when applying a metadata value update.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetadataValueError {
+    /// Value size delta shrinks the value below zero bytes.
+    NegativeValueSize(i32),
+    /// Update value does not have the size implied by the value size delta.
+    ValueSizeMismatch { expected: usize, actual: usize },
+    /// Bytes removed by a shrinking update do not cancel the current value.
+    ValueNotTrimmable,
+    /// Value is longer than the 16-bit value size field allows.
+    ValueTooLarge(usize),
+    /// Change in value size does not fit the 16-bit value size delta.
+    ValueSizeDeltaOutOfRange(i64),
+}
+
+impl fmt::Display for MetadataValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MetadataValueError::NegativeValueSize(size) => write!(f, "value size delta results in a size of {}", size),
+            MetadataValueError::ValueSizeMismatch { expected, actual } => write!(f, "update value has {} bytes, expected {}", actual, expected),
+            MetadataValueError::ValueNotTrimmable => write!(f, "trimmed bytes do not cancel the current value"),
+            MetadataValueError::ValueTooLarge(size) => write!(f, "value has {} bytes, at most {} are allowed", size, u16::MAX),
+            MetadataValueError::ValueSizeDeltaOutOfRange(delta) => write!(f, "value size delta {} does not fit 16 bits", delta),
+        }
+    }
+}
+
+impl std::error::Error for MetadataValueError {}
+
+/// Value fields of a metadata transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetadataValueUpdate {
+    /// Change in value size in bytes, as a two's complement 16-bit integer.
+    pub value_size_delta: u16,
+    /// Xor of the current value and the new value, both padded to the longer of the two.
+    pub value: Vec<u8>,
+}
+
+impl MetadataValueUpdate {
+    /// Creates the update turning a metadata entry value into a new value.
+    /// current: Current metadata entry or None when the key has no value yet.
+    /// new_value: Desired value.
+    /// # Returns
+    /// A MetadataValueUpdate, or an error when a value or the size delta does not fit 16 bits.
+    pub fn create(current: Option<&MetadataEntryBuilder>, new_value: &[u8]) -> Result<Self, MetadataValueError> {
+        let current_value = current.map(|entry| entry.get_value().get_data()).unwrap_or_default();
+        Self::from_values(&current_value, new_value)
+    }
+
+    /// Creates the update turning a value into a new value.
+    /// current_value: Current value, empty when there is none.
+    /// new_value: Desired value.
+    /// # Returns
+    /// A MetadataValueUpdate, or an error when a value or the size delta does not fit 16 bits.
+    pub fn from_values(current_value: &[u8], new_value: &[u8]) -> Result<Self, MetadataValueError> {
+        for value in [current_value, new_value].iter() {
+            if value.len() > u16::MAX as usize {
+                return Err(MetadataValueError::ValueTooLarge(value.len()));
+            }
+        }
+
+        let delta = new_value.len() as i64 - current_value.len() as i64;
+        if delta < i16::MIN as i64 || delta > i16::MAX as i64 {
+            return Err(MetadataValueError::ValueSizeDeltaOutOfRange(delta));
+        }
+
+        Ok(MetadataValueUpdate { value_size_delta: delta as i16 as u16, value: xor_padded(current_value, new_value) })
+    }
+
+    /// Gets the change in value size in bytes.
+    ///
+    /// # Returns
+    /// A Signed size delta.
+    pub fn get_signed_value_size_delta(&self) -> i16 {
+        self.value_size_delta as i16
+    }
+
+    /// Applies the update to a value.
+    /// current_value: Current value, empty when there is none.
+    /// # Returns
+    /// A New value.
+    pub fn apply(&self, current_value: &[u8]) -> Result<Vec<u8>, MetadataValueError> {
+        let new_size = current_value.len() as i32 + self.get_signed_value_size_delta() as i32;
+        if new_size < 0 {
+            return Err(MetadataValueError::NegativeValueSize(new_size));
+        }
+
+        let new_size = new_size as usize;
+        let expected = cmp::max(current_value.len(), new_size);
+        if self.value.len() != expected {
+            return Err(MetadataValueError::ValueSizeMismatch { expected, actual: self.value.len() });
+        }
+
+        let mut new_value = xor_padded(current_value, &self.value);
+        if new_value[new_size..].iter().any(|byte| *byte != 0) {
+            return Err(MetadataValueError::ValueNotTrimmable);
+        }
+
+        new_value.truncate(new_size);
+        Ok(new_value)
+    }
+
+    /// Applies a chain of updates starting from an empty value.
+    /// updates: Updates in the order they were confirmed.
+    /// # Returns
+    /// A Final value.
+    pub fn apply_all(updates: &[MetadataValueUpdate]) -> Result<Vec<u8>, MetadataValueError> {
+        updates.iter().try_fold(vec![], |value, update| update.apply(&value))
+    }
+}
+
+fn xor_padded(lhs: &[u8], rhs: &[u8]) -> Vec<u8> {
+    let size = cmp::max(lhs.len(), rhs.len());
+    (0..size).map(|i| lhs.get(i).unwrap_or(&0) ^ rhs.get(i).unwrap_or(&0)).collect()
+}
@@ -22,6 +22,7 @@
 use super::address_dto::*;
 use super::generator_utils::*;
 use super::height_dto::*;
+use super::mosaic_flags_dto::*;
 use super::mosaic_properties_builder::*;
 
 /// Binary layout for mosaic definition.
@@ -88,6 +89,25 @@ impl MosaicDefinitionBuilder {
         self.properties.clone()
     }
 
+    /// Checks if the mosaic is active at a height.
+    /// Mosaics with a zero duration never expire.
+    /// height: Height to check.
+    /// # Returns
+    /// True if the height is within the mosaic lifetime.
+    pub fn is_active(&self, height: &HeightDto) -> bool {
+        let start_height = self.start_height.get_height();
+        let duration = self.properties.get_duration().get_block_duration();
+        start_height <= height.get_height() && (duration == 0 || height.get_height() < start_height + duration)
+    }
+
+    /// Checks if the mosaic can be transferred between accounts other than the owner.
+    ///
+    /// # Returns
+    /// True if the transferable flag is set.
+    pub fn is_transferable(&self) -> bool {
+        self.properties.get_flags().contains(&MosaicFlagsDto::TRANSFERABLE)
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
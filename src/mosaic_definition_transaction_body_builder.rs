@@ -24,6 +24,8 @@ use super::generator_utils::*;
 use super::mosaic_flags_dto::*;
 use super::mosaic_id_dto::*;
 use super::mosaic_nonce_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for a mosaic definition transaction.
 #[derive(Debug, Clone)]
@@ -62,6 +64,22 @@ impl MosaicDefinitionTransactionBodyBuilder {
         MosaicDefinitionTransactionBodyBuilder { id, duration, nonce, flags, divisibility } // TransactionBody
     }
 
+    /// Runs the stateless checks of the node on the body.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the body is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = vec![];
+        if self.divisibility > config.max_mosaic_divisibility {
+            failures.push(ValidationFailure::Failure_Mosaic_Invalid_Divisibility);
+        }
+        if self.duration.get_block_duration() > config.max_mosaic_duration {
+            failures.push(ValidationFailure::Failure_Mosaic_Invalid_Duration);
+        }
+        failures
+    }
+
+
     /// Gets the size of the type.
     ///
     /// Returns:
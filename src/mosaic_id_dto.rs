@@ -22,7 +22,7 @@
 use super::generator_utils::*;
 
 /// Mosaic id.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct MosaicIdDto(pub u64);
 
 impl MosaicIdDto {
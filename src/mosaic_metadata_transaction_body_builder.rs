@@ -20,8 +20,12 @@
  */
 
 use super::generator_utils::*;
+use super::metadata_entry_builder::*;
+use super::metadata_value_update::*;
 use super::unresolved_address_dto::*;
 use super::unresolved_mosaic_id_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for a mosaic metadata transaction.
 #[derive(Debug, Clone)]
@@ -64,6 +68,44 @@ impl MosaicMetadataTransactionBodyBuilder {
         MosaicMetadataTransactionBodyBuilder { target_address, scoped_metadata_key, target_mosaic_id, value_size_delta, value } // TransactionBody
     }
 
+    /// Sets the value size delta and value turning the current value into a new value.
+    /// current: Current metadata entry or None when the key has no value yet.
+    /// new_value: Desired value.
+    /// # Returns
+    /// An error when a value or the size delta does not fit 16 bits.
+    pub fn set_value_update(&mut self, current: Option<&MetadataEntryBuilder>, new_value: &[u8]) -> Result<(), MetadataValueError> {
+        let update = MetadataValueUpdate::create(current, new_value)?;
+        self.value_size_delta = update.value_size_delta;
+        self.value = update.value;
+        Ok(())
+    }
+
+    /// Gets the value size delta and value as an update.
+    ///
+    /// # Returns
+    /// A Metadata value update.
+    pub fn get_value_update(&self) -> MetadataValueUpdate {
+        MetadataValueUpdate { value_size_delta: self.value_size_delta, value: self.value.clone() }
+    }
+
+    /// Runs the stateless checks of the node on the body.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the body is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = vec![];
+        if self.value.is_empty() {
+            failures.push(ValidationFailure::Failure_Metadata_Value_Too_Small);
+        } else if self.value.len() > config.max_value_size {
+            failures.push(ValidationFailure::Failure_Metadata_Value_Too_Large);
+        }
+        if (self.value_size_delta as i16).unsigned_abs() as usize > self.value.len() {
+            failures.push(ValidationFailure::Failure_Metadata_Value_Size_Delta_Too_Large);
+        }
+        failures
+    }
+
+
     /// Gets the size of the type.
     ///
     /// Returns:
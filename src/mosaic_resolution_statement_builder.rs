@@ -19,17 +19,17 @@
  * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
  */
 
+use sha3::{Digest, Sha3_256};
+
 use super::generator_utils::*;
+use super::hash256_dto::*;
 use super::mosaic_resolution_entry_builder::*;
-use super::receipt_builder::*;
 use super::receipt_type_dto::*;
 use super::unresolved_mosaic_id_dto::*;
 
 /// Binary layout for a mosaic resolution statement.
 #[derive(Debug, Clone)]
 pub struct MosaicResolutionStatementBuilder {
-    /// Receipt.
-    super_object: ReceiptBuilder,
     /// Unresolved mosaic.
     unresolved: UnresolvedMosaicIdDto,
     /// Resolution entries.
@@ -38,18 +38,26 @@ pub struct MosaicResolutionStatementBuilder {
 
 
 impl MosaicResolutionStatementBuilder {
+    /// Statement version used when hashing.
+    const VERSION: u16 = 1;
+
     /// Creates an instance of MosaicResolutionStatementBuilder from binary payload.
     /// payload: Byte payload to use to serialize the object.
     /// # Returns
     /// A MosaicResolutionStatementBuilder.
     pub fn from_binary(_bytes: &[u8]) -> Self {
-        let super_object = ReceiptBuilder::from_binary(_bytes);
-        let mut _bytes = _bytes[super_object.get_size()..].to_vec();
-        let unresolved = UnresolvedMosaicIdDto::from_binary(&_bytes); // kind:CUSTOM1
+        let unresolved = UnresolvedMosaicIdDto::from_binary(_bytes); // kind:CUSTOM1
         let mut _bytes = _bytes[unresolved.get_size()..].to_vec();
-        let resolution_entries: Vec<MosaicResolutionEntryBuilder> = vec![];
-        //let _bytes = GeneratorUtils.load_from_binary(MosaicResolutionEntryBuilder, resolutionEntries, _bytes, len(_bytes));
-        MosaicResolutionStatementBuilder { super_object, unresolved, resolution_entries }
+        let buf = fixed_bytes::<4>(&_bytes);
+        let resolution_entries_count = u32::from_le_bytes(buf); // kind:SIZE_FIELD
+        _bytes = _bytes[4..].to_vec();
+        let mut resolution_entries: Vec<MosaicResolutionEntryBuilder> = vec![]; // kind:ARRAY
+        for _ in 0..resolution_entries_count {
+            let item = MosaicResolutionEntryBuilder::from_binary(&_bytes);
+            _bytes = _bytes[item.get_size()..].to_vec();
+            resolution_entries.push(item);
+        }
+        MosaicResolutionStatementBuilder { unresolved, resolution_entries }
     }
 
     /// Gets unresolved mosaic.
@@ -68,13 +76,28 @@ impl MosaicResolutionStatementBuilder {
         self.resolution_entries.clone() // ARRAY or FILL_ARRAY
     }
 
+    /// Calculates the statement hash the way Catapult does.
+    /// # Returns
+    /// A statement hash.
+    pub fn calculate_hash(&self) -> Hash256Dto {
+        let mut hasher = Sha3_256::new();
+        hasher.update(Self::VERSION.to_le_bytes());
+        hasher.update(ReceiptTypeDto::MOSAIC_ALIAS_RESOLUTION.serializer());
+        hasher.update(self.unresolved.serializer());
+        for entry in &self.resolution_entries {
+            hasher.update(entry.serializer());
+        }
+        Hash256Dto::from_binary(&hasher.finalize())
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
     /// A size in bytes.
     pub fn get_size(&self) -> usize {
-        let mut size = self.super_object.get_size();
+        let mut size = 0;
         size += self.unresolved.get_size(); // unresolved;
+        size += 4; // resolution_entries_count;
         size += self.resolution_entries.iter().map(|item| item.get_size()).sum::<usize>(); // array or fill_array;
         size
     }
@@ -85,12 +108,11 @@ impl MosaicResolutionStatementBuilder {
     /// A Serialized bytes.
     pub fn serializer(&self) -> Vec<u8> {
         let mut buf: Vec<u8> = vec![];
-        buf.append(&mut self.super_object.serializer());
         buf.append(&mut self.unresolved.serializer()); // kind:CUSTOM
+        buf.append(&mut (self.resolution_entries.len() as u32).to_le_bytes().to_vec()); // kind:SIZE_FIELD
         for i in &self.resolution_entries {
             buf.append(&mut i.serializer()); // kind:ARRAY|FILL_ARRAY
         }
         buf
     }
 }
-
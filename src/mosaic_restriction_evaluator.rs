@@ -0,0 +1,154 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use std::fmt;
+
+use super::address_dto::*;
+use super::mosaic_address_restriction_entry_builder::*;
+use super::mosaic_global_restriction_entry_builder::*;
+use super::mosaic_id_dto::*;
+use super::mosaic_restriction_key_dto::*;
+use super::mosaic_restriction_type_dto::*;
+use super::restriction_rule_builder::*;
+use super::validation_failure::*;
+
+/// Global mosaic restriction rule an account does not satisfy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MosaicRestrictionViolation {
+    /// Address of the account.
+    pub address: AddressDto,
+    /// Key of the failed rule.
+    pub key: MosaicRestrictionKeyDto,
+    /// Mosaic the account value is read from.
+    pub reference_mosaic_id: MosaicIdDto,
+    /// Comparison of the failed rule.
+    pub restriction_type: MosaicRestrictionTypeDto,
+    /// Value of the failed rule.
+    pub restriction_value: u64,
+    /// Value of the account, None when the account has no value for the key.
+    pub address_value: Option<u64>,
+}
+
+impl MosaicRestrictionViolation {
+    /// Gets the failure the node reports for the violation.
+    ///
+    /// # Returns
+    /// A ValidationFailure.
+    pub fn get_failure(&self) -> ValidationFailure {
+        ValidationFailure::Failure_RestrictionMosaic_Account_Unauthorized
+    }
+}
+
+impl fmt::Display for MosaicRestrictionViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let address: String = self.address.0.iter().map(|byte| format!("{:02X}", byte)).collect();
+        let address_value = self.address_value.map_or("unset".to_string(), |value| value.to_string());
+        write!(
+            f,
+            "account {} fails key {:016X} of mosaic {:016X}: value {} is not {:?} {}",
+            address,
+            self.key.get_mosaic_restriction_key(),
+            self.reference_mosaic_id.get_mosaic_id(),
+            address_value,
+            self.restriction_type,
+            self.restriction_value
+        )
+    }
+}
+
+impl std::error::Error for MosaicRestrictionViolation {}
+
+/// Evaluates the global restrictions of a mosaic against account values, like the mosaic restriction validators of the node.
+#[derive(Debug, Clone, Copy)]
+pub struct MosaicRestrictionEvaluator<'a> {
+    /// Global restrictions of the evaluated mosaic.
+    pub global_restriction: &'a MosaicGlobalRestrictionEntryBuilder,
+    /// Address restrictions of the evaluated accounts for the evaluated mosaic and the reference mosaics of its rules.
+    pub address_restrictions: &'a [MosaicAddressRestrictionEntryBuilder],
+}
+
+impl<'a> MosaicRestrictionEvaluator<'a> {
+    /// Checks that an account satisfies every global rule of the mosaic.
+    /// A rule with a zero reference mosaic id reads the value from the evaluated mosaic.
+    /// An account without a value for a key only satisfies rules of type NONE.
+    /// address: Address of the account.
+    /// # Returns
+    /// The first failed rule, or Ok when the account can send and receive the mosaic.
+    pub fn check_address(&self, address: &AddressDto) -> Result<(), MosaicRestrictionViolation> {
+        for key_value in self.global_restriction.get_key_pairs().get_keys().iter() {
+            let key = key_value.get_key();
+            let rule = key_value.get_restriction_rule();
+            let reference_mosaic_id = self.resolve_reference_mosaic_id(&rule);
+            let address_value = self.address_restrictions.iter()
+                .find(|entry| entry.get_mosaic_id() == reference_mosaic_id && entry.get_address() == *address)
+                .and_then(|entry| entry.get_key_pairs().get_value(&key));
+            let restriction_type = rule.get_restriction_type();
+            let is_satisfied = match address_value {
+                Some(value) => restriction_type.evaluate(value, rule.get_restriction_value()),
+                None => restriction_type == MosaicRestrictionTypeDto::NONE,
+            };
+            if !is_satisfied {
+                return Err(MosaicRestrictionViolation {
+                    address: *address,
+                    key,
+                    reference_mosaic_id,
+                    restriction_type,
+                    restriction_value: rule.get_restriction_value(),
+                    address_value,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that both accounts of a transfer satisfy the global rules of the mosaic.
+    /// sender_address: Address of the sender.
+    /// recipient_address: Address of the recipient.
+    /// # Returns
+    /// The first failed rule, sender first, or Ok when the transfer is allowed.
+    pub fn check_transfer(&self, sender_address: &AddressDto, recipient_address: &AddressDto) -> Result<(), MosaicRestrictionViolation> {
+        self.check_address(sender_address)?;
+        self.check_address(recipient_address)
+    }
+
+    /// Gets the mosaics the account values of the rules are read from.
+    ///
+    /// # Returns
+    /// A list of MosaicIdDto without duplicates.
+    pub fn get_reference_mosaic_ids(&self) -> Vec<MosaicIdDto> {
+        let mut mosaic_ids: Vec<MosaicIdDto> = vec![];
+        for key_value in self.global_restriction.get_key_pairs().get_keys().iter() {
+            let mosaic_id = self.resolve_reference_mosaic_id(&key_value.get_restriction_rule());
+            if !mosaic_ids.contains(&mosaic_id) {
+                mosaic_ids.push(mosaic_id);
+            }
+        }
+        mosaic_ids
+    }
+
+    /// A zero reference mosaic id refers to the evaluated mosaic.
+    fn resolve_reference_mosaic_id(&self, rule: &RestrictionRuleBuilder) -> MosaicIdDto {
+        match rule.get_reference_mosaic_id().get_mosaic_id() {
+            0 => self.global_restriction.get_mosaic_id(),
+            _ => rule.get_reference_mosaic_id(),
+        }
+    }
+}
@@ -22,7 +22,7 @@
 use super::generator_utils::*;
 
 /// Mosaic restriction key.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct MosaicRestrictionKeyDto(pub u64);
 
 impl MosaicRestrictionKeyDto {
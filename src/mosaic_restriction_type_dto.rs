@@ -86,6 +86,23 @@ impl MosaicRestrictionTypeDto {
         Self::from_u8(u8::from_le_bytes(buf)).unwrap()
     }
 
+    /// Compares the value of an account with the value of a rule.
+    /// value: Value of the account.
+    /// restriction_value: Value of the rule.
+    /// # Returns
+    /// True if the value satisfies the rule, always true for NONE.
+    pub fn evaluate(&self, value: u64, restriction_value: u64) -> bool {
+        match self {
+            MosaicRestrictionTypeDto::NONE => true,
+            MosaicRestrictionTypeDto::EQ => value == restriction_value,
+            MosaicRestrictionTypeDto::NE => value != restriction_value,
+            MosaicRestrictionTypeDto::LT => value < restriction_value,
+            MosaicRestrictionTypeDto::LE => value <= restriction_value,
+            MosaicRestrictionTypeDto::GT => value > restriction_value,
+            MosaicRestrictionTypeDto::GE => value >= restriction_value,
+        }
+    }
+
     /// Serializes an type to bytes.
     ///
     /// # Returns
@@ -23,6 +23,8 @@ use super::amount_dto::*;
 use super::generator_utils::*;
 use super::mosaic_supply_change_action_dto::*;
 use super::unresolved_mosaic_id_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for a mosaic supply change transaction.
 #[derive(Debug, Clone)]
@@ -52,6 +54,19 @@ impl MosaicSupplyChangeTransactionBodyBuilder {
         MosaicSupplyChangeTransactionBodyBuilder { mosaic_id, delta, action } // TransactionBody
     }
 
+    /// Runs the stateless checks of the node on the body.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the body is valid.
+    pub fn validate(&self, _config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = vec![];
+        if self.delta.get_amount() == 0 {
+            failures.push(ValidationFailure::Failure_Mosaic_Invalid_Supply_Change_Amount);
+        }
+        failures
+    }
+
+
     /// Gets the size of the type.
     ///
     /// Returns:
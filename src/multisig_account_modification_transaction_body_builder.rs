@@ -21,6 +21,8 @@
 
 use super::generator_utils::*;
 use super::unresolved_address_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for a multisig account modification transaction.
 #[derive(Debug, Clone)]
@@ -73,6 +75,28 @@ impl MultisigAccountModificationTransactionBodyBuilder {
         MultisigAccountModificationTransactionBodyBuilder { min_removal_delta, min_approval_delta, address_additions, address_deletions } // TransactionBody
     }
 
+    /// Runs the stateless checks of the node on the body.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the body is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = vec![];
+        if self.address_additions.iter().any(|address| self.address_deletions.contains(address)) {
+            failures.push(ValidationFailure::Failure_Multisig_Account_In_Both_Sets);
+        }
+        let has_duplicates = |addresses: &[UnresolvedAddressDto]| addresses.iter().enumerate().any(|(i, address)| addresses[..i].contains(address));
+        if has_duplicates(&self.address_additions) || has_duplicates(&self.address_deletions) {
+            failures.push(ValidationFailure::Failure_Multisig_Redundant_Modification);
+        }
+        // deltas are signed 8-bit integers.
+        let deltas = [self.min_removal_delta as i8, self.min_approval_delta as i8];
+        if deltas.iter().any(|delta| delta.unsigned_abs() > config.max_cosignatories_per_account) {
+            failures.push(ValidationFailure::Failure_Multisig_Min_Setting_Out_Of_Range);
+        }
+        failures
+    }
+
+
     /// Gets the size of the type.
     ///
     /// Returns:
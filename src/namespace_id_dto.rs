@@ -19,10 +19,12 @@
  * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
  */
 
+use sha3::{Digest, Sha3_256};
+
 use super::generator_utils::*;
 
 /// Namespace id.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct NamespaceIdDto(pub u64);
 
 impl NamespaceIdDto {
@@ -36,6 +38,19 @@ impl NamespaceIdDto {
         Self::LENGTH
     }
 
+    /// Generates the id of a namespace.
+    /// The id is the first 8 bytes of SHA3-256(parent id || name), read as a little-endian
+    /// integer, with the highest bit set.
+    /// name: Namespace name.
+    /// parent_id: Parent namespace id or None for a root namespace.
+    /// # Returns
+    /// A NamespaceIdDto.
+    pub fn from_name(name: &str, parent_id: Option<NamespaceIdDto>) -> Self {
+        let parent_id = parent_id.map_or(0, |parent_id| parent_id.0);
+        let hash = Sha3_256::new().chain(parent_id.to_le_bytes()).chain(name.as_bytes()).finalize();
+        Self(u64::from_le_bytes(fixed_bytes::<8>(&hash)) | (1 << 63))
+    }
+
     /// Gets Namespace id.
     ///
     /// # Returns
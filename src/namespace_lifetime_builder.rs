@@ -61,6 +61,14 @@ impl NamespaceLifetimeBuilder {
         self.lifetime_end.clone()
     }
 
+    /// Checks if the namespace is active at a height.
+    /// height: Height to check.
+    /// # Returns
+    /// True if the height is within the lifetime.
+    pub fn is_active(&self, height: &HeightDto) -> bool {
+        self.lifetime_start.get_height() <= height.get_height() && height.get_height() < self.lifetime_end.get_height()
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
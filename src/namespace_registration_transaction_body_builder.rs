@@ -23,6 +23,8 @@ use super::block_duration_dto::*;
 use super::generator_utils::*;
 use super::namespace_id_dto::*;
 use super::namespace_registration_type_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for a namespace registration transaction.
 #[derive(Debug, Clone)]
@@ -69,6 +71,30 @@ impl NamespaceRegistrationTransactionBodyBuilder {
         NamespaceRegistrationTransactionBodyBuilder { duration, parent_id, id, registration_type, name } // TransactionBody
     }
 
+    /// Runs the stateless checks of the node on the body.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the body is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = vec![];
+        match std::str::from_utf8(&self.name).ok().filter(|name| is_valid_name(name, config.max_name_size)) {
+            None => failures.push(ValidationFailure::Failure_Namespace_Invalid_Name),
+            Some(name) => {
+                if NamespaceIdDto::from_name(name, self.parent_id) != self.id {
+                    failures.push(ValidationFailure::Failure_Namespace_Name_Id_Mismatch);
+                }
+            }
+        }
+        if self.registration_type == NamespaceRegistrationTypeDto::ROOT {
+            let duration = self.duration.map_or(0, |duration| duration.get_block_duration());
+            if duration < config.min_namespace_duration || duration > config.max_namespace_duration {
+                failures.push(ValidationFailure::Failure_Namespace_Invalid_Duration);
+            }
+        }
+        failures
+    }
+
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -109,3 +135,11 @@ impl NamespaceRegistrationTransactionBodyBuilder {
     }
 }
 
+/// Checks that a namespace name only has lowercase letters, digits, '-' and '_', starting with a letter or a digit.
+fn is_valid_name(name: &str, max_name_size: usize) -> bool {
+    let is_alphanumeric = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit();
+    match name.chars().next() {
+        Some(first) => name.len() <= max_name_size && is_alphanumeric(first) && name.chars().all(|c| is_alphanumeric(c) || c == '-' || c == '_'),
+        None => false,
+    }
+}
@@ -31,13 +31,15 @@ use super::key_dto::*;
 use super::network_type_dto::*;
 use super::signature_dto::*;
 use super::timestamp_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 use super::vrf_proof_builder::*;
 
 /// Binary layout for a normal block header.
 #[derive(Debug, Clone)]
 pub struct NormalBlockHeaderBuilder {
     /// Block header.
-    super_object: BlockHeaderBuilder,
+    pub super_object: BlockHeaderBuilder,
 }
 
 
@@ -52,6 +54,14 @@ impl NormalBlockHeaderBuilder {
         NormalBlockHeaderBuilder { super_object }
     }
 
+    /// Runs the stateless checks of the node on the block header.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the header is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.super_object.validate(config)
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -68,8 +78,9 @@ impl NormalBlockHeaderBuilder {
     /// A Serialized bytes.
     pub fn serializer(&self) -> Vec<u8> {
         let mut buf: Vec<u8> = vec![];
+        buf.append(&mut (self.get_size() as u32).to_le_bytes().to_vec());
         buf.append(&mut self.super_object.serializer());
-        buf.append(&mut 4u16.to_le_bytes().to_vec());
+        buf.append(&mut [0u8; 4].to_vec()); // kind:SIMPLE and is_reserved
         buf
     }
 }
@@ -0,0 +1,84 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use super::key_dto::*;
+use super::key_pair::*;
+use super::message_cipher::*;
+use super::transfer_message::*;
+
+/// Keys delegated to a node by a persistent harvesting delegation message.
+#[derive(Debug, Clone)]
+pub struct PersistentHarvestingDelegation {
+    /// Remote account key pair, linked to the main account and used to sign blocks.
+    pub signing_key_pair: KeyPair,
+    /// VRF key pair linked to the main account.
+    pub vrf_key_pair: KeyPair,
+}
+
+impl PersistentHarvestingDelegation {
+    /// Size of the encrypted payload (tag, initialization vector, signing and VRF private keys).
+    const ENCRYPTED_PAYLOAD_SIZE: usize = AES_TAG_SIZE + AES_IV_SIZE + 64;
+
+    /// Creates the transfer message requesting a node to harvest with the delegated keys.
+    /// The keys are encrypted with a key shared by a random ephemeral key pair and the node.
+    /// node_public_key: Public key of the node (transport key).
+    /// # Returns
+    /// A TransferMessage.
+    pub fn create_message(&self, node_public_key: &KeyDto) -> Result<TransferMessage, MessageCipherError> {
+        let ephemeral_key_pair = KeyPair::random();
+        let keys = [self.signing_key_pair.get_private_key(), self.vrf_key_pair.get_private_key()].concat();
+        let encrypted_payload = encrypt_message(&ephemeral_key_pair, node_public_key, &keys)?;
+        Ok(TransferMessage::PersistentHarvestingDelegation { ephemeral_public_key: ephemeral_key_pair.get_public_key(), encrypted_payload })
+    }
+
+    /// Decrypts a persistent harvesting delegation message received by a node.
+    /// message: Transfer message.
+    /// node_key_pair: Key pair of the node (transport key).
+    /// # Returns
+    /// A PersistentHarvestingDelegation.
+    pub fn from_message(message: &TransferMessage, node_key_pair: &KeyPair) -> Result<Self, MessageCipherError> {
+        let (ephemeral_public_key, encrypted_payload) = match message {
+            TransferMessage::PersistentHarvestingDelegation { ephemeral_public_key, encrypted_payload } => (ephemeral_public_key, encrypted_payload),
+            _ => return Err(MessageCipherError::UnexpectedMessageType),
+        };
+        if encrypted_payload.len() != Self::ENCRYPTED_PAYLOAD_SIZE {
+            return Err(MessageCipherError::InvalidPayload);
+        }
+        let keys = decrypt_message(node_key_pair, ephemeral_public_key, encrypted_payload)?;
+        let mut signing_private_key = [0u8; 32];
+        let mut vrf_private_key = [0u8; 32];
+        signing_private_key.copy_from_slice(&keys[..32]);
+        vrf_private_key.copy_from_slice(&keys[32..]);
+        Ok(PersistentHarvestingDelegation {
+            signing_key_pair: KeyPair::from_private_key(signing_private_key),
+            vrf_key_pair: KeyPair::from_private_key(vrf_private_key),
+        })
+    }
+
+    /// Checks the delegated keys against the keys linked to the main account.
+    /// linked_public_key: Remote public key linked to the main account.
+    /// vrf_public_key: VRF public key linked to the main account.
+    /// # Returns
+    /// true if both delegated keys are the linked ones.
+    pub fn matches(&self, linked_public_key: &KeyDto, vrf_public_key: &KeyDto) -> bool {
+        self.signing_key_pair.get_public_key() == *linked_public_key && self.vrf_key_pair.get_public_key() == *vrf_public_key
+    }
+}
@@ -0,0 +1,126 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use num_traits::FromPrimitive;
+
+use super::balance_change_receipt_builder::*;
+use super::balance_transfer_receipt_builder::*;
+use super::generator_utils::*;
+use super::inflation_receipt_builder::*;
+use super::mosaic_expiry_receipt_builder::*;
+use super::namespace_expiry_receipt_builder::*;
+use super::receipt_type_dto::*;
+
+/// Offset of the receipt type (size and version are skipped).
+const RECEIPT_TYPE_OFFSET: usize = 6;
+
+/// Receipt of any type, decoded with the layout matching its receipt type.
+#[derive(Debug, Clone)]
+pub enum Receipt {
+    /// Harvest fee and lock receipts.
+    BalanceChange(BalanceChangeReceiptBuilder),
+    /// Mosaic and namespace rental fee receipts.
+    BalanceTransfer(BalanceTransferReceiptBuilder),
+    /// Inflation receipt.
+    Inflation(InflationReceiptBuilder),
+    /// Mosaic expired receipt.
+    MosaicExpiry(MosaicExpiryReceiptBuilder),
+    /// Namespace expired and deleted receipts.
+    NamespaceExpiry(NamespaceExpiryReceiptBuilder),
+    /// Receipt of an unknown type, kept as is.
+    Raw(Vec<u8>),
+}
+
+impl Receipt {
+    /// Gets the raw receipt type.
+    ///
+    /// # Returns
+    /// A Receipt type value.
+    pub fn get_type_value(&self) -> u16 {
+        let payload = self.serializer();
+        u16::from_le_bytes(fixed_bytes::<2>(&payload[RECEIPT_TYPE_OFFSET..]))
+    }
+
+    /// Gets the receipt type.
+    ///
+    /// # Returns
+    /// A Receipt type or None if the type is unknown.
+    pub fn get_type(&self) -> Option<ReceiptTypeDto> {
+        ReceiptTypeDto::from_u16(self.get_type_value())
+    }
+
+    /// Gets the size of the type.
+    ///
+    /// Returns:
+    /// A size in bytes.
+    pub fn get_size(&self) -> usize {
+        match self {
+            Receipt::BalanceChange(receipt) => receipt.get_size(),
+            Receipt::BalanceTransfer(receipt) => receipt.get_size(),
+            Receipt::Inflation(receipt) => receipt.get_size(),
+            Receipt::MosaicExpiry(receipt) => receipt.get_size(),
+            Receipt::NamespaceExpiry(receipt) => receipt.get_size(),
+            Receipt::Raw(payload) => payload.len(),
+        }
+    }
+
+    /// Serializes self to bytes.
+    ///
+    /// # Returns
+    /// A Serialized bytes.
+    pub fn serializer(&self) -> Vec<u8> {
+        match self {
+            Receipt::BalanceChange(receipt) => receipt.serializer(),
+            Receipt::BalanceTransfer(receipt) => receipt.serializer(),
+            Receipt::Inflation(receipt) => receipt.serializer(),
+            Receipt::MosaicExpiry(receipt) => receipt.serializer(),
+            Receipt::NamespaceExpiry(receipt) => receipt.serializer(),
+            Receipt::Raw(payload) => payload.clone(),
+        }
+    }
+}
+
+/// Creates a receipt from binary payload, dispatching on its receipt type.
+/// The payload is cut to the size stored in the receipt header.
+/// payload: Byte payload to use to serialize the object.
+/// # Returns
+/// A Receipt.
+pub fn load_receipt_from_binary(payload: &[u8]) -> Receipt {
+    let size = u32::from_le_bytes(fixed_bytes::<4>(payload)) as usize;
+    let payload = &payload[..size];
+    let receipt_type = u16::from_le_bytes(fixed_bytes::<2>(&payload[RECEIPT_TYPE_OFFSET..]));
+    match ReceiptTypeDto::from_u16(receipt_type) {
+        Some(ReceiptTypeDto::HARVEST_FEE)
+        | Some(ReceiptTypeDto::LOCK_HASH_CREATED)
+        | Some(ReceiptTypeDto::LOCK_HASH_COMPLETED)
+        | Some(ReceiptTypeDto::LOCK_HASH_EXPIRED)
+        | Some(ReceiptTypeDto::LOCK_SECRET_CREATED)
+        | Some(ReceiptTypeDto::LOCK_SECRET_COMPLETED)
+        | Some(ReceiptTypeDto::LOCK_SECRET_EXPIRED) => Receipt::BalanceChange(BalanceChangeReceiptBuilder::from_binary(payload)),
+        Some(ReceiptTypeDto::MOSAIC_RENTAL_FEE)
+        | Some(ReceiptTypeDto::NAMESPACE_RENTAL_FEE) => Receipt::BalanceTransfer(BalanceTransferReceiptBuilder::from_binary(payload)),
+        Some(ReceiptTypeDto::INFLATION) => Receipt::Inflation(InflationReceiptBuilder::from_binary(payload)),
+        Some(ReceiptTypeDto::MOSAIC_EXPIRED) => Receipt::MosaicExpiry(MosaicExpiryReceiptBuilder::from_binary(payload)),
+        Some(ReceiptTypeDto::NAMESPACE_EXPIRED)
+        | Some(ReceiptTypeDto::NAMESPACE_DELETED) => Receipt::NamespaceExpiry(NamespaceExpiryReceiptBuilder::from_binary(payload)),
+        _ => Receipt::Raw(payload.to_vec()),
+    }
+}
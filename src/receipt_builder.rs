@@ -38,6 +38,7 @@ impl ReceiptBuilder {
     /// # Returns
     /// A ReceiptBuilder.
     pub fn from_binary(_bytes: &[u8]) -> Self {
+        let _bytes = _bytes[4..].to_vec();
         let buf = fixed_bytes::<2>(&_bytes);
         let version = u16::from_le_bytes(buf); // kind:SIMPLE
         let _bytes = (&_bytes[2..]).to_vec();
@@ -114,6 +114,17 @@ impl RootNamespaceHistoryBuilder {
         self.paths.clone() // ARRAY or FILL_ARRAY
     }
 
+    /// Gets the alias of the root namespace or of one of its children.
+    /// namespace_id: Id of the root or child namespace.
+    /// # Returns
+    /// The namespace alias, or None when the namespace is not part of the history.
+    pub fn get_alias(&self, namespace_id: &NamespaceIdDto) -> Option<NamespaceAliasBuilder> {
+        if self.id == *namespace_id {
+            return Some(self.root_alias.clone());
+        }
+        self.paths.iter().find(|path| path.get_path().last() == Some(namespace_id)).map(|path| path.get_alias())
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -19,10 +19,12 @@
  * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
  */
 
+use sha3::{Digest, Sha3_256};
+
 use super::generator_utils::*;
 
 /// Scoped metadata key.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ScopedMetadataKeyDto(pub u64);
 
 impl ScopedMetadataKeyDto {
@@ -36,6 +38,20 @@ impl ScopedMetadataKeyDto {
         Self::LENGTH
     }
 
+    /// Creates a scoped metadata key from a string key the way the key generator of the SDKs does.
+    /// The key is the first 8 bytes of the SHA3-256 hash of the UTF-8 string, read as a
+    /// little-endian integer, with the high bit set.
+    /// key: String key.
+    /// # Returns
+    /// A ScopedMetadataKeyDto, or None when the key is empty.
+    pub fn from_str_key(key: &str) -> Option<Self> {
+        if key.is_empty() {
+            return None;
+        }
+        let hash = Sha3_256::digest(key.as_bytes());
+        Some(Self(Self::from_binary(&hash[..Self::LENGTH]).0 | 0x8000_0000_0000_0000))
+    }
+
     /// Gets Scoped metadata key.
     ///
     /// # Returns
@@ -62,3 +78,9 @@ impl ScopedMetadataKeyDto {
         Self(u64::from_le_bytes(buf))
     }
 }
+
+impl From<u64> for ScopedMetadataKeyDto {
+    fn from(scoped_metadata_key: u64) -> Self {
+        Self(scoped_metadata_key)
+    }
+}
@@ -25,6 +25,8 @@ use super::hash256_dto::*;
 use super::lock_hash_algorithm_dto::*;
 use super::unresolved_address_dto::*;
 use super::unresolved_mosaic_builder::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for a secret lock transaction.
 #[derive(Debug, Clone)]
@@ -62,6 +64,20 @@ impl SecretLockTransactionBodyBuilder {
         SecretLockTransactionBodyBuilder { recipient_address, secret, mosaic, duration, hash_algorithm } // TransactionBody
     }
 
+    /// Runs the stateless checks of the node on the body.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the body is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = vec![];
+        let duration = self.duration.get_block_duration();
+        if duration == 0 || duration > config.max_secret_lock_duration {
+            failures.push(ValidationFailure::Failure_LockSecret_Invalid_Duration);
+        }
+        failures
+    }
+
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -22,7 +22,10 @@
 use super::generator_utils::*;
 use super::hash256_dto::*;
 use super::lock_hash_algorithm_dto::*;
+use super::secret_lock_transaction_body_builder::*;
 use super::unresolved_address_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for a secret proof transaction.
 #[derive(Debug, Clone)]
@@ -59,6 +62,33 @@ impl SecretProofTransactionBodyBuilder {
         SecretProofTransactionBodyBuilder { recipient_address, secret, hash_algorithm, proof } // TransactionBody
     }
 
+    /// Checks that the proof unlocks a secret lock.
+    /// The recipient, secret and hash algorithm must be the same and the proof must hash into the secret.
+    /// secret_lock: Secret lock transaction body.
+    /// # Returns
+    /// True if the proof unlocks the secret lock.
+    pub fn matches(&self, secret_lock: &SecretLockTransactionBodyBuilder) -> bool {
+        self.recipient_address == secret_lock.recipient_address
+            && self.secret == secret_lock.secret
+            && self.hash_algorithm == secret_lock.hash_algorithm
+            && self.hash_algorithm.verify(&self.secret, &self.proof)
+    }
+
+    /// Runs the stateless checks of the node on the body.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the body is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = vec![];
+        if self.proof.len() < config.min_proof_size || self.proof.len() > config.max_proof_size {
+            failures.push(ValidationFailure::Failure_LockSecret_Proof_Size_Out_Of_Bounds);
+        } else if !self.hash_algorithm.verify(&self.secret, &self.proof) {
+            failures.push(ValidationFailure::Failure_LockSecret_Secret_Mismatch);
+        }
+        failures
+    }
+
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -26,11 +26,15 @@ use super::hash256_dto::*;
 use super::key_dto::*;
 use super::lock_hash_algorithm_dto::*;
 use super::network_type_dto::*;
+use super::secret_lock_transaction_builder::*;
 use super::secret_proof_transaction_body_builder::*;
 use super::signature_dto::*;
 use super::timestamp_dto::*;
 use super::transaction_builder::*;
+use super::transaction_helper::*;
 use super::unresolved_address_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for a non-embedded secret proof transaction.
 #[derive(Debug, Clone)]
@@ -94,6 +98,24 @@ impl SecretProofTransactionBuilder {
         self.body.proof = proof;   // MARKER1 AttributeKind.BUFFER
     }
 
+    /// Checks that the proof unlocks a secret lock.
+    /// secret_lock: Secret lock transaction.
+    /// # Returns
+    /// True if the proof unlocks the secret lock.
+    pub fn matches(&self, secret_lock: &SecretLockTransactionBuilder) -> bool {
+        self.body.matches(&secret_lock.body)
+    }
+
+    /// Runs the stateless checks of the node on the transaction.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = self.super_object.validate(config);
+        failures.append(&mut self.body.validate(config));
+        failures
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -117,3 +139,20 @@ impl SecretProofTransactionBuilder {
     }
 }
 
+impl TransactionHelper for SecretProofTransactionBuilder {
+    fn box_clone(&self) -> Box<dyn TransactionHelper> {
+        Box::new((*self).clone())
+    }
+
+    fn get_size(&self) -> usize {
+        self.get_size()
+    }
+
+    fn serializer(&self) -> Vec<u8> {
+        self.serializer()
+    }
+
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.validate(config)
+    }
+}
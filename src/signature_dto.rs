@@ -22,7 +22,7 @@
 use super::generator_utils::*;
 
 /// Signature.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SignatureDto(pub [u8; 64]);
 
 impl SignatureDto {
@@ -0,0 +1,110 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use super::account_restrictions_builder::*;
+use super::account_state_builder::*;
+use super::address_dto::*;
+use super::height_dto::*;
+use super::mosaic_address_restriction_entry_builder::*;
+use super::mosaic_entry_builder::*;
+use super::mosaic_global_restriction_entry_builder::*;
+use super::mosaic_id_dto::*;
+use super::namespace_alias_builder::*;
+use super::namespace_alias_type_dto::*;
+use super::namespace_id_dto::*;
+use super::root_namespace_history_builder::*;
+use super::unresolved_address_dto::*;
+use super::unresolved_mosaic_id_dto::*;
+
+/// Read access to the chain state used by stateful validation.
+/// Implementations decide where the state comes from, e.g. REST responses or in-memory fixtures.
+pub trait StateProvider {
+    /// Gets the height the validated transactions would be confirmed at.
+    fn get_height(&self) -> HeightDto;
+
+    /// Gets the state of an account, or None when the account is unknown.
+    fn get_account_state(&self, address: &AddressDto) -> Option<AccountStateBuilder>;
+
+    /// Gets the entry of a mosaic, or None when the mosaic is unknown.
+    fn get_mosaic_entry(&self, mosaic_id: &MosaicIdDto) -> Option<MosaicEntryBuilder>;
+
+    /// Gets the history of the root namespace owning a root or child namespace, or None when it is unknown.
+    fn get_root_namespace(&self, namespace_id: &NamespaceIdDto) -> Option<RootNamespaceHistoryBuilder>;
+
+    /// Gets the restrictions of an account, or None when the account has none.
+    fn get_account_restrictions(&self, address: &AddressDto) -> Option<AccountRestrictionsBuilder>;
+
+    /// Gets the global restrictions of a mosaic, or None when the mosaic has none.
+    /// Providers without mosaic restrictions can keep the default.
+    fn get_mosaic_global_restriction(&self, _mosaic_id: &MosaicIdDto) -> Option<MosaicGlobalRestrictionEntryBuilder> {
+        None
+    }
+
+    /// Gets the restriction values of an account for a mosaic, or None when the account has none.
+    /// Providers without mosaic restrictions can keep the default.
+    fn get_mosaic_address_restriction(&self, _mosaic_id: &MosaicIdDto, _address: &AddressDto) -> Option<MosaicAddressRestrictionEntryBuilder> {
+        None
+    }
+
+    /// Gets the alias of an active namespace.
+    /// namespace_id: Id of the root or child namespace.
+    /// # Returns
+    /// The namespace alias, or None when the namespace is unknown or inactive.
+    fn get_namespace_alias(&self, namespace_id: &NamespaceIdDto) -> Option<NamespaceAliasBuilder> {
+        let root_namespace = self.get_root_namespace(namespace_id)?;
+        if !root_namespace.get_lifetime().is_active(&self.get_height()) {
+            return None;
+        }
+        root_namespace.get_alias(namespace_id)
+    }
+
+    /// Resolves a mosaic id that can be a namespace alias.
+    /// mosaic_id: Unresolved mosaic id.
+    /// # Returns
+    /// The mosaic id, or None when the alias does not link a mosaic.
+    fn resolve_mosaic_id(&self, mosaic_id: &UnresolvedMosaicIdDto) -> Option<MosaicIdDto> {
+        let namespace_id = match mosaic_id.as_namespace_id() {
+            Some(namespace_id) => namespace_id,
+            None => return mosaic_id.as_mosaic_id(),
+        };
+        let alias = self.get_namespace_alias(&namespace_id)?;
+        if alias.get_namespace_alias_type() != NamespaceAliasTypeDto::MOSAIC_ID {
+            return None;
+        }
+        alias.get_mosaic_alias()
+    }
+
+    /// Resolves an address that can be a namespace alias.
+    /// address: Unresolved address.
+    /// # Returns
+    /// The address, or None when the alias does not link an address.
+    fn resolve_address(&self, address: &UnresolvedAddressDto) -> Option<AddressDto> {
+        let namespace_id = match address.as_namespace_id() {
+            Some(namespace_id) => namespace_id,
+            None => return address.as_address(),
+        };
+        let alias = self.get_namespace_alias(&namespace_id)?;
+        if alias.get_namespace_alias_type() != NamespaceAliasTypeDto::ADDRESS {
+            return None;
+        }
+        alias.get_address_alias()
+    }
+}
@@ -0,0 +1,260 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use super::account_restriction_evaluator::*;
+use super::address_alias_transaction_body_builder::*;
+use super::address_dto::*;
+use super::aggregate_transaction_body_builder::*;
+use super::embedded_transaction_builder::*;
+use super::entity_type_dto::*;
+use super::hash_lock_transaction_body_builder::*;
+use super::mosaic_alias_transaction_body_builder::*;
+use super::mosaic_id_dto::*;
+use super::mosaic_restriction_evaluator::*;
+use super::namespace_id_dto::*;
+use super::namespace_metadata_transaction_body_builder::*;
+use super::namespace_registration_transaction_body_builder::*;
+use super::secret_lock_transaction_body_builder::*;
+use super::state_provider::*;
+use super::transaction_builder::*;
+use super::transfer_transaction_body_builder::*;
+use super::unresolved_address_dto::*;
+use super::unresolved_mosaic_builder::*;
+use super::validation_config::*;
+use super::validation_failure::*;
+
+/// Checks transactions against the chain state of a `StateProvider`, like the stateful validators of the node.
+#[derive(Clone, Copy)]
+pub struct StatefulValidator<'a> {
+    /// Network limits.
+    pub config: &'a ValidationConfig,
+    /// Chain state the transactions are checked against.
+    pub provider: &'a dyn StateProvider,
+}
+
+/// Failures and balance debits collected while validating a transaction.
+#[derive(Default)]
+struct ValidationContext {
+    failures: Vec<ValidationFailure>,
+    debits: Vec<(AddressDto, MosaicIdDto, u64)>,
+}
+
+impl ValidationContext {
+    fn push(&mut self, failure: ValidationFailure) {
+        if !self.failures.contains(&failure) {
+            self.failures.push(failure);
+        }
+    }
+
+    fn debit(&mut self, address: AddressDto, mosaic_id: MosaicIdDto, amount: u64) {
+        match self.debits.iter_mut().find(|(debit_address, debit_mosaic_id, _)| *debit_address == address && *debit_mosaic_id == mosaic_id) {
+            Some((_, _, total)) => *total = total.saturating_add(amount),
+            None => self.debits.push((address, mosaic_id, amount)),
+        }
+    }
+}
+
+impl<'a> StatefulValidator<'a> {
+    /// Runs the stateful checks of the node on a transaction.
+    /// Balances must cover the fee and every amount sent or locked by the transaction and its inner transactions.
+    /// Amounts received within an aggregate are not credited.
+    /// payload: Serialized top-level transaction.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, payload: &[u8]) -> Vec<ValidationFailure> {
+        let mut context = ValidationContext::default();
+        let header = TransactionBuilder::from_binary(payload);
+        let signer_address = AddressDto::from_public_key(header.network, &header.signer_public_key);
+        context.debit(signer_address, self.config.currency_mosaic_id, header.fee.get_amount());
+        let body = &payload[header.get_size()..];
+        match header._type {
+            EntityTypeDto::AGGREGATE_COMPLETE_TRANSACTION | EntityTypeDto::AGGREGATE_BONDED_TRANSACTION => {
+                self.validate_operation(&mut context, &signer_address, header._type);
+                for transaction in AggregateTransactionBodyBuilder::from_binary(body).transactions.iter() {
+                    let transaction_payload = transaction.serializer();
+                    let transaction_header = EmbeddedTransactionBuilder::from_binary(&transaction_payload);
+                    let transaction_signer_address = AddressDto::from_public_key(transaction_header.network, &transaction_header.signer_public_key);
+                    let transaction_body = &transaction_payload[transaction_header.get_size()..];
+                    self.validate_body(&mut context, &transaction_signer_address, transaction_header._type, transaction_body);
+                }
+            }
+            _ => self.validate_body(&mut context, &signer_address, header._type, body),
+        }
+        self.validate_balances(&mut context);
+        context.failures
+    }
+
+    fn validate_body(&self, context: &mut ValidationContext, signer_address: &AddressDto, entity_type: EntityTypeDto, body: &[u8]) {
+        self.validate_operation(context, signer_address, entity_type);
+        match entity_type {
+            EntityTypeDto::TRANSFER_TRANSACTION => {
+                let body = TransferTransactionBodyBuilder::from_binary(body);
+                let recipient_address = self.resolve_recipient(context, signer_address, &body.recipient_address);
+                for mosaic in body.mosaics.iter() {
+                    self.validate_mosaic(context, signer_address, recipient_address.as_ref(), mosaic);
+                }
+            }
+            EntityTypeDto::HASH_LOCK_TRANSACTION => {
+                let body = HashLockTransactionBodyBuilder::from_binary(body);
+                self.validate_mosaic(context, signer_address, None, &body.mosaic);
+            }
+            EntityTypeDto::SECRET_LOCK_TRANSACTION => {
+                let body = SecretLockTransactionBodyBuilder::from_binary(body);
+                self.resolve_recipient(context, signer_address, &body.recipient_address);
+                self.validate_mosaic(context, signer_address, None, &body.mosaic);
+            }
+            EntityTypeDto::NAMESPACE_REGISTRATION_TRANSACTION => {
+                let body = NamespaceRegistrationTransactionBodyBuilder::from_binary(body);
+                self.validate_namespace_registration(context, signer_address, &body);
+            }
+            EntityTypeDto::ADDRESS_ALIAS_TRANSACTION => {
+                let body = AddressAliasTransactionBodyBuilder::from_binary(body);
+                self.validate_namespace(context, &body.namespace_id);
+            }
+            EntityTypeDto::MOSAIC_ALIAS_TRANSACTION => {
+                let body = MosaicAliasTransactionBodyBuilder::from_binary(body);
+                self.validate_namespace(context, &body.namespace_id);
+            }
+            EntityTypeDto::NAMESPACE_METADATA_TRANSACTION => {
+                let body = NamespaceMetadataTransactionBodyBuilder::from_binary(body);
+                self.validate_namespace(context, &body.target_namespace_id);
+            }
+            _ => {}
+        }
+    }
+
+    fn validate_operation(&self, context: &mut ValidationContext, signer_address: &AddressDto, entity_type: EntityTypeDto) {
+        self.check_restrictions(context, &[signer_address], |evaluator| evaluator.check_operation_type(signer_address, entity_type));
+    }
+
+    /// Resolves the recipient of a transaction and checks that both accounts allow the interaction.
+    fn resolve_recipient(&self, context: &mut ValidationContext, signer_address: &AddressDto, recipient_address: &UnresolvedAddressDto) -> Option<AddressDto> {
+        let recipient_address = match self.provider.resolve_address(recipient_address) {
+            Some(recipient_address) => recipient_address,
+            None => {
+                context.push(ValidationFailure::Failure_Namespace_Unknown);
+                return None;
+            }
+        };
+        self.check_restrictions(context, &[signer_address, &recipient_address], |evaluator| evaluator.check_address_interaction(signer_address, &recipient_address));
+        Some(recipient_address)
+    }
+
+    /// Checks that a mosaic is active and debits it from the signer.
+    /// Transfers also check that the mosaic is transferable and that the recipient accepts it.
+    fn validate_mosaic(&self, context: &mut ValidationContext, signer_address: &AddressDto, recipient_address: Option<&AddressDto>, mosaic: &UnresolvedMosaicBuilder) {
+        let mosaic_id = match self.provider.resolve_mosaic_id(&mosaic.get_mosaic_id()) {
+            Some(mosaic_id) => mosaic_id,
+            None => {
+                context.push(ValidationFailure::Failure_Mosaic_Expired);
+                return;
+            }
+        };
+        match self.provider.get_mosaic_entry(&mosaic_id) {
+            Some(mosaic_entry) => {
+                let definition = mosaic_entry.get_definition();
+                if !definition.is_active(&self.provider.get_height()) {
+                    context.push(ValidationFailure::Failure_Mosaic_Expired);
+                }
+                if let Some(recipient_address) = recipient_address {
+                    let owner_address = definition.get_owner_address();
+                    if !definition.is_transferable() && *signer_address != owner_address && *recipient_address != owner_address {
+                        context.push(ValidationFailure::Failure_Mosaic_Non_Transferable);
+                    }
+                }
+            }
+            None => context.push(ValidationFailure::Failure_Mosaic_Expired),
+        }
+        if let Some(recipient_address) = recipient_address {
+            self.check_restrictions(context, &[recipient_address], |evaluator| evaluator.check_mosaic_transfer(recipient_address, &mosaic_id));
+            self.validate_mosaic_restrictions(context, signer_address, recipient_address, &mosaic_id);
+        }
+        context.debit(*signer_address, mosaic_id, mosaic.get_amount().get_amount());
+    }
+
+    fn validate_mosaic_restrictions(&self, context: &mut ValidationContext, signer_address: &AddressDto, recipient_address: &AddressDto, mosaic_id: &MosaicIdDto) {
+        let global_restriction = match self.provider.get_mosaic_global_restriction(mosaic_id) {
+            Some(global_restriction) => global_restriction,
+            None => return,
+        };
+        let reference_mosaic_ids = MosaicRestrictionEvaluator { global_restriction: &global_restriction, address_restrictions: &[] }.get_reference_mosaic_ids();
+        let address_restrictions: Vec<_> = reference_mosaic_ids.iter()
+            .flat_map(|reference_mosaic_id| [signer_address, recipient_address].iter()
+                .filter_map(|address| self.provider.get_mosaic_address_restriction(reference_mosaic_id, address))
+                .collect::<Vec<_>>())
+            .collect();
+        let evaluator = MosaicRestrictionEvaluator { global_restriction: &global_restriction, address_restrictions: &address_restrictions };
+        if let Err(violation) = evaluator.check_transfer(signer_address, recipient_address) {
+            context.push(violation.get_failure());
+        }
+    }
+
+    fn validate_namespace_registration(&self, context: &mut ValidationContext, signer_address: &AddressDto, body: &NamespaceRegistrationTransactionBodyBuilder) {
+        let height = self.provider.get_height();
+        match body.parent_id {
+            Some(parent_id) => match self.provider.get_root_namespace(&parent_id) {
+                Some(root_namespace) if root_namespace.get_alias(&parent_id).is_some() && root_namespace.get_lifetime().is_active(&height) => {
+                    if root_namespace.get_owner_address() != *signer_address {
+                        context.push(ValidationFailure::Failure_Namespace_Owner_Conflict);
+                    }
+                }
+                _ => context.push(ValidationFailure::Failure_Namespace_Unknown_Parent),
+            },
+            None => {
+                if let Some(root_namespace) = self.provider.get_root_namespace(&body.id) {
+                    if root_namespace.get_lifetime().is_active(&height) && root_namespace.get_owner_address() != *signer_address {
+                        context.push(ValidationFailure::Failure_Namespace_Owner_Conflict);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Checks that a root or child namespace exists and is active.
+    fn validate_namespace(&self, context: &mut ValidationContext, namespace_id: &NamespaceIdDto) {
+        match self.provider.get_root_namespace(namespace_id) {
+            Some(root_namespace) if root_namespace.get_alias(namespace_id).is_some() => {
+                if !root_namespace.get_lifetime().is_active(&self.provider.get_height()) {
+                    context.push(ValidationFailure::Failure_Namespace_Expired);
+                }
+            }
+            _ => context.push(ValidationFailure::Failure_Namespace_Unknown),
+        }
+    }
+
+    fn validate_balances(&self, context: &mut ValidationContext) {
+        let is_insufficient = context.debits.iter().any(|(address, mosaic_id, amount)| {
+            let balance = self.provider.get_account_state(address).map_or(0, |account_state| account_state.get_balance(mosaic_id).get_amount());
+            balance < *amount
+        });
+        if is_insufficient {
+            context.push(ValidationFailure::Failure_Core_Insufficient_Balance);
+        }
+    }
+
+    /// Runs a check of the account restriction evaluator on the restrictions of the given accounts.
+    fn check_restrictions(&self, context: &mut ValidationContext, addresses: &[&AddressDto], check: impl Fn(&AccountRestrictionEvaluator) -> Option<AccountRestrictionViolation>) {
+        let restrictions: Vec<_> = addresses.iter().filter_map(|address| self.provider.get_account_restrictions(address)).collect();
+        if let Some(violation) = check(&AccountRestrictionEvaluator { restrictions: &restrictions }) {
+            context.push(violation.get_failure());
+        }
+    }
+}
@@ -19,13 +19,18 @@
  * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
  */
 
+use sha3::{Digest, Sha3_256};
+
 use super::amount_dto::*;
 use super::entity_type_dto::*;
 use super::generator_utils::*;
+use super::hash256_dto::*;
 use super::key_dto::*;
 use super::network_type_dto::*;
 use super::signature_dto::*;
 use super::timestamp_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for a transaction.
 #[derive(Debug, Clone)]
@@ -47,6 +52,11 @@ pub struct TransactionBuilder {
 }
 
 impl TransactionBuilder {
+    /// Offset of the first byte covered by the entity signature (size, reserved, signature, signer and reserved are skipped).
+    const VERIFIABLE_DATA_OFFSET: usize = 108;
+    /// Size of the aggregate data covered by the entity signature (header fields and transactions hash).
+    const AGGREGATE_VERIFIABLE_DATA_SIZE: usize = 52;
+
     /// Creates an instance of TransactionBuilder from binary payload.
     /// payload: Byte payload to use to serialize the object.
     /// # Returns
@@ -79,6 +89,18 @@ impl TransactionBuilder {
         TransactionBuilder { signature, signer_public_key, version, network, _type, fee, deadline }
     }
 
+    /// Runs the stateless checks of the node on the transaction header.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the header is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = vec![];
+        if self.network != config.network {
+            failures.push(ValidationFailure::Failure_Core_Wrong_Network);
+        }
+        failures
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -116,5 +138,35 @@ impl TransactionBuilder {
         buf.append(&mut self.deadline.serializer()); // kind:CUSTOM
         buf
     }
-}
 
+    /// Gets the part of a serialized transaction that is covered by its signature.
+    /// Aggregate transactions only sign their header and transactions hash.
+    /// payload: Serialized transaction.
+    /// # Returns
+    /// A verifiable data.
+    pub fn get_verifiable_data(payload: &[u8]) -> Vec<u8> {
+        let header = Self::from_binary(payload);
+        let start = Self::VERIFIABLE_DATA_OFFSET;
+        match header._type {
+            EntityTypeDto::AGGREGATE_COMPLETE_TRANSACTION | EntityTypeDto::AGGREGATE_BONDED_TRANSACTION => {
+                payload[start..start + Self::AGGREGATE_VERIFIABLE_DATA_SIZE].to_vec()
+            }
+            _ => payload[start..].to_vec(),
+        }
+    }
+
+    /// Calculates the hash of a serialized transaction.
+    /// payload: Serialized transaction.
+    /// generation_hash_seed: Network generation hash seed.
+    /// # Returns
+    /// A transaction hash.
+    pub fn calculate_transaction_hash(payload: &[u8], generation_hash_seed: &Hash256Dto) -> Hash256Dto {
+        let header = Self::from_binary(payload);
+        let mut hasher = Sha3_256::new();
+        hasher.update(&header.signature.0[..SignatureDto::LENGTH / 2]);
+        hasher.update(header.signer_public_key.0);
+        hasher.update(generation_hash_seed.0);
+        hasher.update(Self::get_verifiable_data(payload));
+        Hash256Dto::from_binary(&hasher.finalize())
+    }
+}
@@ -0,0 +1,183 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use std::fmt::Debug;
+
+use num_traits::FromPrimitive;
+
+use crate::generator_utils::*;
+use crate::network_type_dto::NetworkTypeDto;
+
+use crate::transaction_builder::TransactionBuilder;
+use crate::validation_config::ValidationConfig;
+use crate::validation_failure::ValidationFailure;
+
+use super::account_address_restriction_transaction_builder::*;
+use super::account_key_link_transaction_builder::*;
+use super::account_metadata_transaction_builder::*;
+use super::account_mosaic_restriction_transaction_builder::*;
+use super::account_operation_restriction_transaction_builder::*;
+use super::address_alias_transaction_builder::*;
+use super::aggregate_bonded_transaction_builder::*;
+use super::aggregate_complete_transaction_builder::*;
+use super::hash_lock_transaction_builder::*;
+use super::mosaic_address_restriction_transaction_builder::*;
+use super::mosaic_alias_transaction_builder::*;
+use super::mosaic_definition_transaction_builder::*;
+use super::mosaic_global_restriction_transaction_builder::*;
+use super::mosaic_metadata_transaction_builder::*;
+use super::mosaic_supply_change_transaction_builder::*;
+use super::multisig_account_modification_transaction_builder::*;
+use super::namespace_metadata_transaction_builder::*;
+use super::namespace_registration_transaction_builder::*;
+use super::node_key_link_transaction_builder::*;
+use super::secret_lock_transaction_builder::*;
+use super::secret_proof_transaction_builder::*;
+use super::transfer_transaction_builder::*;
+use super::voting_key_link_transaction_builder::*;
+use super::vrf_key_link_transaction_builder::*;
+
+/// Helper trait for top-level transaction serialization.
+pub trait TransactionHelper: Debug + Send + Sync {
+    fn box_clone(&self) -> Box<dyn TransactionHelper>;
+    fn get_size(&self) -> usize;
+    fn serializer(&self) -> Vec<u8>;
+
+    /// Runs the stateless checks of the node on the transaction.
+    /// Only the header is checked unless the implementation overrides it.
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        TransactionBuilder::from_binary(&self.serializer()).validate(config)
+    }
+}
+
+impl Clone for Box<dyn TransactionHelper + 'static> {
+    fn clone(&self) -> Box<dyn TransactionHelper + 'static> {
+        self.box_clone()
+    }
+}
+
+/// Offset of the transaction version (size, reserved, signature, signer and reserved are skipped).
+const VERSION_OFFSET: usize = 108;
+
+/// Offset of the transaction network.
+const NETWORK_OFFSET: usize = 109;
+
+/// Offset of the transaction type (version and network are skipped).
+const TYPE_OFFSET: usize = 110;
+
+/// Transaction of an unknown type or version, kept as is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawTransaction {
+    /// Transaction payload, cut to its size field.
+    pub payload: Vec<u8>,
+}
+
+impl RawTransaction {
+    /// Creates an instance of RawTransaction from binary payload.
+    /// The payload is cut to the size field, or kept whole when the size field is zero or too large.
+    /// payload: Byte payload to use to serialize the object.
+    /// # Returns
+    /// A RawTransaction.
+    pub fn from_binary(payload: &[u8]) -> Self {
+        let size = u32::from_le_bytes(fixed_bytes::<4>(payload)) as usize;
+        let size = if size == 0 || size > payload.len() { payload.len() } else { size };
+        RawTransaction { payload: payload[..size].to_vec() }
+    }
+
+    /// Gets the raw transaction type.
+    ///
+    /// # Returns
+    /// A Transaction type value, or None when the payload is shorter than a transaction header.
+    pub fn get_type_value(&self) -> Option<u16> {
+        let bytes = self.payload.get(TYPE_OFFSET..TYPE_OFFSET + 2)?;
+        Some(u16::from_le_bytes(fixed_bytes::<2>(bytes)))
+    }
+
+    /// Gets the transaction version.
+    ///
+    /// # Returns
+    /// A Version, or None when the payload is shorter than a transaction header.
+    pub fn get_version(&self) -> Option<u8> {
+        self.payload.get(VERSION_OFFSET).copied()
+    }
+}
+
+impl TransactionHelper for RawTransaction {
+    fn box_clone(&self) -> Box<dyn TransactionHelper> {
+        Box::new((*self).clone())
+    }
+
+    fn get_size(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn serializer(&self) -> Vec<u8> {
+        self.payload.clone()
+    }
+
+    /// Unknown transactions cannot be checked and report no failure.
+    fn validate(&self, _config: &ValidationConfig) -> Vec<ValidationFailure> {
+        vec![]
+    }
+}
+
+/// Creates a top-level transaction from binary payload, dispatching on its type and version.
+/// Transactions of an unknown type, version or network are kept as a RawTransaction.
+/// payload: Byte payload to use to serialize the object.
+/// # Returns
+/// A transaction.
+pub fn load_transaction_from_binary(payload: &[u8]) -> Box<dyn TransactionHelper> {
+    let raw_transaction = RawTransaction::from_binary(payload);
+    let (entity_type, entity_type_version) = match (raw_transaction.get_type_value(), raw_transaction.get_version()) {
+        (Some(entity_type), Some(entity_type_version)) => (entity_type, entity_type_version),
+        _ => return Box::new(raw_transaction),
+    };
+    if NetworkTypeDto::from_u8(raw_transaction.payload[NETWORK_OFFSET]).is_none() {
+        return Box::new(raw_transaction);
+    }
+    match entity_type {
+        0x414c if entity_type_version == 1 => Box::new(AccountKeyLinkTransactionBuilder::from_binary(payload)),
+        0x424c if entity_type_version == 1 => Box::new(NodeKeyLinkTransactionBuilder::from_binary(payload)),
+        0x4143 if entity_type_version == 1 => Box::new(VotingKeyLinkTransactionBuilder::from_binary(payload)),
+        0x4243 if entity_type_version == 1 => Box::new(VrfKeyLinkTransactionBuilder::from_binary(payload)),
+        0x4148 if entity_type_version == 1 => Box::new(HashLockTransactionBuilder::from_binary(payload)),
+        0x4152 if entity_type_version == 1 => Box::new(SecretLockTransactionBuilder::from_binary(payload)),
+        0x4252 if entity_type_version == 1 => Box::new(SecretProofTransactionBuilder::from_binary(payload)),
+        0x4144 if entity_type_version == 1 => Box::new(AccountMetadataTransactionBuilder::from_binary(payload)),
+        0x4244 if entity_type_version == 1 => Box::new(MosaicMetadataTransactionBuilder::from_binary(payload)),
+        0x4344 if entity_type_version == 1 => Box::new(NamespaceMetadataTransactionBuilder::from_binary(payload)),
+        0x414d if entity_type_version == 1 => Box::new(MosaicDefinitionTransactionBuilder::from_binary(payload)),
+        0x424d if entity_type_version == 1 => Box::new(MosaicSupplyChangeTransactionBuilder::from_binary(payload)),
+        0x4155 if entity_type_version == 1 => Box::new(MultisigAccountModificationTransactionBuilder::from_binary(payload)),
+        0x424e if entity_type_version == 1 => Box::new(AddressAliasTransactionBuilder::from_binary(payload)),
+        0x434e if entity_type_version == 1 => Box::new(MosaicAliasTransactionBuilder::from_binary(payload)),
+        0x414e if entity_type_version == 1 => Box::new(NamespaceRegistrationTransactionBuilder::from_binary(payload)),
+        0x4150 if entity_type_version == 1 => Box::new(AccountAddressRestrictionTransactionBuilder::from_binary(payload)),
+        0x4250 if entity_type_version == 1 => Box::new(AccountMosaicRestrictionTransactionBuilder::from_binary(payload)),
+        0x4350 if entity_type_version == 1 => Box::new(AccountOperationRestrictionTransactionBuilder::from_binary(payload)),
+        0x4251 if entity_type_version == 1 => Box::new(MosaicAddressRestrictionTransactionBuilder::from_binary(payload)),
+        0x4151 if entity_type_version == 1 => Box::new(MosaicGlobalRestrictionTransactionBuilder::from_binary(payload)),
+        0x4154 if entity_type_version == 1 => Box::new(TransferTransactionBuilder::from_binary(payload)),
+        0x4141 if entity_type_version == 1 => Box::new(AggregateCompleteTransactionBuilder::from_binary(payload)),
+        0x4241 if entity_type_version == 1 => Box::new(AggregateBondedTransactionBuilder::from_binary(payload)),
+        _ => Box::new(raw_transaction),
+    }
+}
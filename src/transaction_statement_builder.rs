@@ -0,0 +1,119 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use sha3::{Digest, Sha3_256};
+
+use super::generator_utils::*;
+use super::hash256_dto::*;
+use super::receipt::*;
+use super::receipt_source_builder::*;
+use super::receipt_type_dto::*;
+
+/// Binary layout for a transaction statement.
+#[derive(Debug, Clone)]
+pub struct TransactionStatementBuilder {
+    /// Source of the receipts within block.
+    source: ReceiptSourceBuilder,
+    /// Receipts.
+    receipts: Vec<Receipt>,
+}
+
+
+impl TransactionStatementBuilder {
+    /// Statement version used when hashing.
+    const VERSION: u16 = 1;
+
+    /// Creates an instance of TransactionStatementBuilder from binary payload.
+    /// payload: Byte payload to use to serialize the object.
+    /// # Returns
+    /// A TransactionStatementBuilder.
+    pub fn from_binary(_bytes: &[u8]) -> Self {
+        let source = ReceiptSourceBuilder::from_binary(_bytes); // kind:CUSTOM1
+        let mut _bytes = _bytes[source.get_size()..].to_vec();
+        let buf = fixed_bytes::<4>(&_bytes);
+        let receipts_count = u32::from_le_bytes(buf); // kind:SIZE_FIELD
+        _bytes = _bytes[4..].to_vec();
+        let mut receipts: Vec<Receipt> = vec![]; // kind:ARRAY
+        for _ in 0..receipts_count {
+            let item = load_receipt_from_binary(&_bytes);
+            _bytes = _bytes[item.get_size()..].to_vec();
+            receipts.push(item);
+        }
+        TransactionStatementBuilder { source, receipts }
+    }
+
+    /// Gets source of the receipts within block.
+    ///
+    /// # Returns
+    /// A Source of the receipts within block.
+    pub fn get_source(&self) -> ReceiptSourceBuilder {
+        self.source.clone()
+    }
+
+    /// Gets receipts.
+    ///
+    /// # Returns
+    /// A Receipts.
+    pub fn get_receipts(&self) -> Vec<Receipt> {
+        self.receipts.clone() // ARRAY or FILL_ARRAY
+    }
+
+    /// Calculates the statement hash the way Catapult does.
+    /// Receipts are hashed without their size field.
+    /// # Returns
+    /// A statement hash.
+    pub fn calculate_hash(&self) -> Hash256Dto {
+        let mut hasher = Sha3_256::new();
+        hasher.update(Self::VERSION.to_le_bytes());
+        hasher.update(ReceiptTypeDto::TRANSACTION_GROUP.serializer());
+        hasher.update(self.source.serializer());
+        for receipt in &self.receipts {
+            hasher.update(&receipt.serializer()[4..]);
+        }
+        Hash256Dto::from_binary(&hasher.finalize())
+    }
+
+    /// Gets the size of the type.
+    ///
+    /// Returns:
+    /// A size in bytes.
+    pub fn get_size(&self) -> usize {
+        let mut size = 0;
+        size += self.source.get_size(); // source;
+        size += 4; // receipts_count;
+        size += self.receipts.iter().map(|item| item.get_size()).sum::<usize>(); // array or fill_array;
+        size
+    }
+
+    /// Serializes self to bytes.
+    ///
+    /// # Returns
+    /// A Serialized bytes.
+    pub fn serializer(&self) -> Vec<u8> {
+        let mut buf: Vec<u8> = vec![];
+        buf.append(&mut self.source.serializer()); // kind:CUSTOM
+        buf.append(&mut (self.receipts.len() as u32).to_le_bytes().to_vec()); // kind:SIZE_FIELD
+        for i in &self.receipts {
+            buf.append(&mut i.serializer()); // kind:ARRAY|FILL_ARRAY
+        }
+        buf
+    }
+}
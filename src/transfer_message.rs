@@ -0,0 +1,176 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use std::str;
+
+use super::key_dto::*;
+#[cfg(feature = "crypto")]
+use super::key_pair::*;
+#[cfg(feature = "crypto")]
+use super::message_cipher::*;
+use super::message_type_dto::*;
+
+/// Marker starting a persistent harvesting delegation message (message type included).
+pub const PERSISTENT_HARVESTING_DELEGATION_MARKER: [u8; 8] = [0xFE, 0x2A, 0x80, 0x61, 0x57, 0x73, 0x01, 0xE2];
+
+/// Typed content of a transfer message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransferMessage {
+    /// No message.
+    Empty,
+    /// Plain message, usually UTF-8 text.
+    Plain(Vec<u8>),
+    /// Encrypted message payload.
+    Encrypted(Vec<u8>),
+    /// Persistent harvesting delegation request.
+    PersistentHarvestingDelegation {
+        /// Ephemeral public key used to derive the encryption key.
+        ephemeral_public_key: KeyDto,
+        /// Encrypted signing and VRF private keys.
+        encrypted_payload: Vec<u8>,
+    },
+    /// Message of an unknown type, kept as is.
+    Raw(Vec<u8>),
+}
+
+impl TransferMessage {
+    /// Creates a plain message from text.
+    /// text: Message text.
+    /// # Returns
+    /// A TransferMessage.
+    pub fn from_text(text: &str) -> Self {
+        TransferMessage::Plain(text.as_bytes().to_vec())
+    }
+
+    /// Gets the message type.
+    ///
+    /// # Returns
+    /// A Message type or None for empty and unknown messages.
+    pub fn get_message_type(&self) -> Option<MessageTypeDto> {
+        match self {
+            TransferMessage::Plain(_) => Some(MessageTypeDto::PLAIN_MESSAGE),
+            TransferMessage::Encrypted(_) => Some(MessageTypeDto::ENCRYPTED_MESSAGE),
+            TransferMessage::PersistentHarvestingDelegation { .. } => Some(MessageTypeDto::PERSISTENT_HARVESTING_DELEGATION_MESSAGE),
+            TransferMessage::Empty | TransferMessage::Raw(_) => None,
+        }
+    }
+
+    /// Gets the text of a plain message.
+    ///
+    /// # Returns
+    /// A Text or None if the message is not a plain UTF-8 message.
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            TransferMessage::Plain(payload) => str::from_utf8(payload).ok(),
+            _ => None,
+        }
+    }
+
+    /// Creates an encrypted message readable by the sender and the recipient.
+    /// The payload is stored as upper case hex of tag, initialization vector and cipher text, as Symbol wallets expect.
+    /// key_pair: Sender key pair.
+    /// recipient_public_key: Recipient public key.
+    /// message: Clear message.
+    /// # Returns
+    /// A TransferMessage.
+    #[cfg(feature = "crypto")]
+    pub fn encrypt(key_pair: &KeyPair, recipient_public_key: &KeyDto, message: &[u8]) -> Result<Self, MessageCipherError> {
+        let encrypted = encrypt_message(key_pair, recipient_public_key, message)?;
+        let payload = encrypted.iter().map(|byte| format!("{:02X}", byte)).collect::<String>();
+        Ok(TransferMessage::Encrypted(payload.into_bytes()))
+    }
+
+    /// Decrypts an encrypted message.
+    /// Both the hex payload written by wallets and the raw binary payload are accepted.
+    /// key_pair: Key pair of the sender or the recipient.
+    /// other_public_key: Public key of the other party.
+    /// # Returns
+    /// A Clear message.
+    #[cfg(feature = "crypto")]
+    pub fn decrypt(&self, key_pair: &KeyPair, other_public_key: &KeyDto) -> Result<Vec<u8>, MessageCipherError> {
+        let payload = match self {
+            TransferMessage::Encrypted(payload) => payload,
+            _ => return Err(MessageCipherError::UnexpectedMessageType),
+        };
+        match Self::decode_hex(payload) {
+            Some(encrypted) => decrypt_message(key_pair, other_public_key, &encrypted)
+                .or_else(|_| decrypt_message(key_pair, other_public_key, payload)),
+            None => decrypt_message(key_pair, other_public_key, payload),
+        }
+    }
+
+    #[cfg(feature = "crypto")]
+    fn decode_hex(payload: &[u8]) -> Option<Vec<u8>> {
+        payload.chunks(2).map(|pair| match pair {
+            [high, low] => {
+                let high = (*high as char).to_digit(16)?;
+                let low = (*low as char).to_digit(16)?;
+                Some((high * 16 + low) as u8)
+            }
+            _ => None,
+        }).collect()
+    }
+
+    /// Creates an instance of TransferMessage from the message field of a transfer.
+    /// payload: Message field bytes (message type included).
+    /// # Returns
+    /// A TransferMessage.
+    pub fn from_binary(payload: &[u8]) -> Self {
+        let marker_size = PERSISTENT_HARVESTING_DELEGATION_MARKER.len();
+        match payload.first() {
+            None => TransferMessage::Empty,
+            Some(0x00) => TransferMessage::Plain(payload[1..].to_vec()),
+            Some(0x01) => TransferMessage::Encrypted(payload[1..].to_vec()),
+            Some(0xFE) if payload.len() >= marker_size + KeyDto::LENGTH && payload[..marker_size] == PERSISTENT_HARVESTING_DELEGATION_MARKER => {
+                let ephemeral_public_key = KeyDto::from_binary(&payload[marker_size..]);
+                let encrypted_payload = payload[marker_size + KeyDto::LENGTH..].to_vec();
+                TransferMessage::PersistentHarvestingDelegation { ephemeral_public_key, encrypted_payload }
+            }
+            Some(_) => TransferMessage::Raw(payload.to_vec()),
+        }
+    }
+
+    /// Gets the size of the type.
+    ///
+    /// Returns:
+    /// A size in bytes.
+    pub fn get_size(&self) -> usize {
+        self.serializer().len()
+    }
+
+    /// Serializes self to the message field of a transfer.
+    ///
+    /// # Returns
+    /// A Serialized bytes.
+    pub fn serializer(&self) -> Vec<u8> {
+        match self {
+            TransferMessage::Empty => vec![],
+            TransferMessage::Plain(payload) | TransferMessage::Encrypted(payload) => {
+                let message_type = self.get_message_type().unwrap();
+                [message_type.serializer(), payload.clone()].concat()
+            }
+            TransferMessage::PersistentHarvestingDelegation { ephemeral_public_key, encrypted_payload } => {
+                [PERSISTENT_HARVESTING_DELEGATION_MARKER.to_vec(), ephemeral_public_key.serializer(), encrypted_payload.clone()].concat()
+            }
+            TransferMessage::Raw(payload) => payload.clone(),
+        }
+    }
+}
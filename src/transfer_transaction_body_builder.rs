@@ -20,8 +20,11 @@
  */
 
 use super::generator_utils::*;
+use super::transfer_message::*;
 use super::unresolved_address_dto::*;
 use super::unresolved_mosaic_builder::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for a transfer transaction.
 #[derive(Debug, Clone)]
@@ -67,6 +70,36 @@ impl TransferTransactionBodyBuilder {
         TransferTransactionBodyBuilder { recipient_address, mosaics, message } // TransactionBody
     }
 
+    /// Gets the typed message.
+    ///
+    /// # Returns
+    /// A Transfer message.
+    pub fn get_transfer_message(&self) -> TransferMessage {
+        TransferMessage::from_binary(&self.message)
+    }
+
+    /// Sets the message from a typed message.
+    /// message: Transfer message.
+    pub fn set_transfer_message(&mut self, message: &TransferMessage) {
+        self.message = message.serializer();
+    }
+
+    /// Runs the stateless checks of the node on the body.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the body is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = vec![];
+        if self.message.len() > config.max_message_size {
+            failures.push(ValidationFailure::Failure_Transfer_Message_Too_Large);
+        }
+        if self.mosaics.windows(2).any(|pair| pair[0].get_mosaic_id().0 >= pair[1].get_mosaic_id().0) {
+            failures.push(ValidationFailure::Failure_Transfer_Out_Of_Order_Mosaics);
+        }
+        failures
+    }
+
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -23,13 +23,21 @@ use super::amount_dto::*;
 use super::entity_type_dto::*;
 use super::generator_utils::*;
 use super::key_dto::*;
+#[cfg(feature = "crypto")]
+use super::key_pair::*;
+#[cfg(feature = "crypto")]
+use super::message_cipher::*;
 use super::network_type_dto::*;
 use super::signature_dto::*;
 use super::timestamp_dto::*;
 use super::transaction_builder::*;
+use super::transaction_helper::*;
+use super::transfer_message::*;
 use super::transfer_transaction_body_builder::*;
 use super::unresolved_address_dto::*;
 use super::unresolved_mosaic_builder::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 
 /// Binary layout for a non-embedded transfer transaction.
 #[derive(Debug, Clone)]
@@ -81,6 +89,51 @@ impl TransferTransactionBuilder {
         self.body.message = message;   // MARKER1 AttributeKind.BUFFER
     }
 
+    /// Gets the typed message.
+    ///
+    /// # Returns
+    /// A Transfer message.
+    pub fn get_transfer_message(&self) -> TransferMessage {
+        self.body.get_transfer_message()
+    }
+
+    /// Sets the message from a typed message.
+    /// message: Transfer message.
+    pub fn set_transfer_message(&mut self, message: &TransferMessage) {
+        self.body.set_transfer_message(message);
+    }
+
+    /// Sets an encrypted message readable by the sender and the recipient.
+    /// key_pair: Sender key pair.
+    /// recipient_public_key: Recipient public key.
+    /// message: Clear message.
+    #[cfg(feature = "crypto")]
+    pub fn set_encrypted_message(&mut self, key_pair: &KeyPair, recipient_public_key: &KeyDto, message: &[u8]) -> Result<(), MessageCipherError> {
+        let message = TransferMessage::encrypt(key_pair, recipient_public_key, message)?;
+        self.set_transfer_message(&message);
+        Ok(())
+    }
+
+    /// Decrypts an encrypted message.
+    /// key_pair: Key pair of the sender or the recipient.
+    /// other_public_key: Public key of the other party.
+    /// # Returns
+    /// A Clear message.
+    #[cfg(feature = "crypto")]
+    pub fn decrypt_message(&self, key_pair: &KeyPair, other_public_key: &KeyDto) -> Result<Vec<u8>, MessageCipherError> {
+        self.get_transfer_message().decrypt(key_pair, other_public_key)
+    }
+
+    /// Runs the stateless checks of the node on the transaction.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the transaction is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = self.super_object.validate(config);
+        failures.append(&mut self.body.validate(config));
+        failures
+    }
+
     /// Gets the size of the type.
     ///
     /// Returns:
@@ -104,3 +157,20 @@ impl TransferTransactionBuilder {
     }
 }
 
+impl TransactionHelper for TransferTransactionBuilder {
+    fn box_clone(&self) -> Box<dyn TransactionHelper> {
+        Box::new((*self).clone())
+    }
+
+    fn get_size(&self) -> usize {
+        self.get_size()
+    }
+
+    fn serializer(&self) -> Vec<u8> {
+        self.serializer()
+    }
+
+    fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        self.validate(config)
+    }
+}
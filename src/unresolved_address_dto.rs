@@ -19,15 +19,55 @@
  * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
  */
 
+use super::address_dto::*;
 use super::generator_utils::*;
+use super::namespace_id_dto::*;
+use super::network_type_dto::*;
 
 /// Unresolved address.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct UnresolvedAddressDto(pub [u8; 24]);
 
 impl UnresolvedAddressDto {
     pub const LENGTH: usize = std::mem::size_of::<Self>();
 
+    /// Creates an unresolved address pointing to a namespace alias.
+    /// The network byte is kept with the alias flag set, followed by the little-endian namespace id.
+    /// network: Network of the transaction.
+    /// namespace_id: Namespace id.
+    /// # Returns
+    /// A UnresolvedAddressDto.
+    pub fn from_namespace_id(network: NetworkTypeDto, namespace_id: &NamespaceIdDto) -> Self {
+        let mut unresolved_address = [0u8; Self::LENGTH];
+        unresolved_address[0] = network.get_value() | 0x01;
+        unresolved_address[1..9].copy_from_slice(&namespace_id.serializer());
+        Self(unresolved_address)
+    }
+
+    /// Checks if the address is a namespace alias (lowest bit of the first byte set).
+    ///
+    /// # Returns
+    /// True if the address is a namespace alias.
+    pub fn is_alias(&self) -> bool {
+        self.0[0] & 0x01 != 0
+    }
+
+    /// Gets the namespace id of an alias.
+    ///
+    /// # Returns
+    /// A Namespace id or None if the address is not an alias.
+    pub fn as_namespace_id(&self) -> Option<NamespaceIdDto> {
+        if self.is_alias() { Some(NamespaceIdDto::from_binary(&self.0[1..9])) } else { None }
+    }
+
+    /// Gets the address if it is not an alias.
+    ///
+    /// # Returns
+    /// An Address or None if the address is an alias.
+    pub fn as_address(&self) -> Option<AddressDto> {
+        if self.is_alias() { None } else { Some(AddressDto(self.0)) }
+    }
+
     /// Gets the size of the type.
     ///
     /// # Returns
@@ -64,3 +104,9 @@ impl UnresolvedAddressDto {
         Self(buf)
     }
 }
+
+impl From<AddressDto> for UnresolvedAddressDto {
+    fn from(address: AddressDto) -> Self {
+        Self(address.0)
+    }
+}
@@ -20,14 +20,40 @@
  */
 
 use super::generator_utils::*;
+use super::mosaic_id_dto::*;
+use super::namespace_id_dto::*;
 
 /// Unresolved mosaic id.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct UnresolvedMosaicIdDto(pub u64);
 
 impl UnresolvedMosaicIdDto {
     pub const LENGTH: usize = std::mem::size_of::<Self>();
 
+    /// Checks if the mosaic id is a namespace alias (highest bit set).
+    ///
+    /// # Returns
+    /// True if the mosaic id is a namespace alias.
+    pub fn is_alias(&self) -> bool {
+        self.0 >> 63 != 0
+    }
+
+    /// Gets the namespace id of an alias.
+    ///
+    /// # Returns
+    /// A Namespace id or None if the mosaic id is not an alias.
+    pub fn as_namespace_id(&self) -> Option<NamespaceIdDto> {
+        if self.is_alias() { Some(NamespaceIdDto(self.0)) } else { None }
+    }
+
+    /// Gets the mosaic id if it is not an alias.
+    ///
+    /// # Returns
+    /// A Mosaic id or None if the mosaic id is an alias.
+    pub fn as_mosaic_id(&self) -> Option<MosaicIdDto> {
+        if self.is_alias() { None } else { Some(MosaicIdDto(self.0)) }
+    }
+
     /// Gets the size of the type.
     ///
     /// # Returns
@@ -62,3 +88,15 @@ impl UnresolvedMosaicIdDto {
         Self(u64::from_le_bytes(buf))
     }
 }
+
+impl From<MosaicIdDto> for UnresolvedMosaicIdDto {
+    fn from(mosaic_id: MosaicIdDto) -> Self {
+        Self(mosaic_id.0)
+    }
+}
+
+impl From<NamespaceIdDto> for UnresolvedMosaicIdDto {
+    fn from(namespace_id: NamespaceIdDto) -> Self {
+        Self(namespace_id.0)
+    }
+}
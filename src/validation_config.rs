@@ -0,0 +1,102 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use super::mosaic_id_dto::*;
+use super::network_type_dto::*;
+
+/// Network limits used by validation.
+/// The default values are the ones of the Symbol public network, with 30 second blocks.
+#[derive(Debug, Clone)]
+pub struct ValidationConfig {
+    /// Network entities must belong to.
+    pub network: NetworkTypeDto,
+    /// Maximum transfer message size in bytes.
+    pub max_message_size: usize,
+    /// Maximum number of inner transactions in an aggregate.
+    pub max_transactions_per_aggregate: usize,
+    /// Maximum number of cosignatures in an aggregate.
+    pub max_cosignatures_per_aggregate: usize,
+    /// Maximum number of cosignatories of a multisig account.
+    pub max_cosignatories_per_account: u8,
+    /// Maximum namespace name size in bytes.
+    pub max_name_size: usize,
+    /// Minimum root namespace duration in blocks.
+    pub min_namespace_duration: u64,
+    /// Maximum root namespace duration in blocks.
+    pub max_namespace_duration: u64,
+    /// Maximum mosaic duration in blocks.
+    pub max_mosaic_duration: u64,
+    /// Maximum mosaic divisibility.
+    pub max_mosaic_divisibility: u8,
+    /// Maximum hash lock duration in blocks.
+    pub max_hash_lock_duration: u64,
+    /// Maximum secret lock duration in blocks.
+    pub max_secret_lock_duration: u64,
+    /// Minimum secret proof size in bytes.
+    pub min_proof_size: usize,
+    /// Maximum secret proof size in bytes.
+    pub max_proof_size: usize,
+    /// Maximum metadata value size in bytes.
+    pub max_value_size: usize,
+    /// Maximum number of values in an account restriction modification.
+    pub max_account_restriction_values: usize,
+    /// Minimum voting key lifetime in epochs.
+    pub min_voting_key_lifetime: u32,
+    /// Maximum voting key lifetime in epochs.
+    pub max_voting_key_lifetime: u32,
+    /// Number of blocks between importance blocks.
+    pub importance_grouping: u64,
+    /// Targeted time between blocks in milliseconds.
+    pub block_generation_target_time: u64,
+    /// Number of previous blocks the difficulty is calculated from.
+    pub max_difficulty_blocks: usize,
+    /// Mosaic transaction fees are paid with.
+    pub currency_mosaic_id: MosaicIdDto,
+}
+
+impl Default for ValidationConfig {
+    fn default() -> Self {
+        ValidationConfig {
+            network: NetworkTypeDto::PUBLIC,
+            max_message_size: 1024,
+            max_transactions_per_aggregate: 100,
+            max_cosignatures_per_aggregate: 25,
+            max_cosignatories_per_account: 25,
+            max_name_size: 64,
+            min_namespace_duration: 86_400,
+            max_namespace_duration: 5_256_000,
+            max_mosaic_duration: 10_512_000,
+            max_mosaic_divisibility: 6,
+            max_hash_lock_duration: 5_760,
+            max_secret_lock_duration: 1_051_200,
+            min_proof_size: 20,
+            max_proof_size: 1024,
+            max_value_size: 1024,
+            max_account_restriction_values: 100,
+            min_voting_key_lifetime: 112,
+            max_voting_key_lifetime: 360,
+            importance_grouping: 720,
+            block_generation_target_time: 30_000,
+            max_difficulty_blocks: 60,
+            currency_mosaic_id: MosaicIdDto(0x6BED_913F_A202_23F8),
+        }
+    }
+}
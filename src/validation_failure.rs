@@ -0,0 +1,229 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use std::fmt;
+
+use super::validation_result_dto::*;
+
+/// Validation failures, named after the Catapult result codes.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValidationFailure {
+    /// Entity network does not match the configured network.
+    Failure_Core_Wrong_Network,
+
+    /// Link epoch range is inverted or its length is out of bounds.
+    Failure_Core_Invalid_Link_Range,
+
+    /// Block type does not match the block height.
+    Failure_Core_Unexpected_Block_Type,
+
+    /// Account balance does not cover the transaction.
+    Failure_Core_Insufficient_Balance,
+
+    /// Entity signature does not verify against its signer.
+    Failure_Signature_Not_Verifiable,
+
+    /// Block does not follow the previous block hash and height.
+    Failure_Chain_Unlinked,
+
+    /// Block timestamp is before the timestamp of the previous block.
+    /// The node has no result code for it.
+    Failure_Chain_Timestamp_Decreasing,
+
+    /// Importance block does not point at the previous importance block.
+    /// The node has no result code for it.
+    Failure_Chain_Importance_Block_Unlinked,
+
+    /// Aggregate has more inner transactions than allowed.
+    Failure_Aggregate_Too_Many_Transactions,
+
+    /// Aggregate has no inner transactions.
+    Failure_Aggregate_No_Transactions,
+
+    /// Aggregate has more cosignatures than allowed.
+    Failure_Aggregate_Too_Many_Cosignatures,
+
+    /// Aggregate is cosigned more than once by the same account, or by its signer.
+    Failure_Aggregate_Redundant_Cosignatures,
+
+    /// Aggregate transaction is embedded in an aggregate.
+    /// The node has no result code for it and rejects the payload as malformed.
+    Failure_Aggregate_Nested_Aggregate,
+
+    /// Transfer message is larger than allowed.
+    Failure_Transfer_Message_Too_Large,
+
+    /// Transfer mosaics are not sorted by id or contain duplicates.
+    Failure_Transfer_Out_Of_Order_Mosaics,
+
+    /// Namespace name is empty, too long or contains invalid characters.
+    Failure_Namespace_Invalid_Name,
+
+    /// Namespace id is not derived from the name and the parent id.
+    Failure_Namespace_Name_Id_Mismatch,
+
+    /// Root namespace duration is out of bounds.
+    Failure_Namespace_Invalid_Duration,
+
+    /// Namespace does not exist.
+    Failure_Namespace_Unknown,
+
+    /// Namespace is no longer active.
+    Failure_Namespace_Expired,
+
+    /// Parent namespace does not exist or is no longer active.
+    Failure_Namespace_Unknown_Parent,
+
+    /// Active root namespace is owned by another account.
+    Failure_Namespace_Owner_Conflict,
+
+    /// Mosaic divisibility is larger than allowed.
+    Failure_Mosaic_Invalid_Divisibility,
+
+    /// Mosaic duration is larger than allowed.
+    Failure_Mosaic_Invalid_Duration,
+
+    /// Mosaic supply change delta is zero.
+    Failure_Mosaic_Invalid_Supply_Change_Amount,
+
+    /// Mosaic does not exist or is no longer active.
+    Failure_Mosaic_Expired,
+
+    /// Non transferable mosaic is sent between accounts that do not own it.
+    Failure_Mosaic_Non_Transferable,
+
+    /// Multisig modification adds and deletes the same account.
+    Failure_Multisig_Account_In_Both_Sets,
+
+    /// Multisig modification contains the same account twice.
+    Failure_Multisig_Redundant_Modification,
+
+    /// Multisig minimum approval or removal delta is out of bounds.
+    Failure_Multisig_Min_Setting_Out_Of_Range,
+
+    /// Hash lock duration is zero or larger than allowed.
+    Failure_LockHash_Invalid_Duration,
+
+    /// Secret lock duration is zero or larger than allowed.
+    Failure_LockSecret_Invalid_Duration,
+
+    /// Secret proof size is out of bounds.
+    Failure_LockSecret_Proof_Size_Out_Of_Bounds,
+
+    /// Secret proof does not hash into the secret.
+    Failure_LockSecret_Secret_Mismatch,
+
+    /// Metadata value is empty.
+    Failure_Metadata_Value_Too_Small,
+
+    /// Metadata value is larger than allowed.
+    Failure_Metadata_Value_Too_Large,
+
+    /// Metadata value size delta is larger than the value.
+    Failure_Metadata_Value_Size_Delta_Too_Large,
+
+    /// Account restriction flags do not match the restriction transaction.
+    Failure_RestrictionAccount_Invalid_Restriction_Flags,
+
+    /// Account restriction modification contains the same value twice.
+    Failure_RestrictionAccount_Redundant_Modification,
+
+    /// Account restriction modification has more values than allowed.
+    Failure_RestrictionAccount_Modification_Count_Exceeded,
+
+    /// Account restrictions do not allow the interaction with the address.
+    Failure_RestrictionAccount_Address_Interaction_Prohibited,
+
+    /// Account restrictions do not allow receiving the mosaic.
+    Failure_RestrictionAccount_Mosaic_Transfer_Prohibited,
+
+    /// Account restrictions do not allow sending the transaction type.
+    Failure_RestrictionAccount_Operation_Type_Prohibited,
+
+    /// Account does not satisfy the global restrictions of the mosaic.
+    Failure_RestrictionMosaic_Account_Unauthorized,
+}
+
+impl ValidationFailure {
+    /// Gets the result code the node reports for the failure.
+    ///
+    /// # Returns
+    ///
+    /// A `ValidationResultDto`, or None when the node has no code for the failure.
+    pub fn get_result(&self) -> Option<ValidationResultDto> {
+        let result = match self {
+            ValidationFailure::Failure_Core_Wrong_Network => ValidationResultDto::Failure_Core_Wrong_Network,
+            ValidationFailure::Failure_Core_Invalid_Link_Range => ValidationResultDto::Failure_Core_Invalid_Link_Range,
+            ValidationFailure::Failure_Core_Unexpected_Block_Type => ValidationResultDto::Failure_Core_Unexpected_Block_Type,
+            ValidationFailure::Failure_Core_Insufficient_Balance => ValidationResultDto::Failure_Core_Insufficient_Balance,
+            ValidationFailure::Failure_Aggregate_Too_Many_Transactions => ValidationResultDto::Failure_Aggregate_Too_Many_Transactions,
+            ValidationFailure::Failure_Aggregate_No_Transactions => ValidationResultDto::Failure_Aggregate_No_Transactions,
+            ValidationFailure::Failure_Aggregate_Too_Many_Cosignatures => ValidationResultDto::Failure_Aggregate_Too_Many_Cosignatures,
+            ValidationFailure::Failure_Aggregate_Redundant_Cosignatures => ValidationResultDto::Failure_Aggregate_Redundant_Cosignatures,
+            ValidationFailure::Failure_Transfer_Message_Too_Large => ValidationResultDto::Failure_Transfer_Message_Too_Large,
+            ValidationFailure::Failure_Transfer_Out_Of_Order_Mosaics => ValidationResultDto::Failure_Transfer_Out_Of_Order_Mosaics,
+            ValidationFailure::Failure_Namespace_Invalid_Name => ValidationResultDto::Failure_Namespace_Invalid_Name,
+            ValidationFailure::Failure_Namespace_Name_Id_Mismatch => ValidationResultDto::Failure_Namespace_Name_Id_Mismatch,
+            ValidationFailure::Failure_Namespace_Invalid_Duration => ValidationResultDto::Failure_Namespace_Invalid_Duration,
+            ValidationFailure::Failure_Namespace_Unknown => ValidationResultDto::Failure_Namespace_Unknown,
+            ValidationFailure::Failure_Namespace_Expired => ValidationResultDto::Failure_Namespace_Expired,
+            ValidationFailure::Failure_Namespace_Unknown_Parent => ValidationResultDto::Failure_Namespace_Unknown_Parent,
+            ValidationFailure::Failure_Namespace_Owner_Conflict => ValidationResultDto::Failure_Namespace_Owner_Conflict,
+            ValidationFailure::Failure_Mosaic_Invalid_Divisibility => ValidationResultDto::Failure_Mosaic_Invalid_Divisibility,
+            ValidationFailure::Failure_Mosaic_Invalid_Duration => ValidationResultDto::Failure_Mosaic_Invalid_Duration,
+            ValidationFailure::Failure_Mosaic_Invalid_Supply_Change_Amount => ValidationResultDto::Failure_Mosaic_Invalid_Supply_Change_Amount,
+            ValidationFailure::Failure_Mosaic_Expired => ValidationResultDto::Failure_Mosaic_Expired,
+            ValidationFailure::Failure_Mosaic_Non_Transferable => ValidationResultDto::Failure_Mosaic_Non_Transferable,
+            ValidationFailure::Failure_Multisig_Account_In_Both_Sets => ValidationResultDto::Failure_Multisig_Account_In_Both_Sets,
+            ValidationFailure::Failure_Multisig_Redundant_Modification => ValidationResultDto::Failure_Multisig_Redundant_Modification,
+            ValidationFailure::Failure_Multisig_Min_Setting_Out_Of_Range => ValidationResultDto::Failure_Multisig_Min_Setting_Out_Of_Range,
+            ValidationFailure::Failure_LockHash_Invalid_Duration => ValidationResultDto::Failure_LockHash_Invalid_Duration,
+            ValidationFailure::Failure_LockSecret_Invalid_Duration => ValidationResultDto::Failure_LockSecret_Invalid_Duration,
+            ValidationFailure::Failure_LockSecret_Proof_Size_Out_Of_Bounds => ValidationResultDto::Failure_LockSecret_Proof_Size_Out_Of_Bounds,
+            ValidationFailure::Failure_LockSecret_Secret_Mismatch => ValidationResultDto::Failure_LockSecret_Secret_Mismatch,
+            ValidationFailure::Failure_Metadata_Value_Too_Small => ValidationResultDto::Failure_Metadata_Value_Too_Small,
+            ValidationFailure::Failure_Metadata_Value_Too_Large => ValidationResultDto::Failure_Metadata_Value_Too_Large,
+            ValidationFailure::Failure_Metadata_Value_Size_Delta_Too_Large => ValidationResultDto::Failure_Metadata_Value_Size_Delta_Too_Large,
+            ValidationFailure::Failure_RestrictionAccount_Invalid_Restriction_Flags => ValidationResultDto::Failure_RestrictionAccount_Invalid_Restriction_Flags,
+            ValidationFailure::Failure_RestrictionAccount_Redundant_Modification => ValidationResultDto::Failure_RestrictionAccount_Redundant_Modification,
+            ValidationFailure::Failure_RestrictionAccount_Modification_Count_Exceeded => ValidationResultDto::Failure_RestrictionAccount_Modification_Count_Exceeded,
+            ValidationFailure::Failure_RestrictionAccount_Address_Interaction_Prohibited => ValidationResultDto::Failure_RestrictionAccount_Address_Interaction_Prohibited,
+            ValidationFailure::Failure_RestrictionAccount_Mosaic_Transfer_Prohibited => ValidationResultDto::Failure_RestrictionAccount_Mosaic_Transfer_Prohibited,
+            ValidationFailure::Failure_RestrictionAccount_Operation_Type_Prohibited => ValidationResultDto::Failure_RestrictionAccount_Operation_Type_Prohibited,
+            ValidationFailure::Failure_RestrictionMosaic_Account_Unauthorized => ValidationResultDto::Failure_RestrictionMosaic_Account_Unauthorized,
+            ValidationFailure::Failure_Signature_Not_Verifiable => ValidationResultDto::Failure_Signature_Not_Verifiable,
+            ValidationFailure::Failure_Chain_Unlinked => ValidationResultDto::Failure_Chain_Unlinked,
+            ValidationFailure::Failure_Chain_Timestamp_Decreasing
+            | ValidationFailure::Failure_Chain_Importance_Block_Unlinked
+            | ValidationFailure::Failure_Aggregate_Nested_Aggregate => return None,
+        };
+        Some(result)
+    }
+}
+
+impl fmt::Display for ValidationFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for ValidationFailure {}
@@ -0,0 +1,549 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use std::fmt;
+
+use num_derive::{FromPrimitive, ToPrimitive};
+use num_traits::{FromPrimitive, ToPrimitive};
+use strum_macros::EnumIter;
+
+use super::facility_code_dto::*;
+use super::generator_utils::*;
+use super::result_severity_dto::*;
+
+/// Enumeration of validation results reported by the node.
+/// The value packs the severity into the two high bits, the facility into the next byte and the code into the low 16 bits.
+#[allow(non_camel_case_types)]
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, FromPrimitive, ToPrimitive, EnumIter)]
+pub enum ValidationResultDto {
+    /// Validation succeeded.
+    Success = 0x00000000,
+
+    /// Validation was neutral.
+    Neutral = 0x40000000,
+
+    /// Validation failed.
+    Failure = 0x80000000,
+
+    /// Validation failed because the aggregate has too many transactions.
+    Failure_Aggregate_Too_Many_Transactions = 0x80410001,
+
+    /// Validation failed because the aggregate does not have any transactions.
+    Failure_Aggregate_No_Transactions = 0x80410002,
+
+    /// Validation failed because the aggregate has too many cosignatures.
+    Failure_Aggregate_Too_Many_Cosignatures = 0x80410003,
+
+    /// Validation failed because redundant cosignatures are present.
+    Failure_Aggregate_Redundant_Cosignatures = 0x80410004,
+
+    /// Validation failed because at least one cosignatory is ineligible.
+    Failure_Aggregate_Ineligible_Cosignatories = 0x80410005,
+
+    /// Validation failed because at least one required cosignature is missing.
+    Failure_Aggregate_Missing_Cosignatures = 0x80410006,
+
+    /// Validation failed because the aggregate transactions hash does not match the calculated value.
+    Failure_Aggregate_Transactions_Hash_Mismatch = 0x80410007,
+
+    /// Validation failed because the deadline passed.
+    Failure_Core_Past_Deadline = 0x80430001,
+
+    /// Validation failed because the deadline is too far in the future.
+    Failure_Core_Future_Deadline = 0x80430002,
+
+    /// Validation failed because the account has an insufficient balance.
+    Failure_Core_Insufficient_Balance = 0x80430003,
+
+    /// Validation failed because there are too many transactions in a block.
+    Failure_Core_Too_Many_Transactions = 0x80430004,
+
+    /// Validation failed because an entity originated from the nemesis account after the nemesis block.
+    Failure_Core_Nemesis_Account_Signed_After_Nemesis_Block = 0x80430005,
+
+    /// Validation failed because the entity has the wrong network specified.
+    Failure_Core_Wrong_Network = 0x80430006,
+
+    /// Validation failed because an address is invalid.
+    Failure_Core_Invalid_Address = 0x80430007,
+
+    /// Validation failed because the entity version is invalid.
+    Failure_Core_Invalid_Version = 0x80430008,
+
+    /// Validation failed because a transaction fee is invalid.
+    Failure_Core_Invalid_Transaction_Fee = 0x80430009,
+
+    /// Validation failed because a block was harvested by an ineligible harvester.
+    Failure_Core_Block_Harvester_Ineligible = 0x8043000A,
+
+    /// Validation failed because an address is zero.
+    Failure_Core_Zero_Address = 0x8043000B,
+
+    /// Validation failed because a public key is zero.
+    Failure_Core_Zero_Public_Key = 0x8043000C,
+
+    /// Validation failed because internal padding is nonzero.
+    Failure_Core_Nonzero_Internal_Padding = 0x8043000D,
+
+    /// Validation failed because an address collision is detected.
+    Failure_Core_Address_Collision = 0x8043000E,
+
+    /// Validation failed because the block does not match the importance block schedule.
+    Failure_Core_Importance_Block_Mismatch = 0x8043000F,
+
+    /// Validation failed because the block type is unexpected.
+    Failure_Core_Unexpected_Block_Type = 0x80430010,
+
+    /// Validation failed because the block transactions hash does not match the explicit transactions.
+    Failure_Core_Block_Explicit_Transactions_Hash_Mismatch = 0x80430011,
+
+    /// Validation failed because the link action is invalid.
+    Failure_Core_Invalid_Link_Action = 0x80430012,
+
+    /// Validation failed because the main account is already linked to another account.
+    Failure_Core_Link_Already_Exists = 0x80430013,
+
+    /// Validation failed because the unlink data is not consistent with the existing link.
+    Failure_Core_Inconsistent_Unlink_Data = 0x80430014,
+
+    /// Validation failed because the link range is invalid.
+    Failure_Core_Invalid_Link_Range = 0x80430015,
+
+    /// Validation failed because the main account has too many links of the specified type.
+    Failure_Core_Too_Many_Links = 0x80430016,
+
+    /// Validation failed because the start epoch is invalid.
+    Failure_Core_Link_Start_Epoch_Invalid = 0x80430017,
+
+    /// Validation failed because the metadata value is too small.
+    Failure_Metadata_Value_Too_Small = 0x80440001,
+
+    /// Validation failed because the metadata value is too large.
+    Failure_Metadata_Value_Too_Large = 0x80440002,
+
+    /// Validation failed because the metadata value size delta is larger than the value size.
+    Failure_Metadata_Value_Size_Delta_Too_Large = 0x80440003,
+
+    /// Validation failed because the metadata value size delta does not match the expected value.
+    Failure_Metadata_Value_Size_Delta_Mismatch = 0x80440004,
+
+    /// Validation failed because a metadata value change truncates bytes that cannot be restored.
+    Failure_Metadata_Value_Change_Irreversible = 0x80440005,
+
+    /// Validation failed because the lock mosaic is not the currency mosaic.
+    Failure_LockHash_Invalid_Mosaic_Id = 0x80480001,
+
+    /// Validation failed because the lock amount is invalid.
+    Failure_LockHash_Invalid_Mosaic_Amount = 0x80480002,
+
+    /// Validation failed because the hash is already locked.
+    Failure_LockHash_Hash_Already_Exists = 0x80480003,
+
+    /// Validation failed because the hash is not locked.
+    Failure_LockHash_Unknown_Hash = 0x80480004,
+
+    /// Validation failed because the hash lock is inactive.
+    Failure_LockHash_Inactive_Hash = 0x80480005,
+
+    /// Validation failed because the hash lock duration is too long.
+    Failure_LockHash_Invalid_Duration = 0x80480006,
+
+    /// Validation failed because the account is already linked.
+    Failure_AccountLink_Link_Already_Exists = 0x804C0001,
+
+    /// Validation failed because the unlink data is not consistent with the existing link.
+    Failure_AccountLink_Inconsistent_Unlink_Data = 0x804C0002,
+
+    /// Validation failed because the account is not linked.
+    Failure_AccountLink_Unknown_Link = 0x804C0003,
+
+    /// Validation failed because the remote account is ineligible for linking.
+    Failure_AccountLink_Remote_Account_Ineligible = 0x804C0004,
+
+    /// Validation failed because the remote account signed a transaction.
+    Failure_AccountLink_Remote_Account_Signer_Prohibited = 0x804C0005,
+
+    /// Validation failed because the remote account participated in a transaction.
+    Failure_AccountLink_Remote_Account_Participant_Prohibited = 0x804C0006,
+
+    /// Validation failed because the mosaic duration is invalid.
+    Failure_Mosaic_Invalid_Duration = 0x804D0001,
+
+    /// Validation failed because the mosaic expired.
+    Failure_Mosaic_Expired = 0x804D0004,
+
+    /// Validation failed because the mosaic owner conflicts with the signer.
+    Failure_Mosaic_Owner_Conflict = 0x804D0005,
+
+    /// Validation failed because the mosaic id does not match the nonce and owner.
+    Failure_Mosaic_Id_Mismatch = 0x804D0006,
+
+    /// Validation failed because the mosaic parent conflicts with the existing parent.
+    Failure_Mosaic_Parent_Id_Conflict = 0x804D0064,
+
+    /// Validation failed because a mosaic property is invalid.
+    Failure_Mosaic_Invalid_Property = 0x804D0065,
+
+    /// Validation failed because the mosaic flags are invalid.
+    Failure_Mosaic_Invalid_Flags = 0x804D0066,
+
+    /// Validation failed because the mosaic divisibility is invalid.
+    Failure_Mosaic_Invalid_Divisibility = 0x804D0067,
+
+    /// Validation failed because the mosaic supply change action is invalid.
+    Failure_Mosaic_Invalid_Supply_Change_Action = 0x804D0068,
+
+    /// Validation failed because the mosaic supply change amount is invalid.
+    Failure_Mosaic_Invalid_Supply_Change_Amount = 0x804D0069,
+
+    /// Validation failed because the mosaic id is invalid.
+    Failure_Mosaic_Invalid_Id = 0x804D006A,
+
+    /// Validation failed because the mosaic definition cannot be modified.
+    Failure_Mosaic_Modification_Disallowed = 0x804D006B,
+
+    /// Validation failed because the mosaic definition modification does not change anything.
+    Failure_Mosaic_Modification_No_Changes = 0x804D006C,
+
+    /// Validation failed because the mosaic supply is immutable.
+    Failure_Mosaic_Supply_Immutable = 0x804D006D,
+
+    /// Validation failed because the resulting mosaic supply is negative.
+    Failure_Mosaic_Supply_Negative = 0x804D006E,
+
+    /// Validation failed because the resulting mosaic supply exceeds the maximum.
+    Failure_Mosaic_Supply_Exceeded = 0x804D006F,
+
+    /// Validation failed because the mosaic is not transferable.
+    Failure_Mosaic_Non_Transferable = 0x804D0070,
+
+    /// Validation failed because the account owns too many mosaics.
+    Failure_Mosaic_Max_Mosaics_Exceeded = 0x804D0071,
+
+    /// Validation failed because the mosaic lacks a required property flag.
+    Failure_Mosaic_Required_Property_Flag_Unset = 0x804D0072,
+
+    /// Validation failed because the namespace duration is invalid.
+    Failure_Namespace_Invalid_Duration = 0x804E0001,
+
+    /// Validation failed because the namespace name is invalid.
+    Failure_Namespace_Invalid_Name = 0x804E0002,
+
+    /// Validation failed because the namespace id does not match the name and parent.
+    Failure_Namespace_Name_Id_Mismatch = 0x804E0003,
+
+    /// Validation failed because the namespace expired.
+    Failure_Namespace_Expired = 0x804E0004,
+
+    /// Validation failed because the namespace owner conflicts with the signer.
+    Failure_Namespace_Owner_Conflict = 0x804E0005,
+
+    /// Validation failed because the namespace id does not match the expected id.
+    Failure_Namespace_Id_Mismatch = 0x804E0006,
+
+    /// Validation failed because the namespace registration type is invalid.
+    Failure_Namespace_Invalid_Registration_Type = 0x804E0064,
+
+    /// Validation failed because the root namespace name is reserved.
+    Failure_Namespace_Root_Name_Reserved = 0x804E0065,
+
+    /// Validation failed because the namespace is nested too deeply.
+    Failure_Namespace_Too_Deep = 0x804E0066,
+
+    /// Validation failed because the parent namespace is unknown.
+    Failure_Namespace_Unknown_Parent = 0x804E0067,
+
+    /// Validation failed because the namespace already exists.
+    Failure_Namespace_Already_Exists = 0x804E0068,
+
+    /// Validation failed because the namespace is already active.
+    Failure_Namespace_Already_Active = 0x804E0069,
+
+    /// Validation failed because an eternal namespace is registered after the nemesis block.
+    Failure_Namespace_Eternal_After_Nemesis_Block = 0x804E006A,
+
+    /// Validation failed because the namespace has too many children.
+    Failure_Namespace_Max_Children_Exceeded = 0x804E006B,
+
+    /// Validation failed because the alias action is invalid.
+    Failure_Namespace_Alias_Invalid_Action = 0x804E006C,
+
+    /// Validation failed because the namespace is unknown.
+    Failure_Namespace_Unknown = 0x804E006D,
+
+    /// Validation failed because the namespace is already aliased.
+    Failure_Namespace_Alias_Already_Exists = 0x804E006E,
+
+    /// Validation failed because the namespace has no alias.
+    Failure_Namespace_Unknown_Alias = 0x804E006F,
+
+    /// Validation failed because the unlink alias type does not match the existing alias.
+    Failure_Namespace_Alias_Inconsistent_Unlink_Type = 0x804E0070,
+
+    /// Validation failed because the unlink alias data does not match the existing alias.
+    Failure_Namespace_Alias_Inconsistent_Unlink_Data = 0x804E0071,
+
+    /// Validation failed because the aliased address is invalid.
+    Failure_Namespace_Alias_Invalid_Address = 0x804E0072,
+
+    /// Validation failed because the account restriction flags are invalid.
+    Failure_RestrictionAccount_Invalid_Restriction_Flags = 0x80500001,
+
+    /// Validation failed because the account restriction modification action is invalid.
+    Failure_RestrictionAccount_Invalid_Modification_Action = 0x80500002,
+
+    /// Validation failed because the account restriction address is invalid.
+    Failure_RestrictionAccount_Invalid_Modification_Address = 0x80500003,
+
+    /// Validation failed because the restricted operation type is incompatible.
+    Failure_RestrictionAccount_Modification_Operation_Type_Incompatible = 0x80500004,
+
+    /// Validation failed because the account restriction modification is redundant.
+    Failure_RestrictionAccount_Redundant_Modification = 0x80500005,
+
+    /// Validation failed because a value is deleted that is not restricted.
+    Failure_RestrictionAccount_Invalid_Modification = 0x80500006,
+
+    /// Validation failed because the account restriction modification has too many values.
+    Failure_RestrictionAccount_Modification_Count_Exceeded = 0x80500007,
+
+    /// Validation failed because the account restriction modification has no values.
+    Failure_RestrictionAccount_No_Modifications = 0x80500008,
+
+    /// Validation failed because the resulting account restriction has too many values.
+    Failure_RestrictionAccount_Values_Count_Exceeded = 0x80500009,
+
+    /// Validation failed because the account restriction value is invalid.
+    Failure_RestrictionAccount_Invalid_Value = 0x8050000A,
+
+    /// Validation failed because the account does not allow interactions with the address.
+    Failure_RestrictionAccount_Address_Interaction_Prohibited = 0x8050000B,
+
+    /// Validation failed because the account does not allow receiving the mosaic.
+    Failure_RestrictionAccount_Mosaic_Transfer_Prohibited = 0x8050000C,
+
+    /// Validation failed because the account does not allow sending the transaction type.
+    Failure_RestrictionAccount_Operation_Type_Prohibited = 0x8050000D,
+
+    /// Validation failed because the mosaic restriction type is invalid.
+    Failure_RestrictionMosaic_Invalid_Restriction_Type = 0x80510001,
+
+    /// Validation failed because the previous restriction value does not match the current value.
+    Failure_RestrictionMosaic_Previous_Value_Mismatch = 0x80510002,
+
+    /// Validation failed because the previous restriction value is nonzero for a new restriction.
+    Failure_RestrictionMosaic_Previous_Value_Must_Be_Zero = 0x80510003,
+
+    /// Validation failed because the mosaic has too many restrictions.
+    Failure_RestrictionMosaic_Max_Restrictions_Exceeded = 0x80510004,
+
+    /// Validation failed because a nonexistent restriction is deleted.
+    Failure_RestrictionMosaic_Cannot_Delete_Nonexistent_Restriction = 0x80510005,
+
+    /// Validation failed because the referenced global restriction does not exist.
+    Failure_RestrictionMosaic_Unknown_Global_Restriction = 0x80510006,
+
+    /// Validation failed because the global restriction is invalid.
+    Failure_RestrictionMosaic_Invalid_Global_Restriction = 0x80510007,
+
+    /// Validation failed because the account is not authorized to transfer the mosaic.
+    Failure_RestrictionMosaic_Account_Unauthorized = 0x80510008,
+
+    /// Validation failed because the hash algorithm is invalid.
+    Failure_LockSecret_Invalid_Hash_Algorithm = 0x80520001,
+
+    /// Validation failed because the secret is already locked.
+    Failure_LockSecret_Hash_Already_Exists = 0x80520002,
+
+    /// Validation failed because the proof size is out of bounds.
+    Failure_LockSecret_Proof_Size_Out_Of_Bounds = 0x80520003,
+
+    /// Validation failed because the proof does not hash into the secret.
+    Failure_LockSecret_Secret_Mismatch = 0x80520004,
+
+    /// Validation failed because the secret and recipient are not locked.
+    Failure_LockSecret_Unknown_Composite_Key = 0x80520005,
+
+    /// Validation failed because the secret lock is inactive.
+    Failure_LockSecret_Inactive_Secret = 0x80520006,
+
+    /// Validation failed because the hash algorithm does not match the lock.
+    Failure_LockSecret_Hash_Algorithm_Mismatch = 0x80520007,
+
+    /// Validation failed because the secret lock duration is too long.
+    Failure_LockSecret_Invalid_Duration = 0x80520008,
+
+    /// Validation failed because the verification of the signature failed.
+    Failure_Signature_Not_Verifiable = 0x80530001,
+
+    /// Validation failed because the message is too large.
+    Failure_Transfer_Message_Too_Large = 0x80540001,
+
+    /// Validation failed because the mosaics are out of order.
+    Failure_Transfer_Out_Of_Order_Mosaics = 0x80540002,
+
+    /// Validation failed because an account is both added and deleted.
+    Failure_Multisig_Account_In_Both_Sets = 0x80550001,
+
+    /// Validation failed because more than one cosignatory is deleted.
+    Failure_Multisig_Multiple_Deletes = 0x80550002,
+
+    /// Validation failed because a cosignatory modification is redundant.
+    Failure_Multisig_Redundant_Modification = 0x80550003,
+
+    /// Validation failed because the account is not a multisig account.
+    Failure_Multisig_Unknown_Multisig_Account = 0x80550004,
+
+    /// Validation failed because the account is not a cosignatory.
+    Failure_Multisig_Not_A_Cosignatory = 0x80550005,
+
+    /// Validation failed because the account is already a cosignatory.
+    Failure_Multisig_Already_A_Cosignatory = 0x80550006,
+
+    /// Validation failed because the minimum approval or removal setting is out of range.
+    Failure_Multisig_Min_Setting_Out_Of_Range = 0x80550007,
+
+    /// Validation failed because the minimum setting is larger than the number of cosignatories.
+    Failure_Multisig_Min_Setting_Larger_Than_Num_Cosignatories = 0x80550008,
+
+    /// Validation failed because the modification action is invalid.
+    Failure_Multisig_Invalid_Modification_Action = 0x80550009,
+
+    /// Validation failed because the cosignatory cosigns too many accounts.
+    Failure_Multisig_Max_Cosigned_Accounts = 0x8055000A,
+
+    /// Validation failed because the multisig account has too many cosignatories.
+    Failure_Multisig_Max_Cosignatories = 0x8055000B,
+
+    /// Validation failed because the modification creates a multisig loop.
+    Failure_Multisig_Loop = 0x8055000C,
+
+    /// Validation failed because the multisig hierarchy is too deep.
+    Failure_Multisig_Max_Multisig_Depth = 0x8055000D,
+
+    /// Validation failed because the operation is not allowed for multisig accounts.
+    Failure_Multisig_Operation_Prohibited_By_Account = 0x8055000E,
+
+    /// Validation failed because a block was received that did not link with the existing chain.
+    Failure_Chain_Unlinked = 0x80FF0001,
+
+}
+
+impl ValidationResultDto {
+    pub const LENGTH: usize = std::mem::size_of::<Self>();
+
+    /// Gets the size of the type.
+    ///
+    /// # Returns
+    ///
+    /// A usize.
+    pub fn get_size(&self) -> usize {
+        Self::LENGTH
+    }
+
+    /// Gets the value of the enum.
+    ///
+    /// # Returns
+    ///
+    /// A u32
+    pub fn get_value(&self) -> u32 {
+        self.to_u32().unwrap()
+    }
+
+    /// Decodes a status code reported by the node.
+    /// value: Raw result code.
+    /// # Returns
+    /// The validation result, or None when the code is unknown.
+    pub fn from_value(value: u32) -> Option<Self> {
+        Self::from_u32(value)
+    }
+
+    /// Gets the severity of the result.
+    ///
+    /// # Returns
+    ///
+    /// A `ResultSeverityDto`.
+    pub fn get_severity(&self) -> ResultSeverityDto {
+        ResultSeverityDto::from_u32(self.get_value() >> 30).unwrap()
+    }
+
+    /// Gets the facility that reported the result.
+    ///
+    /// # Returns
+    ///
+    /// A `FacilityCodeDto`.
+    pub fn get_facility(&self) -> FacilityCodeDto {
+        FacilityCodeDto::from_u32((self.get_value() >> 16) & 0xFF).unwrap()
+    }
+
+    /// Gets the facility specific code of the result.
+    ///
+    /// # Returns
+    ///
+    /// A u16
+    pub fn get_code(&self) -> u16 {
+        (self.get_value() & 0xFFFF) as u16
+    }
+
+    /// Checks if the result is a failure.
+    ///
+    /// # Returns
+    ///
+    /// A bool.
+    pub fn is_failure(&self) -> bool {
+        self.get_severity() == ResultSeverityDto::Failure
+    }
+
+    /// Gets the name the node uses for the result.
+    ///
+    /// # Returns
+    ///
+    /// A String, e.g. `Failure_Core_Insufficient_Balance`.
+    pub fn get_name(&self) -> String {
+        format!("{:?}", self)
+    }
+
+    /// Creates an `ValidationResultDto` from a slice.
+    ///
+    /// # Returns
+    ///
+    /// A `ValidationResultDto`.
+    pub fn from_binary(src: &[u8]) -> Self {
+        let buf = fixed_bytes::<{ Self::LENGTH }>(src);
+        Self::from_u32(u32::from_le_bytes(buf)).unwrap()
+    }
+
+    /// Serializes an type to bytes.
+    ///
+    /// # Returns
+    ///
+    /// A Serialized bytes.
+    pub fn serializer(&self) -> Vec<u8> {
+        self.get_value().to_le_bytes().to_vec()
+    }
+}
+
+impl fmt::Display for ValidationResultDto {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
@@ -22,6 +22,8 @@
 use super::finalization_epoch_dto::*;
 use super::generator_utils::*;
 use super::link_action_dto::*;
+use super::validation_config::*;
+use super::validation_failure::*;
 use super::voting_key_dto::*;
 
 /// Binary layout for a voting key link transaction.
@@ -56,6 +58,21 @@ impl VotingKeyLinkTransactionBodyBuilder {
         VotingKeyLinkTransactionBodyBuilder { linked_public_key, start_epoch, end_epoch, link_action } // TransactionBody
     }
 
+    /// Runs the stateless checks of the node on the body.
+    /// config: Network limits.
+    /// # Returns
+    /// A list of validation failures, empty when the body is valid.
+    pub fn validate(&self, config: &ValidationConfig) -> Vec<ValidationFailure> {
+        let mut failures = vec![];
+        let (start_epoch, end_epoch) = (self.start_epoch.get_finalization_epoch(), self.end_epoch.get_finalization_epoch());
+        let lifetime = u64::from(config.min_voting_key_lifetime)..=u64::from(config.max_voting_key_lifetime);
+        if start_epoch > end_epoch || !lifetime.contains(&(u64::from(end_epoch) - u64::from(start_epoch) + 1)) {
+            failures.push(ValidationFailure::Failure_Core_Invalid_Link_Range);
+        }
+        failures
+    }
+
+
     /// Gets the size of the type.
     ///
     /// Returns:
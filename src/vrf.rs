@@ -0,0 +1,281 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use std::convert::TryInto;
+
+use curve25519_dalek::constants::ED25519_BASEPOINT_POINT;
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use curve25519_dalek::montgomery::MontgomeryPoint;
+use curve25519_dalek::scalar::Scalar;
+use sha2::{Digest, Sha512};
+
+use super::hash256_dto::*;
+use super::hash512_dto::*;
+use super::key_dto::*;
+use super::key_pair::*;
+use super::proof_gamma_dto::*;
+use super::proof_scalar_dto::*;
+use super::proof_verification_hash_dto::*;
+use super::vrf_proof_builder::*;
+
+/// Suite string of ECVRF-EDWARDS25519-SHA512-Elligator2 in draft-irtf-cfrg-vrf-03.
+const SUITE_STRING: u8 = 0x04;
+
+/// Montgomery coefficient A of curve25519.
+const CURVE25519_A: u64 = 486_662;
+
+/// Field prime 2^255 - 19 as little endian 64-bit limbs.
+const FIELD_PRIME: [u64; 4] = [0xFFFF_FFFF_FFFF_FFED, u64::MAX, u64::MAX, 0x7FFF_FFFF_FFFF_FFFF];
+
+/// Exponent p - 2, used to invert field elements.
+const INVERSION_EXPONENT: [u64; 4] = [0xFFFF_FFFF_FFFF_FFEB, u64::MAX, u64::MAX, 0x7FFF_FFFF_FFFF_FFFF];
+
+/// Exponent (p - 1) / 2, used to compute Legendre symbols.
+const LEGENDRE_EXPONENT: [u64; 4] = [0xFFFF_FFFF_FFFF_FFF6, u64::MAX, u64::MAX, 0x3FFF_FFFF_FFFF_FFFF];
+
+/// Element of the field of integers modulo 2^255 - 19, kept reduced.
+/// curve25519-dalek keeps its field arithmetic private, and Elligator2 needs it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FieldElement([u64; 4]);
+
+impl FieldElement {
+    fn from_u64(value: u64) -> Self {
+        FieldElement([value, 0, 0, 0])
+    }
+
+    fn from_bytes(bytes: &[u8; 32]) -> Self {
+        let mut wide = [0u64; 8];
+        for (limb, chunk) in wide.iter_mut().zip(bytes.chunks(8)) {
+            *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Self::reduce(wide)
+    }
+
+    fn to_bytes(self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (chunk, limb) in bytes.chunks_mut(8).zip(self.0.iter()) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Reduces a 512-bit value, using 2^256 = 38 and 2^255 = 19 modulo p.
+    fn reduce(wide: [u64; 8]) -> Self {
+        let mut limbs = [0u64; 4];
+        let mut carry = 0u128;
+        for i in 0..4 {
+            let value = wide[i] as u128 + wide[i + 4] as u128 * 38 + carry;
+            limbs[i] = value as u64;
+            carry = value >> 64;
+        }
+        let mut overflow = carry as u64 * 38;
+        while overflow != 0 {
+            overflow = add_small(&mut limbs, overflow) * 38;
+        }
+        while limbs[3] >> 63 == 1 {
+            limbs[3] &= u64::MAX >> 1;
+            add_small(&mut limbs, 19);
+        }
+        // the value is at least p when adding 19 reaches 2^255
+        let mut candidate = limbs;
+        add_small(&mut candidate, 19);
+        if candidate[3] >> 63 == 1 {
+            candidate[3] &= u64::MAX >> 1;
+            limbs = candidate;
+        }
+        FieldElement(limbs)
+    }
+
+    fn add(self, other: Self) -> Self {
+        let mut wide = [0u64; 8];
+        let mut carry = 0u128;
+        for (limb, (left, right)) in wide.iter_mut().zip(self.0.iter().zip(other.0.iter())) {
+            let value = *left as u128 + *right as u128 + carry;
+            *limb = value as u64;
+            carry = value >> 64;
+        }
+        wide[4] = carry as u64;
+        Self::reduce(wide)
+    }
+
+    fn neg(self) -> Self {
+        let mut wide = [0u64; 8];
+        let mut borrow = 0u64;
+        for i in 0..4 {
+            let (value, borrow_a) = FIELD_PRIME[i].overflowing_sub(self.0[i]);
+            let (value, borrow_b) = value.overflowing_sub(borrow);
+            wide[i] = value;
+            borrow = (borrow_a || borrow_b) as u64;
+        }
+        Self::reduce(wide)
+    }
+
+    fn sub(self, other: Self) -> Self {
+        self.add(other.neg())
+    }
+
+    fn mul(self, other: Self) -> Self {
+        let mut wide = [0u64; 8];
+        for i in 0..4 {
+            let mut carry = 0u128;
+            for j in 0..4 {
+                let value = wide[i + j] as u128 + self.0[i] as u128 * other.0[j] as u128 + carry;
+                wide[i + j] = value as u64;
+                carry = value >> 64;
+            }
+            wide[i + 4] = carry as u64;
+        }
+        Self::reduce(wide)
+    }
+
+    fn pow(self, exponent: &[u64; 4]) -> Self {
+        let mut result = Self::from_u64(1);
+        for limb in exponent.iter().rev() {
+            for bit in (0..64).rev() {
+                result = result.mul(result);
+                if (limb >> bit) & 1 == 1 {
+                    result = result.mul(self);
+                }
+            }
+        }
+        result
+    }
+
+    fn invert(self) -> Self {
+        self.pow(&INVERSION_EXPONENT)
+    }
+}
+
+fn add_small(limbs: &mut [u64; 4], value: u64) -> u64 {
+    let mut carry = value as u128;
+    for limb in limbs.iter_mut() {
+        let sum = *limb as u128 + carry;
+        *limb = sum as u64;
+        carry = sum >> 64;
+    }
+    carry as u64
+}
+
+/// Maps a field element to the u coordinate of a curve25519 point with Elligator2.
+fn map_to_curve(r: FieldElement) -> FieldElement {
+    let one = FieldElement::from_u64(1);
+    let a = FieldElement::from_u64(CURVE25519_A);
+    let r_squared = r.mul(r);
+    let u = a.neg().mul(one.add(r_squared).add(r_squared).invert());
+    let w = u.mul(u.mul(u).add(a.mul(u)).add(one));
+    let e = w.pow(&LEGENDRE_EXPONENT);
+    let half_a = a.mul(FieldElement::from_u64(2).invert());
+    e.mul(u).add(e.sub(one).mul(half_a))
+}
+
+/// Hashes the public key and input into a point of the prime order subgroup.
+fn hash_to_curve(public_key: &KeyDto, alpha: &[u8]) -> Option<EdwardsPoint> {
+    let hash = Sha512::new().chain([SUITE_STRING, 0x01]).chain(public_key.0).chain(alpha).finalize();
+    let mut r_bytes = [0u8; 32];
+    r_bytes.copy_from_slice(&hash[..32]);
+    r_bytes[31] &= 0x7F;
+    let u = map_to_curve(FieldElement::from_bytes(&r_bytes));
+    Some(MontgomeryPoint(u.to_bytes()).to_edwards(0)?.mul_by_cofactor())
+}
+
+fn hash_points(points: &[EdwardsPoint]) -> ProofVerificationHashDto {
+    let mut hasher = Sha512::new().chain([SUITE_STRING, 0x02]);
+    for point in points.iter() {
+        hasher.update(point.compress().as_bytes());
+    }
+    ProofVerificationHashDto::from_binary(&hasher.finalize()[..ProofVerificationHashDto::LENGTH])
+}
+
+fn to_scalar(verification_hash: &ProofVerificationHashDto) -> Scalar {
+    let mut bytes = [0u8; 32];
+    bytes[..ProofVerificationHashDto::LENGTH].copy_from_slice(&verification_hash.0);
+    Scalar::from_bytes_mod_order(bytes)
+}
+
+/// Generates an ECVRF-EDWARDS25519-SHA512-Elligator2 proof.
+/// alpha: Input of the verifiable random function, the previous generation hash for blocks.
+/// key_pair: VRF key pair.
+/// # Returns
+/// A VrfProofBuilder.
+pub fn generate_vrf_proof(alpha: &[u8], key_pair: &KeyPair) -> VrfProofBuilder {
+    let hashed_private_key = Sha512::digest(&key_pair.get_private_key());
+    let mut scalar_bytes = [0u8; 32];
+    scalar_bytes.copy_from_slice(&hashed_private_key[..32]);
+    scalar_bytes[0] &= 0xF8;
+    scalar_bytes[31] &= 0x7F;
+    scalar_bytes[31] |= 0x40;
+    let x = Scalar::from_bytes_mod_order(scalar_bytes);
+
+    let h = hash_to_curve(&key_pair.get_public_key(), alpha).expect("hash maps to a curve point");
+    let gamma = x * h;
+    let nonce_hash = Sha512::new().chain(&hashed_private_key[32..]).chain(h.compress().as_bytes()).finalize();
+    let mut nonce_bytes = [0u8; 64];
+    nonce_bytes.copy_from_slice(&nonce_hash);
+    let k = Scalar::from_bytes_mod_order_wide(&nonce_bytes);
+    let verification_hash = hash_points(&[h, gamma, k * ED25519_BASEPOINT_POINT, k * h]);
+    let s = k + to_scalar(&verification_hash) * x;
+    VrfProofBuilder {
+        gamma: ProofGammaDto(gamma.compress().to_bytes()),
+        verification_hash,
+        scalar: ProofScalarDto(s.to_bytes()),
+    }
+}
+
+/// Verifies an ECVRF-EDWARDS25519-SHA512-Elligator2 proof.
+/// vrf_proof: Proof to verify.
+/// alpha: Input of the verifiable random function.
+/// public_key: VRF public key.
+/// # Returns
+/// The proof hash, or None when the proof is invalid.
+pub fn verify_vrf_proof(vrf_proof: &VrfProofBuilder, alpha: &[u8], public_key: &KeyDto) -> Option<Hash512Dto> {
+    let y = CompressedEdwardsY(public_key.0).decompress()?;
+    if y.is_small_order() {
+        return None;
+    }
+    let gamma = CompressedEdwardsY(vrf_proof.gamma.0).decompress()?;
+    let s = Scalar::from_canonical_bytes(vrf_proof.scalar.0)?;
+    let c = to_scalar(&vrf_proof.verification_hash);
+
+    let h = hash_to_curve(public_key, alpha)?;
+    let u = EdwardsPoint::vartime_double_scalar_mul_basepoint(&-c, &y, &s);
+    let v = s * h - c * gamma;
+    if hash_points(&[h, gamma, u, v]).0 != vrf_proof.verification_hash.0 {
+        return None;
+    }
+    Some(calculate_vrf_proof_hash(&gamma))
+}
+
+fn calculate_vrf_proof_hash(gamma: &EdwardsPoint) -> Hash512Dto {
+    let hash = Sha512::new().chain([SUITE_STRING, 0x03]).chain(gamma.mul_by_cofactor().compress().as_bytes()).finalize();
+    Hash512Dto::from_binary(&hash)
+}
+
+/// Derives the generation hash of a block from its generation hash proof.
+/// vrf_proof: Generation hash proof of the block.
+/// previous_generation_hash: Generation hash of the previous block, the generation hash seed for the nemesis block.
+/// vrf_public_key: VRF public key linked to the harvester.
+/// # Returns
+/// The generation hash, or None when the proof is invalid.
+pub fn calculate_generation_hash(vrf_proof: &VrfProofBuilder, previous_generation_hash: &Hash256Dto, vrf_public_key: &KeyDto) -> Option<Hash256Dto> {
+    let proof_hash = verify_vrf_proof(vrf_proof, &previous_generation_hash.0, vrf_public_key)?;
+    Some(Hash256Dto::from_binary(&proof_hash.0[..Hash256Dto::LENGTH]))
+}
+
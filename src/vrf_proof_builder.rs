@@ -28,11 +28,11 @@ use super::proof_verification_hash_dto::*;
 #[derive(Debug, Clone)]
 pub struct VrfProofBuilder {
     /// Gamma.
-    gamma: ProofGammaDto,
+    pub gamma: ProofGammaDto,
     /// Verification hash.
-    verification_hash: ProofVerificationHashDto,
+    pub verification_hash: ProofVerificationHashDto,
     /// Scalar.
-    scalar: ProofScalarDto,
+    pub scalar: ProofScalarDto,
 }
 
 
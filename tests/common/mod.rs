@@ -0,0 +1,158 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Accounts and entity factories shared by the integration tests.
+
+#![allow(dead_code)]
+
+use catbuffer_rust::{
+    account_restriction_flags_dto::AccountRestrictionFlagsDto,
+    account_restrictions_builder::AccountRestrictionsBuilder,
+    address_dto::AddressDto,
+    amount_dto::AmountDto,
+    embedded_transaction_builder::EmbeddedTransactionBuilder,
+    embedded_transfer_transaction_builder::EmbeddedTransferTransactionBuilder,
+    entity_type_dto::EntityTypeDto,
+    key_dto::KeyDto,
+    network_type_dto::NetworkTypeDto,
+    signature_dto::SignatureDto,
+    timestamp_dto::TimestampDto,
+    transaction_builder::TransactionBuilder,
+    transfer_transaction_body_builder::TransferTransactionBodyBuilder,
+    transfer_transaction_builder::TransferTransactionBuilder,
+    unresolved_address_dto::UnresolvedAddressDto,
+    unresolved_mosaic_builder::UnresolvedMosaicBuilder,
+};
+
+pub const ALICE_PUBLIC_KEY: &str = "2E834140FD66CF87B254A693A2C7862C819217B676D3943267156625E816EC6F";
+pub const BOB_PUBLIC_KEY: &str = "4875FD2E32875D1BC6567745F1509F0F890A1BF8EE59FA74452FA4183A270E03";
+
+/// Public test network addresses of the keys above.
+pub const ALICE_ADDRESS: &str = "9826D27E1D0A26CA4E316F901E23E55C8711DB20DFD26776";
+pub const BOB_ADDRESS: &str = "98E3E25B5609D301E18D316FAA072763322EBCCBD602EDA8";
+pub const CAROL_ADDRESS: &str = "981111111111111111111111111111111111111111111111";
+
+pub const CURRENCY_MOSAIC_ID: u64 = 0x6BED_913F_A202_23F8;
+pub const TOKEN_MOSAIC_ID: u64 = 0x091F_837E_059A_E13C;
+pub const NAMESPACE_ID: u64 = 0xC0FF_EE00_0000_0001;
+
+pub fn key(public_key: &str) -> KeyDto {
+    KeyDto::from_binary(&hex::decode(public_key).unwrap())
+}
+
+/// Derives the public test network address of a public key.
+pub fn address(public_key: &str) -> AddressDto {
+    AddressDto::from_public_key(NetworkTypeDto::PUBLIC_TEST, &key(public_key))
+}
+
+pub fn to_address(address: &str) -> AddressDto {
+    AddressDto::from_binary(&hex::decode(address).unwrap())
+}
+
+/// Creates the unresolved address pointing at a namespace alias.
+pub fn alias_address(namespace_id: u64) -> UnresolvedAddressDto {
+    let mut unresolved_address = [0u8; 24];
+    unresolved_address[0] = NetworkTypeDto::PUBLIC_TEST.get_value() | 0x01;
+    unresolved_address[1..9].copy_from_slice(&namespace_id.to_le_bytes());
+    UnresolvedAddressDto(unresolved_address)
+}
+
+pub fn hex_u64(value: u64) -> String {
+    hex::encode(value.to_le_bytes())
+}
+
+pub fn create_restrictions(address: &AddressDto, rules: &[(Vec<AccountRestrictionFlagsDto>, Vec<Vec<u8>>)]) -> AccountRestrictionsBuilder {
+    let mut payload = [vec![1, 0], address.0.to_vec(), (rules.len() as u64).to_le_bytes().to_vec()].concat();
+    for (flags, values) in rules.iter() {
+        payload.extend(AccountRestrictionFlagsDto::flags_to_int(flags.clone()).to_le_bytes());
+        payload.extend((values.len() as u64).to_le_bytes());
+        payload.extend(values.concat());
+    }
+    AccountRestrictionsBuilder::from_binary(&payload)
+}
+
+pub fn create_transaction(signer_public_key: &str, _type: EntityTypeDto) -> TransactionBuilder {
+    TransactionBuilder {
+        signature: SignatureDto([0; 64]),
+        signer_public_key: key(signer_public_key),
+        version: 1,
+        network: NetworkTypeDto::PUBLIC_TEST,
+        _type,
+        fee: AmountDto(100),
+        deadline: TimestampDto(1),
+    }
+}
+
+pub fn create_mosaics(mosaics: &[(u64, u64)]) -> Vec<UnresolvedMosaicBuilder> {
+    mosaics.iter().map(|(mosaic_id, amount)| UnresolvedMosaicBuilder::from_binary(&hex::decode(hex_u64(*mosaic_id) + &hex_u64(*amount)).unwrap())).collect()
+}
+
+pub fn create_transfer_body(recipient_address: UnresolvedAddressDto, mosaics: &[(u64, u64)], message: &[u8]) -> TransferTransactionBodyBuilder {
+    TransferTransactionBodyBuilder { recipient_address, mosaics: create_mosaics(mosaics), message: message.to_vec() }
+}
+
+/// Creates a transfer signed by alice.
+pub fn create_transfer(recipient_address: UnresolvedAddressDto, mosaics: &[(u64, u64)]) -> Vec<u8> {
+    create_message_transfer(recipient_address, mosaics, &[])
+}
+
+pub fn create_message_transfer(recipient_address: UnresolvedAddressDto, mosaics: &[(u64, u64)], message: &[u8]) -> Vec<u8> {
+    TransferTransactionBuilder {
+        super_object: create_transaction(ALICE_PUBLIC_KEY, EntityTypeDto::TRANSFER_TRANSACTION),
+        body: create_transfer_body(recipient_address, mosaics, message),
+    }.serializer()
+}
+
+pub fn create_embedded_transfer(signer_public_key: &str, recipient_address: UnresolvedAddressDto, mosaics: &[(u64, u64)]) -> EmbeddedTransferTransactionBuilder {
+    EmbeddedTransferTransactionBuilder {
+        super_object: EmbeddedTransactionBuilder {
+            signer_public_key: key(signer_public_key),
+            version: 1,
+            network: NetworkTypeDto::PUBLIC_TEST,
+            _type: EntityTypeDto::TRANSFER_TRANSACTION,
+        },
+        body: create_transfer_body(recipient_address, mosaics, &[]),
+    }
+}
+
+/// Creates a block header harvested for bob, without the footer of its block type.
+pub fn create_block_header(block_type: &str, size: usize) -> Vec<u8> {
+    create_block_header_with_receipts_hash(block_type, size, &[0x44; 32])
+}
+
+pub fn create_block_header_with_receipts_hash(block_type: &str, size: usize, receipts_hash: &[u8]) -> Vec<u8> {
+    [
+        (size as u32).to_le_bytes().to_vec(),
+        vec![0; 4],
+        vec![0x11; 64],
+        vec![0x22; 32],
+        vec![0; 4],
+        hex::decode(format!("0198{}", block_type)).unwrap(),
+        hex::decode("0A000000000000004000000000000000E803000000000000").unwrap(),
+        vec![0x33; 80],
+        vec![0x44; 64],
+        receipts_hash.to_vec(),
+        vec![0x44; 32],
+        hex::decode(BOB_ADDRESS).unwrap(),
+        hex::decode("64000000").unwrap(),
+    ]
+    .concat()
+}
@@ -0,0 +1,89 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use catbuffer_rust::{
+    account_restriction_evaluator::{AccountRestrictionEvaluator, AccountRestrictionViolation},
+    account_restriction_flags_dto::AccountRestrictionFlagsDto,
+    entity_type_dto::EntityTypeDto,
+    mosaic_id_dto::MosaicIdDto,
+    unresolved_address_dto::UnresolvedAddressDto,
+    validation_failure::ValidationFailure,
+};
+
+use common::{address, alias_address, create_restrictions, create_transfer, to_address, ALICE_PUBLIC_KEY, BOB_ADDRESS, CAROL_ADDRESS, NAMESPACE_ID, TOKEN_MOSAIC_ID};
+
+#[test]
+fn test_should_evaluate_address_allow_and_block_lists() {
+    let alice = address(ALICE_PUBLIC_KEY);
+    let bob = to_address(BOB_ADDRESS);
+    let flags = vec![AccountRestrictionFlagsDto::ADDRESS];
+    let restrictions = [create_restrictions(&bob, &[(flags.clone(), vec![alice.0.to_vec()])])];
+    let evaluator = AccountRestrictionEvaluator { restrictions: &restrictions };
+    assert_eq!(evaluator.check_address_interaction(&alice, &bob), None);
+    assert_eq!(evaluator.check_address_interaction(&to_address(CAROL_ADDRESS), &bob), Some(AccountRestrictionViolation::AddressInteraction {
+        address: bob,
+        restriction_flags: flags,
+        counterparty_address: to_address(CAROL_ADDRESS),
+    }));
+
+    let flags = vec![AccountRestrictionFlagsDto::ADDRESS, AccountRestrictionFlagsDto::BLOCK];
+    let restrictions = [create_restrictions(&bob, &[(flags, vec![alice.0.to_vec()])])];
+    let evaluator = AccountRestrictionEvaluator { restrictions: &restrictions };
+    assert!(evaluator.check_address_interaction(&alice, &bob).is_some());
+    assert_eq!(evaluator.check_address_interaction(&to_address(CAROL_ADDRESS), &bob), None);
+    // incoming rules do not restrict what the account sends
+    assert_eq!(evaluator.check_address_interaction(&bob, &alice), None);
+}
+
+#[test]
+fn test_should_evaluate_transfer_against_sender_and_recipient_rules() {
+    let alice = address(ALICE_PUBLIC_KEY);
+    let bob = to_address(BOB_ADDRESS);
+    let outgoing_flags = vec![AccountRestrictionFlagsDto::ADDRESS, AccountRestrictionFlagsDto::OUTGOING, AccountRestrictionFlagsDto::BLOCK];
+    let operation_flags = vec![AccountRestrictionFlagsDto::TRANSACTION_TYPE, AccountRestrictionFlagsDto::OUTGOING];
+    let mosaic_flags = vec![AccountRestrictionFlagsDto::MOSAIC_ID, AccountRestrictionFlagsDto::BLOCK];
+    let restrictions = [
+        create_restrictions(&alice, &[
+            (outgoing_flags.clone(), vec![bob.0.to_vec()]),
+            (operation_flags.clone(), vec![EntityTypeDto::HASH_LOCK_TRANSACTION.serializer()]),
+        ]),
+        create_restrictions(&bob, &[(mosaic_flags.clone(), vec![TOKEN_MOSAIC_ID.to_le_bytes().to_vec()])]),
+    ];
+    let evaluator = AccountRestrictionEvaluator { restrictions: &restrictions };
+
+    let violations = evaluator.evaluate(&create_transfer(UnresolvedAddressDto(bob.0), &[(TOKEN_MOSAIC_ID, 1), (0x1234, 1)]));
+    assert_eq!(violations, vec![
+        AccountRestrictionViolation::OperationType { address: alice, restriction_flags: operation_flags, entity_type: EntityTypeDto::TRANSFER_TRANSACTION },
+        AccountRestrictionViolation::AddressInteraction { address: alice, restriction_flags: outgoing_flags, counterparty_address: bob },
+        AccountRestrictionViolation::MosaicTransfer { address: bob, restriction_flags: mosaic_flags, mosaic_id: MosaicIdDto(TOKEN_MOSAIC_ID) },
+    ]);
+    assert_eq!(violations.iter().map(|violation| violation.get_failure()).collect::<Vec<_>>(), vec![
+        ValidationFailure::Failure_RestrictionAccount_Operation_Type_Prohibited,
+        ValidationFailure::Failure_RestrictionAccount_Address_Interaction_Prohibited,
+        ValidationFailure::Failure_RestrictionAccount_Mosaic_Transfer_Prohibited,
+    ]);
+    assert_eq!(violations[2].to_string(), format!("restriction [MOSAIC_ID, BLOCK] of account {} rejects mosaic 091F837E059AE13C", BOB_ADDRESS));
+
+    // aliased recipients are left to the caller
+    assert_eq!(evaluator.evaluate(&create_transfer(alias_address(NAMESPACE_ID), &[(TOKEN_MOSAIC_ID, 1)])).len(), 1);
+}
@@ -0,0 +1,116 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use catbuffer_rust::{
+    address_resolution_statement_builder::AddressResolutionStatementBuilder,
+    aggregate_complete_transaction_builder::AggregateCompleteTransactionBuilder,
+    aggregate_transaction_body_builder::AggregateTransactionBodyBuilder,
+    alias_resolver::AliasResolver,
+    entity_type_dto::EntityTypeDto,
+    hash256_dto::Hash256Dto,
+    mosaic_id_dto::MosaicIdDto,
+    mosaic_resolution_statement_builder::MosaicResolutionStatementBuilder,
+    unresolved_address_dto::UnresolvedAddressDto,
+    unresolved_mosaic_id_dto::UnresolvedMosaicIdDto,
+};
+
+use common::{
+    alias_address, create_embedded_transfer, create_transaction, create_transfer, to_address,
+    ALICE_PUBLIC_KEY, BOB_ADDRESS, CAROL_ADDRESS, CURRENCY_MOSAIC_ID, NAMESPACE_ID, TOKEN_MOSAIC_ID,
+};
+
+const UNKNOWN_NAMESPACE_ID: u64 = 0xC0FF_EE00_0000_0002;
+
+fn create_aggregate(recipient_addresses: &[UnresolvedAddressDto]) -> Vec<u8> {
+    let mut aggregate = AggregateCompleteTransactionBuilder {
+        super_object: create_transaction(ALICE_PUBLIC_KEY, EntityTypeDto::AGGREGATE_COMPLETE_TRANSACTION),
+        body: AggregateTransactionBodyBuilder { transactions_hash: Hash256Dto([0; 32]), transactions: vec![], cosignatures: vec![] },
+    };
+    for recipient_address in recipient_addresses {
+        let transaction = create_embedded_transfer(ALICE_PUBLIC_KEY, *recipient_address, &[]);
+        aggregate.push_transaction(Box::new(transaction)).unwrap();
+    }
+    aggregate.serializer()
+}
+
+fn source(primary_id: u32, secondary_id: u32) -> String {
+    hex::encode([primary_id.to_le_bytes(), secondary_id.to_le_bytes()].concat())
+}
+
+fn create_address_resolution_statement() -> AddressResolutionStatementBuilder {
+    // the alias links bob until the second inner transaction of the second transaction changes it to carol
+    let payload = format!("{}02000000{}{}{}{}", hex::encode(alias_address(NAMESPACE_ID).0), source(1, 0), BOB_ADDRESS, source(2, 2), CAROL_ADDRESS);
+    AddressResolutionStatementBuilder::from_binary(&hex::decode(payload).unwrap())
+}
+
+fn create_mosaic_resolution_statement() -> MosaicResolutionStatementBuilder {
+    let payload = format!("{}01000000{}{}", hex::encode(NAMESPACE_ID.to_le_bytes()), source(1, 0), hex::encode(TOKEN_MOSAIC_ID.to_le_bytes()));
+    MosaicResolutionStatementBuilder::from_binary(&hex::decode(payload).unwrap())
+}
+
+#[test]
+fn test_should_resolve_aliases_with_latest_preceding_entry() {
+    let address_resolution_statements = [create_address_resolution_statement()];
+    let mosaic_resolution_statements = [create_mosaic_resolution_statement()];
+    let resolver = AliasResolver { address_resolution_statements: &address_resolution_statements, mosaic_resolution_statements: &mosaic_resolution_statements };
+    let alias = alias_address(NAMESPACE_ID);
+
+    assert_eq!(resolver.resolve_address(&alias, 1, 0), Some(to_address(BOB_ADDRESS)));
+    assert_eq!(resolver.resolve_address(&alias, 2, 1), Some(to_address(BOB_ADDRESS)));
+    assert_eq!(resolver.resolve_address(&alias, 2, 2), Some(to_address(CAROL_ADDRESS)));
+    assert_eq!(resolver.resolve_address(&alias, 3, 0), Some(to_address(CAROL_ADDRESS)));
+    assert_eq!(resolver.resolve_address(&alias_address(UNKNOWN_NAMESPACE_ID), 3, 0), None);
+    assert_eq!(resolver.resolve_address(&UnresolvedAddressDto(to_address(BOB_ADDRESS).0), 1, 0), Some(to_address(BOB_ADDRESS)));
+
+    assert_eq!(resolver.resolve_mosaic_id(&UnresolvedMosaicIdDto(NAMESPACE_ID), 1, 0), Some(MosaicIdDto(TOKEN_MOSAIC_ID)));
+    assert_eq!(resolver.resolve_mosaic_id(&UnresolvedMosaicIdDto(CURRENCY_MOSAIC_ID), 1, 0), Some(MosaicIdDto(CURRENCY_MOSAIC_ID)));
+}
+
+#[test]
+fn test_should_resolve_transactions_and_inner_transactions() {
+    let address_resolution_statements = [create_address_resolution_statement()];
+    let mosaic_resolution_statements = [create_mosaic_resolution_statement()];
+    let resolver = AliasResolver { address_resolution_statements: &address_resolution_statements, mosaic_resolution_statements: &mosaic_resolution_statements };
+    let alias = alias_address(NAMESPACE_ID);
+    let unknown_alias = alias_address(UNKNOWN_NAMESPACE_ID);
+    let payloads = vec![
+        create_transfer(alias, &[(NAMESPACE_ID, 1), (CURRENCY_MOSAIC_ID, 1)]),
+        create_aggregate(&[alias, alias]),
+        create_transfer(unknown_alias, &[]),
+    ];
+
+    let resolved_transactions = resolver.resolve_transactions(&payloads);
+    let sources: Vec<(u32, u32)> = resolved_transactions.iter().map(|transaction| (transaction.primary_id, transaction.secondary_id)).collect();
+    assert_eq!(sources, vec![(1, 0), (2, 0), (2, 1), (2, 2), (3, 0)]);
+
+    assert_eq!(resolved_transactions[0].addresses, vec![(alias, Some(to_address(BOB_ADDRESS)))]);
+    assert_eq!(resolved_transactions[0].mosaic_ids, vec![
+        (UnresolvedMosaicIdDto(NAMESPACE_ID), Some(MosaicIdDto(TOKEN_MOSAIC_ID))),
+        (UnresolvedMosaicIdDto(CURRENCY_MOSAIC_ID), Some(MosaicIdDto(CURRENCY_MOSAIC_ID))),
+    ]);
+    assert_eq!(resolved_transactions[1].entity_type, EntityTypeDto::AGGREGATE_COMPLETE_TRANSACTION);
+    assert!(resolved_transactions[1].addresses.is_empty());
+    assert_eq!(resolved_transactions[2].addresses, vec![(alias, Some(to_address(BOB_ADDRESS)))]);
+    assert_eq!(resolved_transactions[3].addresses, vec![(alias, Some(to_address(CAROL_ADDRESS)))]);
+    assert_eq!(resolved_transactions[4].addresses, vec![(unknown_alias, None)]);
+}
@@ -0,0 +1,102 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use catbuffer_rust::{
+    amount_dto::AmountDto,
+    atomic_swap::{AtomicSwap, AtomicSwapError, AtomicSwapLeg},
+    block_duration_dto::BlockDurationDto,
+    key_dto::KeyDto,
+    lock_hash_algorithm_dto::LockHashAlgorithmDto,
+    network_type_dto::NetworkTypeDto,
+    secret_lock_transaction_builder::SecretLockTransactionBuilder,
+    secret_proof_transaction_builder::SecretProofTransactionBuilder,
+    timestamp_dto::TimestampDto,
+    unresolved_address_dto::UnresolvedAddressDto,
+    unresolved_mosaic_id_dto::UnresolvedMosaicIdDto,
+};
+
+const ALICE_PUBLIC_KEY: &str = "2E834140FD66CF87B254A693A2C7862C819217B676D3943267156625E816EC6F";
+const BOB_PUBLIC_KEY: &str = "4875FD2E32875D1BC6567745F1509F0F890A1BF8EE59FA74452FA4183A270E03";
+const ALICE_ADDRESS: &str = "9022D04812D05000F96C283657B0C17990932BC84926CDE6";
+const BOB_ADDRESS: &str = "809D7AE580CA673E2365F9019C19A68FFE3F59F38910CD46";
+
+fn create_leg(network: NetworkTypeDto, sender: &str, recipient: &str, duration: u64) -> AtomicSwapLeg {
+    AtomicSwapLeg {
+        network,
+        sender_public_key: KeyDto::from_binary(&hex::decode(sender).unwrap()),
+        recipient_address: UnresolvedAddressDto::from_binary(&hex::decode(recipient).unwrap()),
+        mosaic_id: UnresolvedMosaicIdDto(0x091F_837E_059A_E13C),
+        amount: AmountDto(1_000_000),
+        duration: BlockDurationDto(duration),
+    }
+}
+
+#[test]
+fn test_should_create_matching_swap_transactions() {
+    let proof = LockHashAlgorithmDto::generate_proof();
+    let initiator = create_leg(NetworkTypeDto::PUBLIC_TEST, ALICE_PUBLIC_KEY, BOB_ADDRESS, 480);
+    let participant = create_leg(NetworkTypeDto::PRIVATE_TEST, BOB_PUBLIC_KEY, ALICE_ADDRESS, 240);
+    let swap = AtomicSwap::from_proof(LockHashAlgorithmDto::SHA3_256, &proof, initiator, participant).unwrap();
+
+    let initiator_lock = swap.create_initiator_lock(AmountDto(100), TimestampDto(1));
+    let participant_lock = swap.create_participant_lock(AmountDto(100), TimestampDto(1));
+    assert_eq!(initiator_lock.get_secret(), participant_lock.get_secret());
+    assert_eq!(initiator_lock.super_object.network, NetworkTypeDto::PUBLIC_TEST);
+    assert_eq!(participant_lock.super_object.network, NetworkTypeDto::PRIVATE_TEST);
+    assert_eq!(initiator_lock.get_duration().get_block_duration(), 480);
+    assert_eq!(initiator_lock.get_mosaic().get_amount().get_amount(), 1_000_000);
+
+    let bob = KeyDto::from_binary(&hex::decode(BOB_PUBLIC_KEY).unwrap());
+    let alice = KeyDto::from_binary(&hex::decode(ALICE_PUBLIC_KEY).unwrap());
+    let initiator_proof = swap.create_initiator_proof(alice, &proof, AmountDto(100), TimestampDto(2)).unwrap();
+    let participant_proof = swap.create_participant_proof(bob, &proof, AmountDto(100), TimestampDto(3)).unwrap();
+
+    // round trip through the wire format
+    let initiator_lock = SecretLockTransactionBuilder::from_binary(&initiator_lock.serializer());
+    let participant_lock = SecretLockTransactionBuilder::from_binary(&participant_lock.serializer());
+    let initiator_proof = SecretProofTransactionBuilder::from_binary(&initiator_proof.serializer());
+    let participant_proof = SecretProofTransactionBuilder::from_binary(&participant_proof.serializer());
+
+    assert!(initiator_proof.matches(&participant_lock));
+    assert!(participant_proof.matches(&initiator_lock));
+    assert!(!initiator_proof.matches(&initiator_lock));
+    assert_eq!(initiator_proof.super_object.network, NetworkTypeDto::PRIVATE_TEST);
+    assert_eq!(participant_proof.super_object.network, NetworkTypeDto::PUBLIC_TEST);
+}
+
+#[test]
+fn test_should_reject_unsafe_durations() {
+    for (initiator_duration, participant_duration) in [(480, 241), (480, 480), (240, 480), (480, 0)].iter() {
+        let initiator = create_leg(NetworkTypeDto::PUBLIC_TEST, ALICE_PUBLIC_KEY, BOB_ADDRESS, *initiator_duration);
+        let participant = create_leg(NetworkTypeDto::PRIVATE_TEST, BOB_PUBLIC_KEY, ALICE_ADDRESS, *participant_duration);
+        let result = AtomicSwap::from_proof(LockHashAlgorithmDto::HASH_256, b"proof", initiator, participant);
+        assert_eq!(result.unwrap_err(), AtomicSwapError::UnsafeDurations { initiator: *initiator_duration, participant: *participant_duration });
+    }
+}
+
+#[test]
+fn test_should_reject_invalid_proof() {
+    let initiator = create_leg(NetworkTypeDto::PUBLIC_TEST, ALICE_PUBLIC_KEY, BOB_ADDRESS, 480);
+    let participant = create_leg(NetworkTypeDto::PRIVATE_TEST, BOB_PUBLIC_KEY, ALICE_ADDRESS, 240);
+    let swap = AtomicSwap::from_proof(LockHashAlgorithmDto::HASH_160, b"proof", initiator.clone(), participant).unwrap();
+    let result = swap.create_participant_proof(initiator.sender_public_key, b"guess", AmountDto(100), TimestampDto(1));
+    assert_eq!(result.unwrap_err(), AtomicSwapError::InvalidProof);
+}
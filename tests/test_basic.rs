@@ -20,8 +20,12 @@
  */
 
 use catbuffer_rust::{
-    amount_dto::AmountDto, hash256_dto::Hash256Dto, key_dto::KeyDto, timestamp_dto::TimestampDto,
-    unresolved_address_dto::UnresolvedAddressDto,
+    address_dto::AddressDto, amount_dto::AmountDto, facility_code_dto::FacilityCodeDto, hash256_dto::Hash256Dto,
+    key_dto::KeyDto, lock_hash_algorithm_dto::LockHashAlgorithmDto, mosaic_id_dto::MosaicIdDto,
+    namespace_id_dto::NamespaceIdDto, network_type_dto::NetworkTypeDto, result_severity_dto::ResultSeverityDto,
+    scoped_metadata_key_dto::ScopedMetadataKeyDto, timestamp_dto::TimestampDto,
+    unresolved_address_dto::UnresolvedAddressDto, unresolved_mosaic_id_dto::UnresolvedMosaicIdDto,
+    validation_failure::ValidationFailure, validation_result_dto::ValidationResultDto,
 };
 
 #[test]
@@ -63,3 +67,104 @@ fn test_should_create_unresolved_address_dto() {
     let build_object = UnresolvedAddressDto::from_binary(&*bytes_payload);
     assert_eq!(build_object.serializer(), bytes_payload);
 }
+
+#[test]
+fn test_should_distinguish_alias_and_address_in_unresolved_address_dto() {
+    let address = AddressDto::from_binary(&hex::decode("90F36CA680C35D630662A0C38DC89D4978D10B511B3D241A").unwrap());
+    let unresolved_address = UnresolvedAddressDto::from(address);
+    assert!(!unresolved_address.is_alias());
+    assert_eq!(unresolved_address.as_address(), Some(address));
+    assert_eq!(unresolved_address.as_namespace_id(), None);
+
+    let namespace_id = NamespaceIdDto::from_name("symbol", None);
+    let alias = UnresolvedAddressDto::from_namespace_id(NetworkTypeDto::PUBLIC, &namespace_id);
+    assert_eq!(hex::encode_upper(alias.0), format!("69{}{}", hex::encode_upper(namespace_id.0.to_le_bytes()), "00".repeat(15)));
+    assert!(alias.is_alias());
+    assert_eq!(alias.as_address(), None);
+    assert_eq!(alias.as_namespace_id(), Some(namespace_id));
+}
+
+#[test]
+fn test_should_distinguish_alias_and_mosaic_id_in_unresolved_mosaic_id_dto() {
+    let mosaic_id = UnresolvedMosaicIdDto::from(MosaicIdDto(0x6BED_913F_A202_23F8));
+    assert!(!mosaic_id.is_alias());
+    assert_eq!(mosaic_id.as_mosaic_id(), Some(MosaicIdDto(0x6BED_913F_A202_23F8)));
+    assert_eq!(mosaic_id.as_namespace_id(), None);
+
+    let namespace_id = NamespaceIdDto::from_name("symbol", None);
+    let alias = UnresolvedMosaicIdDto::from(namespace_id);
+    assert!(alias.is_alias());
+    assert_eq!(alias.as_mosaic_id(), None);
+    assert_eq!(alias.as_namespace_id(), Some(namespace_id));
+}
+
+#[test]
+fn test_should_create_scoped_metadata_key_from_string() {
+    // KeyGenerator.generateUInt64Key of the SDKs: first 8 bytes of SHA3-256, little-endian, with the high bit set
+    assert_eq!(ScopedMetadataKeyDto::from_str_key("abc"), Some(ScopedMetadataKeyDto(0xB225_E24F_A75D_983A)));
+    assert_eq!(ScopedMetadataKeyDto::from_str_key("CERT"), Some(ScopedMetadataKeyDto(0xF5CC_2D09_ED04_8388)));
+    assert_eq!(ScopedMetadataKeyDto::from_str_key("kyc_level"), Some(ScopedMetadataKeyDto(0xB592_FEF4_C6C0_B9DC)));
+    assert_eq!(ScopedMetadataKeyDto::from_str_key("a"), Some(ScopedMetadataKeyDto(0xF524_A0FB_F24B_0880)));
+    assert_eq!(ScopedMetadataKeyDto::from_str_key(""), None);
+}
+
+#[test]
+fn test_should_hash_proof_with_lock_hash_algorithm() {
+    const VECTORS: [(LockHashAlgorithmDto, &str, &str); 4] = [
+        (LockHashAlgorithmDto::SHA3_256, "B778A39A3663719DFC5E48C9D78431B1E45C2AF9DF538782BF199C189DABEAC7", "9B3155B37159DA50AA52D5967C509B410F5A36A3B1E31ECB5AC76675D79B4A5E"),
+        (LockHashAlgorithmDto::HASH_160, "9A493664", "DD3BCB27905B39FAD60D26D66F44FD837F86E0EB000000000000000000000000"),
+        (LockHashAlgorithmDto::HASH_256, "9A493664", "B3B927960A9E7D6D04137491E8FAA0EA8815958E919C882D1D5AEDC473BCE3E9"),
+        (LockHashAlgorithmDto::HASH_256, "", "5DF6E0E2761359D30A8275058E299FCC0381534545F55CF43E41983F5D4C9456"),
+    ];
+    for (hash_algorithm, proof, secret) in VECTORS.iter() {
+        let proof = hex::decode(proof).unwrap();
+        let secret = Hash256Dto::from_binary(&hex::decode(secret).unwrap());
+        assert_eq!(hash_algorithm.hash(&proof), secret);
+        assert!(hash_algorithm.verify(&secret, &proof));
+        assert!(!hash_algorithm.verify(&secret, &[proof, vec![0]].concat()));
+    }
+}
+
+#[test]
+fn test_should_generate_random_proof() {
+    let proof = LockHashAlgorithmDto::generate_proof();
+    assert_eq!(proof.len(), LockHashAlgorithmDto::PROOF_SIZE);
+    assert_ne!(proof, LockHashAlgorithmDto::generate_proof());
+    let secret = LockHashAlgorithmDto::HASH_160.hash(&proof);
+    assert!(LockHashAlgorithmDto::HASH_160.verify(&secret, &proof));
+}
+
+#[test]
+fn test_should_decode_validation_result() {
+    let build_object = ValidationResultDto::from_value(0x80430003).unwrap();
+    assert_eq!(build_object, ValidationResultDto::Failure_Core_Insufficient_Balance);
+    assert_eq!(build_object.get_severity(), ResultSeverityDto::Failure);
+    assert_eq!(build_object.get_facility(), FacilityCodeDto::Core);
+    assert_eq!(build_object.get_code(), 3);
+    assert!(build_object.is_failure());
+    assert_eq!(build_object.get_name(), "Failure_Core_Insufficient_Balance");
+    assert_eq!(ValidationResultDto::from_binary(&build_object.serializer()), build_object);
+
+    let build_object = ValidationResultDto::from_value(0x804D0069).unwrap();
+    assert_eq!(build_object.get_name(), "Failure_Mosaic_Invalid_Supply_Change_Amount");
+    assert_eq!(build_object.get_facility(), FacilityCodeDto::Mosaic);
+    assert!(!ValidationResultDto::Success.is_failure());
+    assert_eq!(ValidationResultDto::Neutral.get_severity(), ResultSeverityDto::Neutral);
+    assert_eq!(ValidationResultDto::from_value(0x80430000 | 0xFFFF), None);
+}
+
+#[test]
+fn test_should_map_validation_failure_to_result() {
+    assert_eq!(ValidationFailure::Failure_Transfer_Message_Too_Large.get_result(), Some(ValidationResultDto::Failure_Transfer_Message_Too_Large));
+    assert_eq!(ValidationFailure::Failure_Transfer_Message_Too_Large.get_result().unwrap().get_value(), 0x80540001);
+    assert_eq!(ValidationFailure::Failure_Aggregate_Nested_Aggregate.get_result(), None);
+
+    let build_object = ValidationFailure::Failure_Signature_Not_Verifiable.get_result().unwrap();
+    assert_eq!(build_object.get_value(), 0x80530001);
+    assert_eq!(build_object.get_facility(), FacilityCodeDto::Signature);
+    let build_object = ValidationFailure::Failure_Chain_Unlinked.get_result().unwrap();
+    assert_eq!(build_object.get_value(), 0x80FF0001);
+    assert_eq!(build_object.get_facility(), FacilityCodeDto::Chain);
+    assert_eq!(ValidationFailure::Failure_Chain_Timestamp_Decreasing.get_result(), None);
+    assert_eq!(ValidationFailure::Failure_Chain_Importance_Block_Unlinked.get_result(), None);
+}
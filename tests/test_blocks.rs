@@ -0,0 +1,328 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use sha3::{Digest, Sha3_256};
+
+use catbuffer_rust::{
+    block::{calculate_difficulty, load_block_from_binary, verify_block_header_chain, BlockFactory, BlockHeader, BlockHeaderChainFailure},
+    block_fee_multiplier_dto::BlockFeeMultiplierDto,
+    block_header_builder::BlockHeaderBuilder,
+    difficulty_dto::DifficultyDto,
+    hash256_dto::Hash256Dto,
+    height_dto::HeightDto,
+    key_pair::KeyPair,
+    merkle_hash::calculate_merkle_hash,
+    network_type_dto::NetworkTypeDto,
+    timestamp_dto::TimestampDto,
+    transaction_builder::TransactionBuilder,
+    transaction_helper::{load_transaction_from_binary, RawTransaction},
+    unresolved_address_dto::UnresolvedAddressDto,
+    validation_config::ValidationConfig,
+    validation_failure::ValidationFailure,
+};
+
+use common::{create_block_header, create_message_transfer, to_address, BOB_ADDRESS, CURRENCY_MOSAIC_ID};
+
+const GENERATION_HASH_SEED: &str = "3B5E1FA6445653C971A50687E75E6D09FB30481055E3990C84B25E9222DC1155";
+
+fn create_importance_block_footer() -> Vec<u8> {
+    [hex::decode("0300000005000000000000000040E59C30120000").unwrap(), vec![0x55; 32]].concat()
+}
+
+fn create_transfer(message: &[u8]) -> Vec<u8> {
+    create_message_transfer(UnresolvedAddressDto(to_address(BOB_ADDRESS).0), &[(CURRENCY_MOSAIC_ID, 1)], message)
+}
+
+fn pad(mut payload: Vec<u8>) -> Vec<u8> {
+    payload.resize(payload.len() + (8 - payload.len() % 8) % 8, 0);
+    payload
+}
+
+#[test]
+fn test_should_round_trip_normal_block_with_padded_transactions() {
+    let transactions = [create_transfer(b"abc"), create_transfer(b"12345678")];
+    let transactions_payload: Vec<u8> = transactions.iter().cloned().flat_map(pad).collect();
+    let size = 376 + transactions_payload.len();
+    let payload = [create_block_header("4381", size), vec![0; 4], transactions_payload].concat();
+    assert_eq!(payload.len(), size);
+
+    let block = load_block_from_binary(&payload);
+    assert!(matches!(block.header, BlockHeader::Normal(_)));
+    assert_eq!(block.header.get_block_header().get_height().0, 10);
+    assert_eq!(block.transactions.len(), 2);
+    assert_eq!(block.transactions[0].serializer(), transactions[0]);
+    assert_eq!(block.transactions[1].serializer(), transactions[1]);
+    assert_eq!(block.get_size(), size);
+    assert_eq!(block.serializer(), payload);
+}
+
+#[test]
+fn test_should_round_trip_importance_and_nemesis_blocks() {
+    for (block_type, is_nemesis) in [("4382", false), ("4380", true)].iter() {
+        let transaction = pad(create_transfer(b"abc"));
+        let size = 372 + 52 + transaction.len();
+        let payload = [create_block_header(block_type, size), create_importance_block_footer(), transaction].concat();
+
+        let block = load_block_from_binary(&payload);
+        match &block.header {
+            BlockHeader::Importance(header) if !is_nemesis => assert_eq!(header.get_voting_eligible_accounts_count(), 3),
+            BlockHeader::Nemesis(header) if *is_nemesis => assert_eq!(header.get_harvesting_eligible_accounts_count(), 5),
+            header => panic!("unexpected header {:?}", header),
+        }
+        assert_eq!(block.transactions.len(), 1);
+        assert_eq!(block.serializer(), payload);
+    }
+}
+
+#[test]
+fn test_should_keep_unknown_transactions_raw() {
+    let mut newer_version = create_transfer(b"abc");
+    newer_version[108] = 2;
+    let mut unknown_type = create_transfer(b"12345678");
+    unknown_type[110..112].copy_from_slice(&[0xFF, 0x4F]);
+    let mut unknown_network = create_transfer(b"");
+    unknown_network[109] = 0x01;
+    let transactions = [newer_version, unknown_type, unknown_network];
+    let transactions_payload: Vec<u8> = transactions.iter().cloned().flat_map(pad).collect();
+    let size = 376 + transactions_payload.len();
+    let payload = [create_block_header("4381", size), vec![0; 4], transactions_payload].concat();
+
+    let block = load_block_from_binary(&payload);
+    assert_eq!(block.transactions.len(), 3);
+    for (transaction, expected) in block.transactions.iter().zip(transactions.iter()) {
+        assert_eq!(transaction.serializer(), *expected);
+        assert!(transaction.validate(&ValidationConfig::default()).is_empty());
+    }
+    assert_eq!(RawTransaction::from_binary(&transactions[0]).get_version(), Some(2));
+    assert_eq!(RawTransaction::from_binary(&transactions[1]).get_type_value(), Some(0x4FFF));
+    assert_eq!(block.serializer(), payload);
+
+    let raw_transaction = RawTransaction::from_binary(&[0x40, 0, 0, 0, 1, 2]);
+    assert_eq!(raw_transaction.payload, vec![0x40, 0, 0, 0, 1, 2]);
+    assert_eq!(raw_transaction.get_type_value(), None);
+}
+
+#[test]
+fn test_should_ignore_bytes_after_block() {
+    let payload = [create_block_header("4381", 376), vec![0; 4], vec![0xFF; 16]].concat();
+    let block = load_block_from_binary(&payload);
+    assert!(block.transactions.is_empty());
+    assert_eq!(block.serializer(), payload[..376].to_vec());
+}
+
+fn create_block_factory() -> BlockFactory {
+    BlockFactory {
+        network: NetworkTypeDto::PUBLIC_TEST,
+        generation_hash_seed: Hash256Dto::from_binary(&hex::decode(GENERATION_HASH_SEED).unwrap()),
+        importance_grouping: 12,
+    }
+}
+
+#[test]
+fn test_should_create_block_following_previous_block() {
+    let previous_block = load_block_from_binary(&[create_block_header("4381", 376), vec![0; 4]].concat());
+    let transactions = [create_transfer(b"abc"), create_transfer(b"12345678")];
+    let factory = create_block_factory();
+    let mut block = factory.create_block(
+        &previous_block.header,
+        TimestampDto(2000),
+        transactions.iter().map(|transaction| load_transaction_from_binary(transaction)).collect(),
+        to_address(BOB_ADDRESS),
+        BlockFeeMultiplierDto(10),
+    );
+
+    let header = block.header.get_block_header();
+    assert!(matches!(block.header, BlockHeader::Normal(_)));
+    assert_eq!(header.height.0, 11);
+    assert_eq!(header.timestamp.0, 2000);
+    assert_eq!(header.difficulty.0, 1000);
+    assert_eq!(header.previous_block_hash, previous_block.calculate_hash());
+    let transaction_hashes: Vec<Hash256Dto> = transactions.iter()
+        .map(|transaction| TransactionBuilder::calculate_transaction_hash(transaction, &factory.generation_hash_seed))
+        .collect();
+    assert_eq!(header.transactions_hash, calculate_merkle_hash(&transaction_hashes));
+
+    let key_pair = KeyPair::from_private_key([0x99; 32]);
+    block.sign(&key_pair);
+    assert_eq!(block.header.get_block_header().signer_public_key, key_pair.get_public_key());
+    assert!(block.header.verify_signature());
+
+    let loaded_block = load_block_from_binary(&block.serializer());
+    assert_eq!(loaded_block.serializer(), block.serializer());
+    assert_eq!(loaded_block.calculate_hash(), block.calculate_hash());
+    assert!(loaded_block.header.verify_signature());
+}
+
+#[test]
+fn test_should_create_importance_block_at_grouping_boundary() {
+    let previous_block = load_block_from_binary(&[create_block_header("4381", 376), vec![0; 4]].concat());
+    let mut factory = create_block_factory();
+    factory.importance_grouping = 11;
+    let mut block = factory.create_block(
+        &previous_block.header,
+        TimestampDto(2000),
+        vec![],
+        to_address(BOB_ADDRESS),
+        BlockFeeMultiplierDto(10),
+    );
+    assert!(matches!(block.header, BlockHeader::Importance(_)));
+    assert_eq!(block.header.get_block_header().transactions_hash, Hash256Dto([0; 32]));
+    assert_eq!(block.get_size(), 424);
+
+    block.sign(&KeyPair::from_private_key([0x99; 32]));
+    let hash = block.calculate_hash();
+    if let BlockHeader::Importance(header) = &mut block.header {
+        header.importance_block_footer.voting_eligible_accounts_count = 1;
+    }
+    assert!(!block.header.verify_signature());
+    assert_ne!(block.calculate_hash(), hash);
+}
+
+#[test]
+fn test_should_calculate_block_hash_from_signature_signer_and_verifiable_data() {
+    let payload = [create_block_header("4381", 376), vec![0; 4]].concat();
+    let block = load_block_from_binary(&payload);
+    assert_eq!(block.header.get_verifiable_data(), payload[72..].to_vec());
+
+    let mut hasher = Sha3_256::new();
+    hasher.update(&payload[8..40]);
+    hasher.update(&payload[72..104]);
+    hasher.update(&payload[72..]);
+    assert_eq!(block.calculate_hash(), Hash256Dto::from_binary(&hasher.finalize()));
+}
+
+fn create_header_chain(timestamps: &[u64]) -> Vec<BlockHeader> {
+    let key_pair = KeyPair::from_private_key([0x99; 32]);
+    let mut factory = create_block_factory();
+    factory.importance_grouping = 2;
+    let mut first_header = BlockHeader::from_binary(&[create_block_header("4381", 376), vec![0; 4]].concat());
+    first_header.sign(&key_pair);
+    let mut headers = vec![first_header];
+    let mut previous_importance_block_hash = None;
+    for timestamp in timestamps.iter() {
+        let beneficiary_address = to_address(BOB_ADDRESS);
+        let mut block = factory.create_block(headers.last().unwrap(), TimestampDto(*timestamp), vec![], beneficiary_address, BlockFeeMultiplierDto(10));
+        if let BlockHeader::Importance(header) = &mut block.header {
+            header.importance_block_footer.previous_importance_block_hash = previous_importance_block_hash.unwrap_or(Hash256Dto([0; 32]));
+        }
+        block.sign(&key_pair);
+        if let BlockHeader::Importance(_) = block.header {
+            previous_importance_block_hash = Some(block.calculate_hash());
+        }
+        headers.push(block.header);
+    }
+    headers
+}
+
+fn assert_chain_failure(headers: &[BlockHeader], height: u64, failure: ValidationFailure) {
+    let result = verify_block_header_chain(NetworkTypeDto::PUBLIC_TEST, headers);
+    assert_eq!(result, Err(BlockHeaderChainFailure { height: HeightDto(height), failure }));
+}
+
+#[test]
+fn test_should_verify_header_chain() {
+    let headers = create_header_chain(&[2000, 2000, 3000, 4000]);
+    assert!(matches!(headers[4], BlockHeader::Importance(_)));
+    let hashes = verify_block_header_chain(NetworkTypeDto::PUBLIC_TEST, &headers).unwrap();
+    assert_eq!(hashes, headers.iter().map(|header| header.calculate_hash()).collect::<Vec<Hash256Dto>>());
+    assert_eq!(hashes[1], headers[2].get_block_header().previous_block_hash);
+    assert_eq!(verify_block_header_chain(NetworkTypeDto::PUBLIC_TEST, &[]), Ok(vec![]));
+
+    let result = verify_block_header_chain(NetworkTypeDto::PUBLIC, &headers).unwrap_err();
+    assert_eq!(result.failure, ValidationFailure::Failure_Core_Wrong_Network);
+    assert_eq!(result.to_string(), "block at height 10 fails Failure_Core_Wrong_Network");
+}
+
+#[test]
+fn test_should_reject_broken_header_chain() {
+    let mut headers = create_header_chain(&[2000, 3000, 4000]);
+    assert_chain_failure(&[headers[0].clone(), headers[2].clone()], 12, ValidationFailure::Failure_Chain_Unlinked);
+    assert_chain_failure(&[headers[1].clone(), headers[1].clone()], 11, ValidationFailure::Failure_Chain_Unlinked);
+
+    if let BlockHeader::Normal(header) = &mut headers[3] {
+        header.super_object.fee_multiplier = BlockFeeMultiplierDto(11);
+    }
+    assert_chain_failure(&headers, 13, ValidationFailure::Failure_Signature_Not_Verifiable);
+
+    let headers = create_header_chain(&[2000, 1999]);
+    assert_chain_failure(&headers, 12, ValidationFailure::Failure_Chain_Timestamp_Decreasing);
+}
+
+#[test]
+fn test_should_reject_unlinked_importance_block() {
+    let key_pair = KeyPair::from_private_key([0x99; 32]);
+    let mut headers = create_header_chain(&[2000, 3000, 4000, 5000]);
+    if let BlockHeader::Importance(header) = &mut headers[4] {
+        header.importance_block_footer.previous_importance_block_hash = Hash256Dto([0; 32]);
+    }
+    headers[4].sign(&key_pair);
+    assert_chain_failure(&headers, 14, ValidationFailure::Failure_Chain_Importance_Block_Unlinked);
+
+    // without the previous importance block in the sequence the link cannot be checked
+    assert!(verify_block_header_chain(NetworkTypeDto::PUBLIC_TEST, &headers[3..]).is_ok());
+}
+
+fn create_difficulty_window(difficulties: &[u64], block_time: u64) -> Vec<BlockHeaderBuilder> {
+    let template = BlockHeader::from_binary(&[create_block_header("4381", 376), vec![0; 4]].concat()).get_block_header().clone();
+    difficulties.iter().enumerate().map(|(index, difficulty)| {
+        let mut header = template.clone();
+        header.height = HeightDto(index as u64 + 1);
+        header.timestamp = TimestampDto(1_000_000 + index as u64 * block_time);
+        header.difficulty = DifficultyDto(*difficulty);
+        header
+    }).collect()
+}
+
+#[test]
+fn test_should_calculate_difficulty_from_window() {
+    let config = ValidationConfig::default();
+    let difficulty = 100_000_000_000_000;
+
+    // blocks on target keep the difficulty, slower blocks lower it proportionally
+    assert_eq!(calculate_difficulty(&create_difficulty_window(&[difficulty; 60], 30_000), &config), DifficultyDto(difficulty));
+    assert_eq!(calculate_difficulty(&create_difficulty_window(&[difficulty; 60], 31_000), &config), DifficultyDto(96_774_193_548_387));
+
+    // only the last blocks of the window are averaged
+    let mut difficulties = vec![difficulty; 61];
+    difficulties[0] = DifficultyDto::MAX.0;
+    assert_eq!(calculate_difficulty(&create_difficulty_window(&difficulties, 30_000), &config), DifficultyDto(difficulty));
+
+    assert_eq!(calculate_difficulty(&create_difficulty_window(&[difficulty], 30_000), &config), DifficultyDto::DEFAULT);
+    assert_eq!(calculate_difficulty(&[], &config), DifficultyDto::DEFAULT);
+}
+
+#[test]
+fn test_should_clamp_difficulty_change() {
+    let config = ValidationConfig::default();
+    let difficulty = 100_000_000_000_000;
+    assert_eq!(calculate_difficulty(&create_difficulty_window(&[difficulty; 60], 15_000), &config), DifficultyDto(105_000_000_000_000));
+    assert_eq!(calculate_difficulty(&create_difficulty_window(&[difficulty; 60], 60_000), &config), DifficultyDto(95_000_000_000_000));
+    assert_eq!(calculate_difficulty(&create_difficulty_window(&[difficulty; 60], 0), &config), DifficultyDto(105_000_000_000_000));
+
+    // the change is relative to the last difficulty, and the result stays within the node bounds
+    let mut difficulties = vec![difficulty; 60];
+    difficulties[59] = 200_000_000_000_000;
+    assert_eq!(calculate_difficulty(&create_difficulty_window(&difficulties, 30_000), &config), DifficultyDto(190_000_000_000_000));
+    assert_eq!(calculate_difficulty(&create_difficulty_window(&[DifficultyDto::MAX.0; 60], 15_000), &config), DifficultyDto::MAX);
+    assert_eq!(calculate_difficulty(&create_difficulty_window(&[DifficultyDto::MIN.0; 60], 60_000), &config), DifficultyDto::MIN);
+}
@@ -0,0 +1,259 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use catbuffer_rust::{
+    hash256_dto::Hash256Dto, key_dto::KeyDto, key_pair::{verify_signature, KeyPair},
+};
+#[cfg(feature = "crypto")]
+use catbuffer_rust::{
+    message_cipher::{decrypt_aes_gcm, decrypt_message, derive_shared_key, encrypt_aes_gcm_with_iv, MessageCipherError, AES_IV_SIZE},
+    persistent_harvesting_delegation::PersistentHarvestingDelegation,
+    transfer_message::TransferMessage,
+    vrf::{calculate_generation_hash, generate_vrf_proof, verify_vrf_proof},
+    vrf_proof_builder::VrfProofBuilder,
+};
+
+const PRIVATE_KEYS: [&str; 5] = [
+    "575DBB3062267EFF57C970A336EBBC8FBCFE12C5BD3ED7BC11EB0481D7704CED",
+    "5B0E3FA5D3B49A79022D7C1E121BA1CBBF4DB5821F47AB8C708EF88DEFC29BFE",
+    "738BA9BB9110AEA8F15CAA353ACA5653B4BDFCA1DB9F34D0EFED2CE1325AEEDA",
+    "E8BF9BC0F35C12D8C8BF94DD3A8B5B4034F1063948E3CC5304E55E31AA4B95A6",
+    "C325EA529674396DB5675939E7988883D59A5FC17A28CA977E3BA85370232A83",
+];
+
+const PUBLIC_KEYS: [&str; 5] = [
+    "2E834140FD66CF87B254A693A2C7862C819217B676D3943267156625E816EC6F",
+    "4875FD2E32875D1BC6567745F1509F0F890A1BF8EE59FA74452FA4183A270E03",
+    "9F780097FB6A1F287ED2736A597B8EA7F08D20F1ECDB9935DE6694ECF1C58900",
+    "0815926E003CDD5AF0113C0E067262307A42CD1E697F53B683F7E5F9F57D72C9",
+    "3683B3E45E76870CFE076E47C2B34CE8E3EAEC26C8AA7C1ED752E3E840AF8A27",
+];
+
+const SIGN_VECTORS: [(&str, &str, &str); 3] = [
+    ("ABF4CF55A2B3F742D7543D9CC17F50447B969E6E06F5EA9195D428AB12B7318D",
+     "8CE03CD60514233B86789729102EA09E867FC6D964DEA8C2018EF7D0A2E0E24BF7E348E917116690B9",
+     "31D272F0662915CAC43AB7D721CAF65D8601F52B2E793EA1533E7BC20E04EA97B74859D9209A7B18DFECFD2C4A42D6957628F5357E3FB8B87CF6A888BAB4280E"),
+    ("6AA6DAD25D3ACB3385D5643293133936CDDDD7F7E11818771DB1FF2F9D3F9215",
+     "E4A92208A6FC52282B620699191EE6FB9CF04DAF48B48FD542C5E43DAA9897763A199AAA4B6F10546109F47AC3564FADE0",
+     "F21E4BE0A914C0C023F724E1EAB9071A3743887BB8824CB170404475873A827B301464261E93700725E8D4427A3E39D365AFB2C9191F75D33C6BE55896E0CC00"),
+    ("8E32BC030A4C53DE782EC75BA7D5E25E64A2A072A56E5170B77A4924EF3C32A9",
+     "13ED795344C4448A3B256F23665336645A853C5C44DBFF6DB1B9224B5303B6447FBF8240A2249C55",
+     "939CD8932093571E24B21EA53F1359279BA5CFC32CE99BB020E676CF82B0AA1DD4BC76FCDE41EF784C06D122B3D018135352C057F079C926B3EFFA7E73CF1D06"),
+];
+
+fn key_pair_from_hex(private_key: &str) -> KeyPair {
+    let mut buf = [0u8; 32];
+    buf.copy_from_slice(&hex::decode(private_key).unwrap());
+    KeyPair::from_private_key(buf)
+}
+
+#[test]
+fn test_should_derive_public_key_from_private_key() {
+    for (private_key, public_key) in PRIVATE_KEYS.iter().zip(PUBLIC_KEYS.iter()) {
+        let key_pair = key_pair_from_hex(private_key);
+        assert_eq!(key_pair.get_public_key().serializer(), hex::decode(public_key).unwrap());
+    }
+}
+
+#[test]
+fn test_should_sign_and_verify_data() {
+    for (private_key, data, signature) in SIGN_VECTORS.iter() {
+        let key_pair = key_pair_from_hex(private_key);
+        let data = hex::decode(data).unwrap();
+        let build_object = key_pair.sign(&data);
+        assert_eq!(build_object.serializer(), hex::decode(signature).unwrap());
+        assert!(verify_signature(&key_pair.get_public_key(), &data, &build_object));
+        assert!(!verify_signature(&key_pair.get_public_key(), &data[1..], &build_object));
+        assert!(!verify_signature(&KeyDto([0; 32]), &data, &build_object));
+    }
+}
+
+#[test]
+fn test_should_not_verify_signature_of_other_key_pair() {
+    let data = Hash256Dto([0x12; 32]).serializer();
+    let signature = KeyPair::random().sign(&data);
+    assert!(!verify_signature(&KeyPair::random().get_public_key(), &data, &signature));
+}
+
+#[cfg(feature = "crypto")]
+#[test]
+fn test_should_decrypt_sdk_encrypted_message() {
+    let recipient = key_pair_from_hex("A22A4BBF126A2D7D7ECE823174DFD184C5DE0FDE4CB2075D30CFA409F7EF8908");
+    let sender_public_key = KeyDto::from_binary(&hex::decode("3FD283D8543C12B81917C154CDF4EFD3D48E553E6D7BC77E29CB168138CED17D").unwrap());
+    let encrypted = hex::decode("7D3B7EF87C819D646F5431A36F4416894B8487D9FB9E734AE2ACC8D021B76E676BAA34AEC06EA42C4D45CB302B11CE8F9A9BE7481C1814F1EACAB842").unwrap();
+
+    let decrypted = decrypt_message(&recipient, &sender_public_key, &encrypted).unwrap();
+    assert_eq!(decrypted, b"Simbol is awesome from Rust SDK!".to_vec());
+
+    // wallet layout (hex payload) and raw binary payload are both accepted.
+    let hex_message = TransferMessage::Encrypted(hex::encode_upper(&encrypted).into_bytes());
+    assert_eq!(hex_message.decrypt(&recipient, &sender_public_key).unwrap(), decrypted);
+    let binary_message = TransferMessage::Encrypted(encrypted.clone());
+    assert_eq!(binary_message.decrypt(&recipient, &sender_public_key).unwrap(), decrypted);
+
+    let mut tampered = encrypted;
+    tampered[40] ^= 0x01;
+    assert_eq!(decrypt_message(&recipient, &sender_public_key, &tampered), Err(MessageCipherError::DecryptionFailed));
+}
+
+#[cfg(feature = "crypto")]
+#[test]
+fn test_should_encrypt_with_given_iv() {
+    // AES-256-GCM test cases 13 and 14 of the GCM specification (McGrew and Viega).
+    let (key, iv) = ([0u8; 32], [0u8; AES_IV_SIZE]);
+    assert_eq!(hex::encode_upper(encrypt_aes_gcm_with_iv(&key, &iv, &[])), "530F8AFBC74536B9A963B4F1C4CB738B000000000000000000000000");
+    assert_eq!(hex::encode_upper(encrypt_aes_gcm_with_iv(&key, &iv, &[0u8; 16])),
+               "D0D1C8A799996BF0265B98B5D48AB919000000000000000000000000CEA7403D4D606B6E074EC5D3BAF39D18");
+
+    // the SDK payload is reproduced from its own initialization vector.
+    let recipient = key_pair_from_hex("A22A4BBF126A2D7D7ECE823174DFD184C5DE0FDE4CB2075D30CFA409F7EF8908");
+    let sender_public_key = KeyDto::from_binary(&hex::decode("3FD283D8543C12B81917C154CDF4EFD3D48E553E6D7BC77E29CB168138CED17D").unwrap());
+    let encrypted = hex::decode("7D3B7EF87C819D646F5431A36F4416894B8487D9FB9E734AE2ACC8D021B76E676BAA34AEC06EA42C4D45CB302B11CE8F9A9BE7481C1814F1EACAB842").unwrap();
+    let shared_key = derive_shared_key(&recipient, &sender_public_key).unwrap();
+    let mut sdk_iv = [0u8; AES_IV_SIZE];
+    sdk_iv.copy_from_slice(&encrypted[16..28]);
+    let reencrypted = encrypt_aes_gcm_with_iv(&shared_key, &sdk_iv, b"Simbol is awesome from Rust SDK!");
+    assert_eq!(reencrypted, encrypted);
+    assert_eq!(encrypt_aes_gcm_with_iv(&shared_key, &sdk_iv, b"Simbol is awesome from Rust SDK!"), reencrypted);
+    assert_eq!(decrypt_aes_gcm(&shared_key, &reencrypted).unwrap(), b"Simbol is awesome from Rust SDK!".to_vec());
+}
+
+#[cfg(feature = "crypto")]
+#[test]
+fn test_should_encrypt_message_for_sender_and_recipient() {
+    let sender = key_pair_from_hex("E1C8521608F4896CA26A0C2DE739310EA4B06861D126CF4D6922064678A1969B");
+    let recipient = key_pair_from_hex("A22A4BBF126A2D7D7ECE823174DFD184C5DE0FDE4CB2075D30CFA409F7EF8908");
+    assert_eq!(derive_shared_key(&sender, &recipient.get_public_key()),
+               derive_shared_key(&recipient, &sender.get_public_key()));
+
+    let message = TransferMessage::encrypt(&sender, &recipient.get_public_key(), "Nem is awesome from Rust!".as_bytes()).unwrap();
+    let serialized = message.serializer();
+    assert_eq!(serialized[0], 0x01);
+    assert_eq!(serialized.len(), 1 + 2 * (16 + 12 + 25));
+    assert!(serialized[1..].iter().all(|c| c.is_ascii_digit() || (b'A'..=b'F').contains(c)));
+
+    let decoded = TransferMessage::from_binary(&serialized);
+    assert_eq!(decoded.decrypt(&recipient, &sender.get_public_key()).unwrap(), b"Nem is awesome from Rust!".to_vec());
+    assert_eq!(decoded.decrypt(&sender, &recipient.get_public_key()).unwrap(), b"Nem is awesome from Rust!".to_vec());
+    assert_eq!(decoded.decrypt(&KeyPair::random(), &sender.get_public_key()), Err(MessageCipherError::DecryptionFailed));
+    assert_eq!(TransferMessage::from_text("clear").decrypt(&recipient, &sender.get_public_key()),
+               Err(MessageCipherError::UnexpectedMessageType));
+}
+
+#[cfg(feature = "crypto")]
+#[test]
+fn test_should_create_and_decrypt_persistent_harvesting_delegation_message() {
+    let node = key_pair_from_hex("A22A4BBF126A2D7D7ECE823174DFD184C5DE0FDE4CB2075D30CFA409F7EF8908");
+    let delegation = PersistentHarvestingDelegation {
+        signing_key_pair: key_pair_from_hex(PRIVATE_KEYS[0]),
+        vrf_key_pair: key_pair_from_hex(PRIVATE_KEYS[1]),
+    };
+
+    let message = delegation.create_message(&node.get_public_key()).unwrap();
+    let serialized = message.serializer();
+    assert_eq!(serialized.len(), 132);
+    assert_eq!(hex::encode_upper(&serialized[..8]), "FE2A8061577301E2");
+
+    let decoded = TransferMessage::from_binary(&serialized);
+    let decrypted = PersistentHarvestingDelegation::from_message(&decoded, &node).unwrap();
+    assert_eq!(decrypted.signing_key_pair.get_private_key(), delegation.signing_key_pair.get_private_key());
+    assert_eq!(decrypted.vrf_key_pair.get_private_key(), delegation.vrf_key_pair.get_private_key());
+    let linked_public_key = KeyDto::from_binary(&hex::decode(PUBLIC_KEYS[0]).unwrap());
+    let vrf_public_key = KeyDto::from_binary(&hex::decode(PUBLIC_KEYS[1]).unwrap());
+    assert!(decrypted.matches(&linked_public_key, &vrf_public_key));
+    assert!(!decrypted.matches(&vrf_public_key, &linked_public_key));
+}
+
+#[cfg(feature = "crypto")]
+#[test]
+fn test_should_reject_invalid_persistent_harvesting_delegation_message() {
+    let node = KeyPair::random();
+    let delegation = PersistentHarvestingDelegation { signing_key_pair: KeyPair::random(), vrf_key_pair: KeyPair::random() };
+    let message = delegation.create_message(&node.get_public_key()).unwrap();
+
+    assert_eq!(PersistentHarvestingDelegation::from_message(&message, &KeyPair::random()).unwrap_err(),
+               MessageCipherError::DecryptionFailed);
+
+    let truncated = TransferMessage::from_binary(&message.serializer()[..131]);
+    assert_eq!(PersistentHarvestingDelegation::from_message(&truncated, &node).unwrap_err(),
+               MessageCipherError::InvalidPayload);
+
+    assert_eq!(PersistentHarvestingDelegation::from_message(&TransferMessage::from_text("hi"), &node).unwrap_err(),
+               MessageCipherError::UnexpectedMessageType);
+}
+
+
+// ECVRF-EDWARDS25519-SHA512-Elligator2 vectors of draft-irtf-cfrg-vrf-03: private key, public key, alpha, proof, proof hash
+#[cfg(feature = "crypto")]
+const VRF_VECTORS: [(&str, &str, &str, &str, &str); 3] = [
+    ("9D61B19DEFFD5A60BA844AF492EC2CC44449C5697B326919703BAC031CAE7F60",
+     "D75A980182B10AB7D54BFED3C964073A0EE172F3DAA62325AF021A68F707511A",
+     "",
+     "B6B4699F87D56126C9117A7DA55BD0085246F4C56DBC95D20172612E9D38E8D7CA65E573A126ED88D4E30A46F80A666854D675CF3BA81DE0DE043C3774F061560F55EDC256A787AFE701677C0F602900",
+     "5B49B554D05C0CD5A5325376B3387DE59D924FD1E13DED44648AB33C21349A603F25B84EC5ED887995B33DA5E3BFCB87CD2F64521C4C62CF825CFFABBE5D31CC"),
+    ("4CCD089B28FF96DA9DB6C346EC114E0F5B8A319F35ABA624DA8CF6ED4FB8A6FB",
+     "3D4017C3E843895A92B70AA74D1B7EBC9C982CCF2EC4968CC0CD55F12AF4660C",
+     "72",
+     "AE5B66BDF04B4C010BFE32B2FC126EAD2107B697634F6F7337B9BFF8785EE111200095ECE87DDE4DBE87343F6DF3B107D91798C8A7EB1245D3BB9C5AAFB093358C13E6AE1111A55717E895FD15F99F07",
+     "94F4487E1B2FEC954309EF1289ECB2E15043A2461ECC7B2AE7D4470607EF82EB1CFA97D84991FE4A7BFDFD715606BC27E2967A6C557CFB5875879B671740B7D8"),
+    ("C5AA8DF43F9F837BEDB7442F31DCB7B166D38535076F094B85CE3A2E0B4458F7",
+     "FC51CD8E6218A1A38DA47ED00230F0580816ED13BA3303AC5DEB911548908025",
+     "AF82",
+     "DFA2CBA34B611CC8C833A6EA83B8EB1BB5E2EF2DD1B0C481BC42FF36AE7847F6AB52B976CFD5DEF172FA412DEFDE270C8B8BDFBAAE1C7ECE17D9833B1BCF31064FFF78EF493F820055B561ECE45E1009",
+     "2031837F582CD17A9AF9E0C7EF5A6540E3453ED894B62C293686CA3C1E319DDE9D0AA489A4B59A9594FC2328BC3DEFF3C8A0929A369A72B1180A596E016B5DED"),
+];
+
+#[cfg(feature = "crypto")]
+#[test]
+fn test_should_generate_and_verify_vrf_proofs() {
+    for (private_key, public_key, alpha, proof, proof_hash) in VRF_VECTORS.iter() {
+        let key_pair = key_pair_from_hex(private_key);
+        assert_eq!(hex::encode_upper(key_pair.get_public_key().0), *public_key);
+        let alpha = hex::decode(alpha).unwrap();
+
+        let vrf_proof = generate_vrf_proof(&alpha, &key_pair);
+        assert_eq!(hex::encode_upper(vrf_proof.serializer()), *proof);
+        let verified_hash = verify_vrf_proof(&vrf_proof, &alpha, &key_pair.get_public_key()).unwrap();
+        assert_eq!(hex::encode_upper(verified_hash.0), *proof_hash);
+    }
+}
+
+#[cfg(feature = "crypto")]
+#[test]
+fn test_should_reject_invalid_vrf_proofs() {
+    let key_pair = key_pair_from_hex(PRIVATE_KEYS[0]);
+    let alpha = Hash256Dto([0x12; 32]);
+    let vrf_proof = generate_vrf_proof(&alpha.0, &key_pair);
+    let proof_hash = verify_vrf_proof(&vrf_proof, &alpha.0, &key_pair.get_public_key()).unwrap();
+
+    let generation_hash = calculate_generation_hash(&vrf_proof, &alpha, &key_pair.get_public_key()).unwrap();
+    assert_eq!(generation_hash.0[..], proof_hash.0[..32]);
+
+    assert_eq!(verify_vrf_proof(&vrf_proof, &[0x12; 31], &key_pair.get_public_key()), None);
+    assert_eq!(verify_vrf_proof(&vrf_proof, &alpha.0, &KeyPair::random().get_public_key()), None);
+    assert_eq!(calculate_generation_hash(&vrf_proof, &Hash256Dto([0x13; 32]), &key_pair.get_public_key()), None);
+    for index in [0, 32, 48].iter() {
+        let mut payload = vrf_proof.serializer();
+        payload[*index] ^= 0x01;
+        let tampered_proof = VrfProofBuilder::from_binary(&payload);
+        assert_eq!(verify_vrf_proof(&tampered_proof, &alpha.0, &key_pair.get_public_key()), None);
+    }
+}
@@ -0,0 +1,119 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use catbuffer_rust::{
+    mosaic_address_restriction_entry_builder::MosaicAddressRestrictionEntryBuilder,
+    mosaic_global_restriction_entry_builder::MosaicGlobalRestrictionEntryBuilder,
+    mosaic_id_dto::MosaicIdDto,
+    mosaic_restriction_evaluator::{MosaicRestrictionEvaluator, MosaicRestrictionViolation},
+    mosaic_restriction_key_dto::MosaicRestrictionKeyDto,
+    mosaic_restriction_type_dto::MosaicRestrictionTypeDto,
+    validation_failure::ValidationFailure,
+};
+
+use common::{to_address, ALICE_ADDRESS, BOB_ADDRESS, TOKEN_MOSAIC_ID};
+
+const KYC_MOSAIC_ID: u64 = 0x2A09_B7F9_097A_3B5A;
+const KYC_KEY: u64 = 0x004B_5943;
+const AGE_KEY: u64 = 0x0041_4745;
+
+fn create_global_restriction(rules: &[(u64, u64, u64, MosaicRestrictionTypeDto)]) -> MosaicGlobalRestrictionEntryBuilder {
+    let mut payload = [TOKEN_MOSAIC_ID.to_le_bytes().to_vec(), vec![rules.len() as u8]].concat();
+    for (key, reference_mosaic_id, value, restriction_type) in rules.iter() {
+        payload.extend(key.to_le_bytes());
+        payload.extend(reference_mosaic_id.to_le_bytes());
+        payload.extend(value.to_le_bytes());
+        payload.extend(restriction_type.serializer());
+    }
+    MosaicGlobalRestrictionEntryBuilder::from_binary(&payload)
+}
+
+fn create_address_restriction(mosaic_id: u64, address: &str, values: &[(u64, u64)]) -> MosaicAddressRestrictionEntryBuilder {
+    let mut payload = [mosaic_id.to_le_bytes().to_vec(), hex::decode(address).unwrap(), vec![values.len() as u8]].concat();
+    for (key, value) in values.iter() {
+        payload.extend(key.to_le_bytes());
+        payload.extend(value.to_le_bytes());
+    }
+    MosaicAddressRestrictionEntryBuilder::from_binary(&payload)
+}
+
+#[test]
+fn test_should_evaluate_restriction_types() {
+    const VECTORS: [(MosaicRestrictionTypeDto, bool, bool, bool); 7] = [
+        // value below, equal to and above the rule value
+        (MosaicRestrictionTypeDto::NONE, true, true, true),
+        (MosaicRestrictionTypeDto::EQ, false, true, false),
+        (MosaicRestrictionTypeDto::NE, true, false, true),
+        (MosaicRestrictionTypeDto::LT, true, false, false),
+        (MosaicRestrictionTypeDto::LE, true, true, false),
+        (MosaicRestrictionTypeDto::GT, false, false, true),
+        (MosaicRestrictionTypeDto::GE, false, true, true),
+    ];
+    for (restriction_type, below, equal, above) in VECTORS.iter() {
+        assert_eq!(restriction_type.evaluate(17, 18), *below);
+        assert_eq!(restriction_type.evaluate(18, 18), *equal);
+        assert_eq!(restriction_type.evaluate(19, 18), *above);
+    }
+}
+
+#[test]
+fn test_should_report_failed_key() {
+    let global_restriction = create_global_restriction(&[
+        (KYC_KEY, KYC_MOSAIC_ID, 1, MosaicRestrictionTypeDto::EQ),
+        (AGE_KEY, 0, 18, MosaicRestrictionTypeDto::GE),
+    ]);
+    let address_restrictions = [
+        create_address_restriction(KYC_MOSAIC_ID, ALICE_ADDRESS, &[(KYC_KEY, 1)]),
+        create_address_restriction(TOKEN_MOSAIC_ID, ALICE_ADDRESS, &[(AGE_KEY, 30)]),
+        create_address_restriction(KYC_MOSAIC_ID, BOB_ADDRESS, &[(KYC_KEY, 1)]),
+        create_address_restriction(TOKEN_MOSAIC_ID, BOB_ADDRESS, &[(AGE_KEY, 17)]),
+    ];
+    let evaluator = MosaicRestrictionEvaluator { global_restriction: &global_restriction, address_restrictions: &address_restrictions };
+    assert_eq!(evaluator.get_reference_mosaic_ids(), vec![MosaicIdDto(KYC_MOSAIC_ID), MosaicIdDto(TOKEN_MOSAIC_ID)]);
+    assert_eq!(evaluator.check_address(&to_address(ALICE_ADDRESS)), Ok(()));
+
+    let violation = evaluator.check_transfer(&to_address(ALICE_ADDRESS), &to_address(BOB_ADDRESS)).unwrap_err();
+    assert_eq!(violation, MosaicRestrictionViolation {
+        address: to_address(BOB_ADDRESS),
+        key: MosaicRestrictionKeyDto(AGE_KEY),
+        reference_mosaic_id: MosaicIdDto(TOKEN_MOSAIC_ID),
+        restriction_type: MosaicRestrictionTypeDto::GE,
+        restriction_value: 18,
+        address_value: Some(17),
+    });
+    assert_eq!(violation.get_failure(), ValidationFailure::Failure_RestrictionMosaic_Account_Unauthorized);
+    assert_eq!(violation.to_string(), format!("account {} fails key 0000000000414745 of mosaic 091F837E059AE13C: value 17 is not GE 18", BOB_ADDRESS));
+}
+
+#[test]
+fn test_should_reject_accounts_without_value() {
+    let global_restriction = create_global_restriction(&[(KYC_KEY, KYC_MOSAIC_ID, 0, MosaicRestrictionTypeDto::NE)]);
+    let evaluator = MosaicRestrictionEvaluator { global_restriction: &global_restriction, address_restrictions: &[] };
+    let violation = evaluator.check_address(&to_address(ALICE_ADDRESS)).unwrap_err();
+    assert_eq!(violation.key, MosaicRestrictionKeyDto(KYC_KEY));
+    assert_eq!(violation.address_value, None);
+
+    let global_restriction = create_global_restriction(&[(KYC_KEY, KYC_MOSAIC_ID, 0, MosaicRestrictionTypeDto::NONE)]);
+    let evaluator = MosaicRestrictionEvaluator { global_restriction: &global_restriction, address_restrictions: &[] };
+    assert_eq!(evaluator.check_address(&to_address(ALICE_ADDRESS)), Ok(()));
+}
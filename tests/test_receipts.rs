@@ -0,0 +1,222 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use sha3::{Digest, Sha3_256};
+
+use catbuffer_rust::{
+    block_statement_builder::BlockStatementBuilder,
+    normal_block_header_builder::NormalBlockHeaderBuilder,
+    receipt::{load_receipt_from_binary, Receipt},
+    receipt_type_dto::ReceiptTypeDto,
+};
+
+use common::{create_block_header_with_receipts_hash, ALICE_ADDRESS, BOB_ADDRESS};
+
+const MOSAIC: &str = "F82302A23F91ED6B40420F0000000000";
+
+fn load(payload: &str) -> (Vec<u8>, Receipt) {
+    let bytes = hex::decode(payload).unwrap();
+    let receipt = load_receipt_from_binary(&bytes);
+    (bytes, receipt)
+}
+
+#[test]
+fn test_should_load_balance_change_receipts() {
+    for receipt_type in ["4321", "4831", "4822", "4823", "5231", "5222", "5223"].iter() {
+        let (bytes, receipt) = load(&format!("300000000100{}{}{}", receipt_type, MOSAIC, ALICE_ADDRESS));
+        match &receipt {
+            Receipt::BalanceChange(builder) => {
+                assert_eq!(builder.get_mosaic().get_amount().0, 1_000_000);
+                assert_eq!(hex::encode_upper(builder.get_target_address().0), ALICE_ADDRESS);
+            }
+            other => panic!("unexpected receipt {:?}", other),
+        }
+        assert_eq!(receipt.get_size(), bytes.len());
+        assert_eq!(receipt.serializer(), bytes);
+    }
+}
+
+#[test]
+fn test_should_load_rental_fee_receipts() {
+    for (receipt_type, expected_type) in [("4D12", ReceiptTypeDto::MOSAIC_RENTAL_FEE), ("4E13", ReceiptTypeDto::NAMESPACE_RENTAL_FEE)].iter() {
+        let (bytes, receipt) = load(&format!("480000000100{}{}{}{}", receipt_type, MOSAIC, ALICE_ADDRESS, BOB_ADDRESS));
+        match &receipt {
+            Receipt::BalanceTransfer(builder) => {
+                assert_eq!(hex::encode_upper(builder.get_sender_address().0), ALICE_ADDRESS);
+                assert_eq!(hex::encode_upper(builder.get_recipient_address().0), BOB_ADDRESS);
+            }
+            other => panic!("unexpected receipt {:?}", other),
+        }
+        assert_eq!(receipt.get_type(), Some(*expected_type));
+        assert_eq!(receipt.serializer(), bytes);
+    }
+}
+
+#[test]
+fn test_should_load_expiry_and_inflation_receipts() {
+    let (bytes, receipt) = load("1000000001004D41F82302A23F91ED6B");
+    assert!(matches!(receipt, Receipt::MosaicExpiry(ref builder) if builder.get_artifact_id().0 == 0x6BED_913F_A202_23F8));
+    assert_eq!(receipt.serializer(), bytes);
+
+    for receipt_type in ["4E41", "4E42"].iter() {
+        let (bytes, receipt) = load(&format!("100000000100{}A95F1F8A96159516", receipt_type));
+        assert!(matches!(receipt, Receipt::NamespaceExpiry(ref builder) if builder.get_artifact_id().0 == 0x1695_1596_8A1F_5FA9));
+        assert_eq!(receipt.serializer(), bytes);
+    }
+
+    let (bytes, receipt) = load(&format!("1800000001004351{}", MOSAIC));
+    assert!(matches!(receipt, Receipt::Inflation(ref builder) if builder.get_mosaic().get_amount().0 == 1_000_000));
+    assert_eq!(receipt.get_type(), Some(ReceiptTypeDto::INFLATION));
+    assert_eq!(receipt.serializer(), bytes);
+}
+
+#[test]
+fn test_should_keep_unknown_receipts_raw() {
+    let (_, receipt) = load("0C0000000100FFFF01020304AABBCCDD");
+    assert!(matches!(receipt, Receipt::Raw(ref payload) if *payload == hex::decode("0C0000000100FFFF01020304").unwrap()));
+    assert_eq!(receipt.get_type_value(), 0xFFFF);
+    assert_eq!(receipt.get_type(), None);
+    assert_eq!(receipt.get_size(), 12);
+
+    let (bytes, receipt) = load("08000000010043F1");
+    assert!(matches!(receipt, Receipt::Raw(_)));
+    assert_eq!(receipt.get_type(), Some(ReceiptTypeDto::ADDRESS_ALIAS_RESOLUTION));
+    assert_eq!(receipt.serializer(), bytes);
+}
+
+const NAMESPACE_ALIAS: &str = "99A95F1F8A96159516000000000000000000000000000000";
+
+fn create_receipts() -> [String; 3] {
+    [
+        format!("300000000100{}{}{}", "4321", MOSAIC, ALICE_ADDRESS),
+        format!("1800000001004351{}", MOSAIC),
+        format!("300000000100{}{}{}", "4831", MOSAIC, BOB_ADDRESS),
+    ]
+}
+
+fn create_address_resolution_entries() -> String {
+    format!("{}{}{}{}", "0100000000000000", ALICE_ADDRESS, "0200000001000000", BOB_ADDRESS)
+}
+
+fn create_block_statement() -> String {
+    let [harvest_fee, inflation, lock_created] = create_receipts();
+    [
+        // transaction statements: block source with two receipts, first transaction with one receipt
+        format!("02000000{}{}02000000{}{}", "00000000", "00000000", harvest_fee, inflation),
+        format!("{}{}01000000{}", "01000000", "00000000", lock_created),
+        // address resolution statement with two entries
+        format!("01000000{}02000000{}", NAMESPACE_ALIAS, create_address_resolution_entries()),
+        // mosaic resolution statement with one entry
+        format!("01000000{}01000000{}{}", "A95F1F8A96159596", "0100000000000000", "F82302A23F91ED6B"),
+    ]
+    .concat()
+}
+
+fn sha3(payload: &str) -> Vec<u8> {
+    Sha3_256::digest(&hex::decode(payload).unwrap()).to_vec()
+}
+
+#[test]
+fn test_should_round_trip_block_statement() {
+    let bytes = hex::decode(create_block_statement()).unwrap();
+    let statement = BlockStatementBuilder::from_binary(&bytes);
+
+    let transaction_statements = statement.get_transaction_statements();
+    assert_eq!(transaction_statements.len(), 2);
+    assert_eq!(transaction_statements[0].get_receipts().len(), 2);
+    assert!(matches!(transaction_statements[0].get_receipts()[1], Receipt::Inflation(_)));
+    assert_eq!(transaction_statements[1].get_source().get_primary_id(), 1);
+    assert_eq!(transaction_statements[1].get_receipts()[0].get_type(), Some(ReceiptTypeDto::LOCK_HASH_CREATED));
+
+    let address_resolution_statements = statement.get_address_resolution_statements();
+    assert_eq!(address_resolution_statements.len(), 1);
+    let entries = address_resolution_statements[0].get_resolution_entries();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[1].get_source().get_primary_id(), 2);
+    assert_eq!(entries[1].get_source().get_secondary_id(), 1);
+    assert_eq!(hex::encode_upper(entries[1].get_resolved().0), BOB_ADDRESS);
+
+    let mosaic_resolution_statements = statement.get_mosaic_resolution_statements();
+    assert_eq!(mosaic_resolution_statements.len(), 1);
+    assert_eq!(mosaic_resolution_statements[0].get_unresolved().0, 0x9695_1596_8A1F_5FA9);
+    assert_eq!(mosaic_resolution_statements[0].get_resolution_entries()[0].get_resolved().0, 0x6BED_913F_A202_23F8);
+
+    assert_eq!(statement.get_size(), bytes.len());
+    assert_eq!(statement.serializer(), bytes);
+}
+
+#[test]
+fn test_should_round_trip_empty_block_statement() {
+    let bytes = vec![0u8; 12];
+    let statement = BlockStatementBuilder::from_binary(&bytes);
+    assert!(statement.get_transaction_statements().is_empty());
+    assert!(statement.get_address_resolution_statements().is_empty());
+    assert!(statement.get_mosaic_resolution_statements().is_empty());
+    assert_eq!(statement.serializer(), bytes);
+}
+
+#[test]
+fn test_should_compute_merkle_root_of_block_statement() {
+    let bytes = hex::decode(create_block_statement()).unwrap();
+    let statement = BlockStatementBuilder::from_binary(&bytes);
+
+    // statements are hashed with version and receipt type headers, receipts without their size
+    let [harvest_fee, inflation, lock_created] = create_receipts();
+    let hashes = [
+        sha3(&format!("010043E10000000000000000{}{}", &harvest_fee[8..], &inflation[8..])),
+        sha3(&format!("010043E10100000000000000{}", &lock_created[8..])),
+        sha3(&format!("010043F1{}{}", NAMESPACE_ALIAS, create_address_resolution_entries())),
+        sha3("010043F2A95F1F8A961595960100000000000000F82302A23F91ED6B"),
+    ];
+    let transaction_statements = statement.get_transaction_statements();
+    assert_eq!(transaction_statements[0].calculate_hash().0.to_vec(), hashes[0]);
+    assert_eq!(statement.get_address_resolution_statements()[0].calculate_hash().0.to_vec(), hashes[2]);
+
+    let left = sha3(&hex::encode([hashes[0].clone(), hashes[1].clone()].concat()));
+    let right = sha3(&hex::encode([hashes[2].clone(), hashes[3].clone()].concat()));
+    let expected_root = sha3(&hex::encode([left, right].concat()));
+    assert_eq!(statement.compute_merkle_root().0.to_vec(), expected_root);
+}
+
+#[test]
+fn test_should_verify_block_statement_against_block_header() {
+    let statement = BlockStatementBuilder::from_binary(&hex::decode(create_block_statement()).unwrap());
+    let root = statement.compute_merkle_root();
+
+    let header_bytes = [create_block_header_with_receipts_hash("4381", 376, &root.0), vec![0; 4]].concat();
+    let header = NormalBlockHeaderBuilder::from_binary(&header_bytes);
+    assert_eq!(header.serializer(), header_bytes);
+    assert_eq!(header.super_object.get_receipts_hash(), root);
+    assert!(statement.verify(&header.super_object));
+
+    let mut other_root = root.0;
+    other_root[0] ^= 0xFF;
+    let other_header = NormalBlockHeaderBuilder::from_binary(&[create_block_header_with_receipts_hash("4381", 376, &other_root), vec![0; 4]].concat());
+    assert!(!statement.verify(&other_header.super_object));
+}
+
+#[test]
+fn test_should_compute_zero_merkle_root_of_empty_block_statement() {
+    let statement = BlockStatementBuilder::from_binary(&[0u8; 12]);
+    assert_eq!(statement.compute_merkle_root().0, [0u8; 32]);
+}
@@ -0,0 +1,247 @@
+/*
+ * // Copyright (c) 2016-2019, Jaguar0625, gimre, BloodyRookie, Tech Bureau, Corp.
+ * // Copyright (c) 2020-present, Jaguar0625, gimre, BloodyRookie.
+ * // All rights reserved.
+ * //
+ * // This file is part of Catapult.
+ * //
+ * // Catapult is free software: you can redistribute it and/or modify
+ * // it under the terms of the GNU Lesser General Public License as published by
+ * // the Free Software Foundation, either version 3 of the License, or
+ * // (at your option) any later version.
+ * //
+ * // Catapult is distributed in the hope that it will be useful,
+ * // but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * // GNU Lesser General Public License for more details.
+ * //
+ * // You should have received a copy of the GNU Lesser General Public License
+ * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use std::collections::HashMap;
+
+use catbuffer_rust::{
+    account_restriction_flags_dto::AccountRestrictionFlagsDto,
+    account_restrictions_builder::AccountRestrictionsBuilder,
+    account_state_builder::AccountStateBuilder,
+    address_alias_transaction_body_builder::AddressAliasTransactionBodyBuilder,
+    address_alias_transaction_builder::AddressAliasTransactionBuilder,
+    address_dto::AddressDto,
+    aggregate_complete_transaction_builder::AggregateCompleteTransactionBuilder,
+    aggregate_transaction_body_builder::AggregateTransactionBodyBuilder,
+    alias_action_dto::AliasActionDto,
+    entity_type_dto::EntityTypeDto,
+    hash256_dto::Hash256Dto,
+    height_dto::HeightDto,
+    mosaic_address_restriction_entry_builder::MosaicAddressRestrictionEntryBuilder,
+    mosaic_entry_builder::MosaicEntryBuilder,
+    mosaic_global_restriction_entry_builder::MosaicGlobalRestrictionEntryBuilder,
+    mosaic_id_dto::MosaicIdDto,
+    namespace_id_dto::NamespaceIdDto,
+    network_type_dto::NetworkTypeDto,
+    root_namespace_history_builder::RootNamespaceHistoryBuilder,
+    state_provider::StateProvider,
+    stateful_validator::StatefulValidator,
+    unresolved_address_dto::UnresolvedAddressDto,
+    validation_config::ValidationConfig,
+    validation_failure::ValidationFailure,
+};
+
+use common::{
+    address, alias_address, create_embedded_transfer, create_restrictions, create_transaction, create_transfer, hex_u64, key,
+    ALICE_ADDRESS, ALICE_PUBLIC_KEY, BOB_PUBLIC_KEY, CAROL_ADDRESS, CURRENCY_MOSAIC_ID, NAMESPACE_ID, TOKEN_MOSAIC_ID,
+};
+
+/// In-memory chain state.
+#[derive(Default)]
+struct MemoryStateProvider {
+    height: u64,
+    accounts: HashMap<AddressDto, AccountStateBuilder>,
+    mosaics: HashMap<MosaicIdDto, MosaicEntryBuilder>,
+    namespaces: Vec<RootNamespaceHistoryBuilder>,
+    restrictions: HashMap<AddressDto, AccountRestrictionsBuilder>,
+    mosaic_global_restrictions: HashMap<MosaicIdDto, MosaicGlobalRestrictionEntryBuilder>,
+    mosaic_address_restrictions: Vec<MosaicAddressRestrictionEntryBuilder>,
+}
+
+impl StateProvider for MemoryStateProvider {
+    fn get_height(&self) -> HeightDto {
+        HeightDto(self.height)
+    }
+
+    fn get_account_state(&self, address: &AddressDto) -> Option<AccountStateBuilder> {
+        self.accounts.get(address).cloned()
+    }
+
+    fn get_mosaic_entry(&self, mosaic_id: &MosaicIdDto) -> Option<MosaicEntryBuilder> {
+        self.mosaics.get(mosaic_id).cloned()
+    }
+
+    fn get_root_namespace(&self, namespace_id: &NamespaceIdDto) -> Option<RootNamespaceHistoryBuilder> {
+        self.namespaces.iter().find(|root_namespace| root_namespace.get_alias(namespace_id).is_some()).cloned()
+    }
+
+    fn get_account_restrictions(&self, address: &AddressDto) -> Option<AccountRestrictionsBuilder> {
+        self.restrictions.get(address).cloned()
+    }
+
+    fn get_mosaic_global_restriction(&self, mosaic_id: &MosaicIdDto) -> Option<MosaicGlobalRestrictionEntryBuilder> {
+        self.mosaic_global_restrictions.get(mosaic_id).cloned()
+    }
+
+    fn get_mosaic_address_restriction(&self, mosaic_id: &MosaicIdDto, address: &AddressDto) -> Option<MosaicAddressRestrictionEntryBuilder> {
+        self.mosaic_address_restrictions.iter().find(|entry| entry.get_mosaic_id() == *mosaic_id && entry.get_address() == *address).cloned()
+    }
+}
+
+fn create_account(address: &AddressDto, balances: &[(u64, u64)]) -> AccountStateBuilder {
+    let balances: String = balances.iter().map(|(mosaic_id, amount)| hex_u64(*mosaic_id) + &hex_u64(*amount)).collect();
+    let payload = format!("0100{}{}{}{}00000000{}{}", hex::encode(address.0), hex_u64(1), "00".repeat(32), hex_u64(1), hex::encode((balances.len() as u16 / 32).to_le_bytes()), balances);
+    AccountStateBuilder::from_binary(&hex::decode(payload).unwrap())
+}
+
+fn create_mosaic(mosaic_id: u64, owner_address: &AddressDto, flags: u8, duration: u64) -> MosaicEntryBuilder {
+    let payload = format!("0100{}{}{}{}01000000{:02X}00{}", hex_u64(mosaic_id), hex_u64(1_000_000), hex_u64(1), hex::encode(owner_address.0), flags, hex_u64(duration));
+    MosaicEntryBuilder::from_binary(&hex::decode(payload).unwrap())
+}
+
+fn create_namespace(namespace_id: u64, owner_address: &AddressDto, alias: &AddressDto, lifetime_end: u64) -> RootNamespaceHistoryBuilder {
+    let payload = format!("0100{}{}{}{}02{}{}", hex_u64(namespace_id), hex::encode(owner_address.0), hex_u64(1), hex_u64(lifetime_end), hex::encode(alias.0), hex_u64(0));
+    RootNamespaceHistoryBuilder::from_binary(&hex::decode(payload).unwrap())
+}
+
+fn create_state() -> MemoryStateProvider {
+    let alice = address(ALICE_PUBLIC_KEY);
+    let bob = address(BOB_PUBLIC_KEY);
+    let mut state = MemoryStateProvider { height: 100, ..Default::default() };
+    state.accounts.insert(alice, create_account(&alice, &[(CURRENCY_MOSAIC_ID, 1_000), (TOKEN_MOSAIC_ID, 50)]));
+    state.accounts.insert(bob, create_account(&bob, &[(CURRENCY_MOSAIC_ID, 50)]));
+    state.mosaics.insert(MosaicIdDto(CURRENCY_MOSAIC_ID), create_mosaic(CURRENCY_MOSAIC_ID, &alice, 2, 0));
+    state.mosaics.insert(MosaicIdDto(TOKEN_MOSAIC_ID), create_mosaic(TOKEN_MOSAIC_ID, &alice, 2, 0));
+    state.namespaces.push(create_namespace(NAMESPACE_ID, &alice, &bob, 1_000));
+    state
+}
+
+fn validate(state: &MemoryStateProvider, payload: &[u8]) -> Vec<ValidationFailure> {
+    let config = ValidationConfig { network: NetworkTypeDto::PUBLIC_TEST, ..Default::default() };
+    StatefulValidator { config: &config, provider: state }.validate(payload)
+}
+
+fn bob_recipient() -> UnresolvedAddressDto {
+    UnresolvedAddressDto(address(BOB_PUBLIC_KEY).0)
+}
+
+#[test]
+fn test_should_derive_address_from_public_key() {
+    let alice = address(ALICE_PUBLIC_KEY);
+    assert_eq!(hex::encode_upper(alice.0), ALICE_ADDRESS);
+    assert_eq!(AddressDto::from_public_key(NetworkTypeDto::PUBLIC, &key(ALICE_PUBLIC_KEY)).0[0], 0x68);
+}
+
+#[test]
+fn test_should_validate_transfer_balances() {
+    let state = create_state();
+    assert_eq!(validate(&state, &create_transfer(bob_recipient(), &[(TOKEN_MOSAIC_ID, 50), (CURRENCY_MOSAIC_ID, 900)])), vec![]);
+    assert_eq!(validate(&state, &create_transfer(bob_recipient(), &[(TOKEN_MOSAIC_ID, 51)])), vec![ValidationFailure::Failure_Core_Insufficient_Balance]);
+    // fee and transferred currency are debited together
+    assert_eq!(validate(&state, &create_transfer(bob_recipient(), &[(CURRENCY_MOSAIC_ID, 901)])), vec![ValidationFailure::Failure_Core_Insufficient_Balance]);
+}
+
+#[test]
+fn test_should_validate_mosaic_state() {
+    let mut state = create_state();
+    let carol = AddressDto::from_binary(&hex::decode(CAROL_ADDRESS).unwrap());
+    state.mosaics.insert(MosaicIdDto(TOKEN_MOSAIC_ID), create_mosaic(TOKEN_MOSAIC_ID, &carol, 0, 0));
+    assert_eq!(validate(&state, &create_transfer(bob_recipient(), &[(TOKEN_MOSAIC_ID, 1)])), vec![ValidationFailure::Failure_Mosaic_Non_Transferable]);
+    assert_eq!(validate(&state, &create_transfer(UnresolvedAddressDto(carol.0), &[(TOKEN_MOSAIC_ID, 1)])), vec![]);
+
+    state.mosaics.insert(MosaicIdDto(TOKEN_MOSAIC_ID), create_mosaic(TOKEN_MOSAIC_ID, &carol, 2, 99));
+    assert_eq!(validate(&state, &create_transfer(bob_recipient(), &[(TOKEN_MOSAIC_ID, 1)])), vec![ValidationFailure::Failure_Mosaic_Expired]);
+    assert_eq!(validate(&state, &create_transfer(bob_recipient(), &[(0x1234, 1)])), vec![
+        ValidationFailure::Failure_Mosaic_Expired,
+        ValidationFailure::Failure_Core_Insufficient_Balance,
+    ]);
+}
+
+#[test]
+fn test_should_resolve_recipient_alias_of_active_namespace() {
+    let mut state = create_state();
+    assert_eq!(validate(&state, &create_transfer(alias_address(NAMESPACE_ID), &[(TOKEN_MOSAIC_ID, 1)])), vec![]);
+
+    state.height = 1_000;
+    assert_eq!(validate(&state, &create_transfer(alias_address(NAMESPACE_ID), &[(TOKEN_MOSAIC_ID, 1)])), vec![ValidationFailure::Failure_Namespace_Unknown]);
+}
+
+#[test]
+fn test_should_validate_alias_namespace() {
+    let mut state = create_state();
+    let payload = |namespace_id: u64| AddressAliasTransactionBuilder {
+        super_object: create_transaction(ALICE_PUBLIC_KEY, EntityTypeDto::ADDRESS_ALIAS_TRANSACTION),
+        body: AddressAliasTransactionBodyBuilder { namespace_id: NamespaceIdDto(namespace_id), address: address(ALICE_PUBLIC_KEY), alias_action: AliasActionDto::LINK },
+    }.serializer();
+    assert_eq!(validate(&state, &payload(NAMESPACE_ID)), vec![]);
+    assert_eq!(validate(&state, &payload(NAMESPACE_ID + 1)), vec![ValidationFailure::Failure_Namespace_Unknown]);
+
+    state.height = 1_000;
+    assert_eq!(validate(&state, &payload(NAMESPACE_ID)), vec![ValidationFailure::Failure_Namespace_Expired]);
+}
+
+#[test]
+fn test_should_validate_account_restrictions() {
+    let alice = address(ALICE_PUBLIC_KEY);
+    let bob = address(BOB_PUBLIC_KEY);
+
+    let mut state = create_state();
+    state.restrictions.insert(bob, create_restrictions(&bob, &[(vec![AccountRestrictionFlagsDto::ADDRESS, AccountRestrictionFlagsDto::BLOCK], vec![alice.0.to_vec()])]));
+    assert_eq!(validate(&state, &create_transfer(bob_recipient(), &[])), vec![ValidationFailure::Failure_RestrictionAccount_Address_Interaction_Prohibited]);
+
+    let mut state = create_state();
+    state.restrictions.insert(bob, create_restrictions(&bob, &[(vec![AccountRestrictionFlagsDto::MOSAIC_ID], vec![CURRENCY_MOSAIC_ID.to_le_bytes().to_vec()])]));
+    assert_eq!(validate(&state, &create_transfer(bob_recipient(), &[(CURRENCY_MOSAIC_ID, 1)])), vec![]);
+    assert_eq!(validate(&state, &create_transfer(bob_recipient(), &[(TOKEN_MOSAIC_ID, 1)])), vec![ValidationFailure::Failure_RestrictionAccount_Mosaic_Transfer_Prohibited]);
+
+    let mut state = create_state();
+    let flags = vec![AccountRestrictionFlagsDto::TRANSACTION_TYPE, AccountRestrictionFlagsDto::OUTGOING];
+    state.restrictions.insert(alice, create_restrictions(&alice, &[(flags, vec![EntityTypeDto::HASH_LOCK_TRANSACTION.serializer()])]));
+    assert_eq!(validate(&state, &create_transfer(bob_recipient(), &[])), vec![ValidationFailure::Failure_RestrictionAccount_Operation_Type_Prohibited]);
+}
+
+#[test]
+fn test_should_validate_inner_transactions_with_their_signer() {
+    let state = create_state();
+    let inner_transfer = |amount: u64| create_embedded_transfer(BOB_PUBLIC_KEY, UnresolvedAddressDto(address(ALICE_PUBLIC_KEY).0), &[(CURRENCY_MOSAIC_ID, amount)]);
+    let payload = |amount: u64| {
+        let mut aggregate = AggregateCompleteTransactionBuilder {
+            super_object: create_transaction(ALICE_PUBLIC_KEY, EntityTypeDto::AGGREGATE_COMPLETE_TRANSACTION),
+            body: AggregateTransactionBodyBuilder { transactions_hash: Hash256Dto([0; 32]), transactions: vec![], cosignatures: vec![] },
+        };
+        aggregate.push_transaction(Box::new(inner_transfer(amount))).unwrap();
+        aggregate.serializer()
+    };
+    // the aggregate fee is paid by alice, the inner transfer by bob
+    assert_eq!(validate(&state, &payload(50)), vec![]);
+    assert_eq!(validate(&state, &payload(51)), vec![ValidationFailure::Failure_Core_Insufficient_Balance]);
+}
+
+#[test]
+fn test_should_validate_mosaic_restrictions_of_both_accounts() {
+    let alice = address(ALICE_PUBLIC_KEY);
+    let bob = address(BOB_PUBLIC_KEY);
+    let kyc_key = 0x004B_5943u64;
+    let rule = [TOKEN_MOSAIC_ID.to_le_bytes().to_vec(), vec![1], kyc_key.to_le_bytes().to_vec(), vec![0; 8], 1u64.to_le_bytes().to_vec(), vec![1]].concat();
+    let address_restriction = |address: &AddressDto| MosaicAddressRestrictionEntryBuilder::from_binary(&[
+        TOKEN_MOSAIC_ID.to_le_bytes().to_vec(), address.0.to_vec(), vec![1], kyc_key.to_le_bytes().to_vec(), 1u64.to_le_bytes().to_vec(),
+    ].concat());
+
+    let mut state = create_state();
+    state.mosaic_global_restrictions.insert(MosaicIdDto(TOKEN_MOSAIC_ID), MosaicGlobalRestrictionEntryBuilder::from_binary(&rule));
+    state.mosaic_address_restrictions.push(address_restriction(&alice));
+    assert_eq!(validate(&state, &create_transfer(bob_recipient(), &[(TOKEN_MOSAIC_ID, 1)])), vec![ValidationFailure::Failure_RestrictionMosaic_Account_Unauthorized]);
+    assert_eq!(validate(&state, &create_transfer(bob_recipient(), &[(CURRENCY_MOSAIC_ID, 1)])), vec![]);
+
+    state.mosaic_address_restrictions.push(address_restriction(&bob));
+    assert_eq!(validate(&state, &create_transfer(bob_recipient(), &[(TOKEN_MOSAIC_ID, 1)])), vec![]);
+}
@@ -19,7 +19,11 @@
  * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
  */
 
-use catbuffer_rust::account_metadata_transaction_builder::AccountMetadataTransactionBuilder;
+use catbuffer_rust::{
+    account_metadata_transaction_builder::AccountMetadataTransactionBuilder,
+    metadata_value_update::{MetadataValueError, MetadataValueUpdate},
+    scoped_metadata_key_dto::ScopedMetadataKeyDto,
+};
 
 const VECTOR_TRANSACTION_BUILDER: [&str; 2] = [
     "AA0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000F6503F78FBF99544B906872DDB392F4BE707180D285E7919DBACEF2E9573B1E60000000001904441000000000000000001000000000000009083025FF3A8AB5AD104631FB370F290004952CD1FDDC4C90A000000000000000A000600313233424143",
@@ -33,4 +37,72 @@ fn test_should_create_transactions() {
         let build_object = AccountMetadataTransactionBuilder::from_binary(&*bytes_vector);
         assert_eq!(build_object.serializer(), bytes_vector);
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_should_set_value_update_without_entry() {
+    let bytes_vector = hex::decode(VECTOR_TRANSACTION_BUILDER[0]).unwrap();
+    let mut build_object = AccountMetadataTransactionBuilder::from_binary(&bytes_vector);
+    build_object.set_value_update(None, b"hello").unwrap();
+    assert_eq!(build_object.get_value_size_delta(), 5);
+    assert_eq!(build_object.get_value(), b"hello".to_vec());
+}
+
+#[test]
+fn test_should_decode_chain_of_value_updates() {
+    let values: [&[u8]; 4] = [b"abc", b"abcdef", b"xy", b""];
+    let bytes_vector = hex::decode(VECTOR_TRANSACTION_BUILDER[0]).unwrap();
+    let mut updates = vec![];
+    let mut previous: &[u8] = &[];
+    for value in values.iter() {
+        let mut build_object = AccountMetadataTransactionBuilder::from_binary(&bytes_vector);
+        let update = MetadataValueUpdate::from_values(previous, value).unwrap();
+        build_object.set_value_size_delta(update.value_size_delta);
+        build_object.set_value(update.value);
+        let build_object = AccountMetadataTransactionBuilder::from_binary(&build_object.serializer());
+        updates.push(build_object.get_value_update());
+        assert_eq!(MetadataValueUpdate::apply_all(&updates).unwrap(), value.to_vec());
+        previous = value;
+    }
+    assert_eq!(updates[2].get_signed_value_size_delta(), -4);
+    assert_eq!(updates[2].value.len(), 6);
+}
+
+#[test]
+fn test_should_reject_inconsistent_value_update() {
+    let update = MetadataValueUpdate { value_size_delta: 3, value: b"abcd".to_vec() };
+    assert_eq!(update.apply(&[]), Err(MetadataValueError::ValueSizeMismatch { expected: 3, actual: 4 }));
+
+    let update = MetadataValueUpdate { value_size_delta: (-2i16) as u16, value: b"ab".to_vec() };
+    assert_eq!(update.apply(b"a"), Err(MetadataValueError::NegativeValueSize(-1)));
+
+    let update = MetadataValueUpdate { value_size_delta: (-1i16) as u16, value: b"\x00\x00".to_vec() };
+    assert_eq!(update.apply(b"ab"), Err(MetadataValueError::ValueNotTrimmable));
+}
+
+#[test]
+fn test_should_reject_value_update_not_fitting_16_bits() {
+    let large_value = vec![0x41; 40_000];
+    assert_eq!(MetadataValueUpdate::from_values(&[], &large_value), Err(MetadataValueError::ValueSizeDeltaOutOfRange(40_000)));
+    assert_eq!(MetadataValueUpdate::from_values(&large_value, b"a"), Err(MetadataValueError::ValueSizeDeltaOutOfRange(-39_999)));
+    assert_eq!(MetadataValueUpdate::from_values(&[], &vec![0x41; 70_000]), Err(MetadataValueError::ValueTooLarge(70_000)));
+
+    let update = MetadataValueUpdate::from_values(&vec![0x41; 30_000], &large_value).unwrap();
+    assert_eq!(update.get_signed_value_size_delta(), 10_000);
+    assert_eq!(update.apply(&vec![0x41; 30_000]).unwrap(), large_value);
+}
+
+#[test]
+fn test_should_set_scoped_metadata_key_from_string() {
+    let bytes_vector = hex::decode(VECTOR_TRANSACTION_BUILDER[0]).unwrap();
+    let mut build_object = AccountMetadataTransactionBuilder::from_binary(&bytes_vector);
+    build_object.set_scoped_metadata_key(ScopedMetadataKeyDto::from_str_key("kyc_level").unwrap());
+    assert_eq!(build_object.get_scoped_metadata_key(), 0xB592_FEF4_C6C0_B9DC);
+    build_object.set_scoped_metadata_key(10);
+    assert_eq!(build_object.get_scoped_metadata_key(), 10);
+
+    assert_eq!(build_object.set_scoped_metadata_key_str("kyc_level"), Some(()));
+    assert_eq!(build_object.get_scoped_metadata_key(), 0xB592_FEF4_C6C0_B9DC);
+    assert_eq!(build_object.set_scoped_metadata_key_str(""), None);
+    assert_eq!(build_object.get_scoped_metadata_key(), 0xB592_FEF4_C6C0_B9DC);
+}
@@ -19,7 +19,12 @@
  * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
  */
 
-use catbuffer_rust::aggregate_bonded_transaction_builder::AggregateBondedTransactionBuilder;
+use catbuffer_rust::{
+    aggregate_bonded_transaction_builder::AggregateBondedTransactionBuilder,
+    aggregate_transaction_body_builder::AggregateError,
+    detached_cosignature_builder::DetachedCosignatureBuilder, hash256_dto::Hash256Dto,
+    key_dto::KeyDto, key_pair::KeyPair,
+};
 
 const VECTOR_TRANSACTION_BUILDER: [&str; 33] = [
     "100100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000019041420A00000000000000010000000000000097EE38BB7E04C0C915F3B69B5D6CF77E04B893A86090E417A42660A073515E9C680000000000000062000000000000004871937A9B3872130EB65765BF5E0AE326C49ABB39A3F353711AE782D95FF2CB00000000019044429083025FF3A8AB5AD104631FB370F290004952CD1FDDC4C90A00000000000000E8030000000000000A000600313233414243000000000000",
@@ -64,4 +69,65 @@ fn test_should_create_transactions() {
         let build_object = AggregateBondedTransactionBuilder::from_binary(&*bytes_vector);
         assert_eq!(build_object.serializer(), bytes_vector);
     }
-}
\ No newline at end of file
+}
+#[test]
+fn test_should_attach_detached_cosignatures_sorted_by_signer() {
+    let generation_hash_seed = Hash256Dto([0x57; 32]);
+    let bytes_vector = hex::decode(VECTOR_TRANSACTION_BUILDER[1]).unwrap();
+    let mut build_object = AggregateBondedTransactionBuilder::from_binary(&bytes_vector);
+    let parent_hash = build_object.get_transaction_hash(&generation_hash_seed);
+
+    let key_pairs = [KeyPair::random(), KeyPair::random(), KeyPair::random()];
+    let cosignatures: Vec<DetachedCosignatureBuilder> = key_pairs.iter()
+        .map(|key_pair| DetachedCosignatureBuilder::create(key_pair, parent_hash))
+        .collect();
+    assert!(cosignatures.iter().all(|cosignature| cosignature.verify()));
+
+    build_object.add_cosignatures(&generation_hash_seed, &cosignatures[..2]).unwrap();
+    build_object.add_cosignatures(&generation_hash_seed, &cosignatures[2..]).unwrap();
+
+    let signers: Vec<KeyDto> = build_object.get_cosignatures().iter().map(|c| c.get_signer_public_key()).collect();
+    let mut expected_signers: Vec<KeyDto> = key_pairs.iter().map(|key_pair| key_pair.get_public_key()).collect();
+    expected_signers.sort();
+    assert_eq!(signers, expected_signers);
+    assert!(build_object.get_cosignatures().iter().all(|cosignature| cosignature.verify(&parent_hash)));
+
+    // cosignatures do not change the aggregate hash and survive a round trip.
+    assert_eq!(build_object.get_transaction_hash(&generation_hash_seed), parent_hash);
+    let serialized = build_object.serializer();
+    assert_eq!(AggregateBondedTransactionBuilder::from_binary(&serialized).serializer(), serialized);
+}
+
+#[test]
+fn test_should_reject_invalid_detached_cosignatures() {
+    let generation_hash_seed = Hash256Dto([0x57; 32]);
+    let bytes_vector = hex::decode(VECTOR_TRANSACTION_BUILDER[1]).unwrap();
+    let mut build_object = AggregateBondedTransactionBuilder::from_binary(&bytes_vector);
+    let parent_hash = build_object.get_transaction_hash(&generation_hash_seed);
+    let key_pair = KeyPair::random();
+    let cosignature = DetachedCosignatureBuilder::create(&key_pair, parent_hash);
+
+    // signs another aggregate.
+    let other = DetachedCosignatureBuilder::create(&key_pair, Hash256Dto([1; 32]));
+    assert_eq!(build_object.add_cosignatures(&generation_hash_seed, &[other]),
+               Err(AggregateError::ParentHashMismatch(key_pair.get_public_key())));
+
+    // tampered signature.
+    let mut bytes = cosignature.serializer();
+    bytes[8 + 32] ^= 0xFF;
+    let tampered = DetachedCosignatureBuilder::from_binary(&bytes);
+    assert!(!tampered.verify());
+    assert_eq!(build_object.add_cosignatures(&generation_hash_seed, &[tampered]),
+               Err(AggregateError::InvalidSignature(key_pair.get_public_key())));
+
+    // duplicate within the batch leaves the aggregate untouched.
+    assert_eq!(build_object.add_cosignatures(&generation_hash_seed, &[cosignature.clone(), cosignature.clone()]),
+               Err(AggregateError::DuplicateCosignature(key_pair.get_public_key())));
+    assert!(build_object.get_cosignatures().is_empty());
+
+    // duplicate of an attached cosignature.
+    build_object.add_cosignatures(&generation_hash_seed, std::slice::from_ref(&cosignature)).unwrap();
+    assert_eq!(build_object.add_cosignatures(&generation_hash_seed, &[cosignature]),
+               Err(AggregateError::DuplicateCosignature(key_pair.get_public_key())));
+    assert_eq!(build_object.get_cosignatures().len(), 1);
+}
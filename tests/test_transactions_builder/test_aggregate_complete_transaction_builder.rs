@@ -19,7 +19,13 @@
  * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
  */
 
-use catbuffer_rust::aggregate_complete_transaction_builder::AggregateCompleteTransactionBuilder;
+use catbuffer_rust::{
+    aggregate_complete_transaction_builder::AggregateCompleteTransactionBuilder,
+    aggregate_transaction_body_builder::AggregateError, cosignature_builder::CosignatureBuilder,
+    embedded_transaction_helper::EmbeddedTransactionHelper, entity_type_dto::EntityTypeDto,
+    hash256_dto::Hash256Dto, validation_config::ValidationConfig,
+    validation_failure::ValidationFailure,
+};
 
 const VECTOR_TRANSACTION_BUILDER: [&str; 17] = [
     "D0010000000000008C281CF19399A4CD7C97336B73F21D395BF296DB2FF8020A5BFDE51BD3314506C0C4BE23A625E71DEAE87E20E565B7684D5ECD7C941DF9847691834D19652C0830EC782177FFEFEE6B8C2B6C38BDFF7413A7872386D4B8A600E255DFD042090300000000019041410000000000000000010000000000000061A53A5CD380F63A506A1059FE2D13FC0DA712E4B39B217407ECECB5DB7DA60DC0000000000000005D00000000000000F7847D28C15F11FED0C16401DA9F1D3D67E5BE14DD00521CB293D13CD28F06A10000000001905441905969EEEC46B0CC6DD90A4910306425B7FCC458DF884F0B0D0000000000000000746573742D6D6573736167650000005D00000000000000BAC8F60B0467AFCDA153477D2446921543D3C2BEB5E964F26F9AA62D4FB0A916000000000190544190B0A565B66E6D10B8151AD76F7C404016C444C81CD9D5BD0D0000000000000000746573742D6D6573736167650000000000000000000000BAC8F60B0467AFCDA153477D2446921543D3C2BEB5E964F26F9AA62D4FB0A916677E32A0DA9F62FC71BD5728350EEF38BF4968AA052EAED678DEBD17CF099BF0C3A37C6FE4D585392411418A1892530B423DB7F791D17A44781B10EB398E4605",
@@ -48,4 +54,123 @@ fn test_should_create_transactions() {
         let build_object = AggregateCompleteTransactionBuilder::from_binary(&*bytes_vector);
         assert_eq!(build_object.serializer(), bytes_vector);
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Clone)]
+struct EmbeddedAggregate(Vec<u8>);
+
+impl EmbeddedTransactionHelper for EmbeddedAggregate {
+    fn box_clone(&self) -> Box<dyn EmbeddedTransactionHelper> {
+        Box::new(self.clone())
+    }
+
+    fn get_size(&self) -> usize {
+        self.0.len()
+    }
+
+    fn serializer(&self) -> Vec<u8> {
+        self.0.clone()
+    }
+}
+
+#[test]
+fn test_should_rebuild_transactions_hash_when_mutating_transactions() {
+    for vector in VECTOR_TRANSACTION_BUILDER.iter() {
+        let bytes_vector = hex::decode(vector).unwrap();
+        let expected = AggregateCompleteTransactionBuilder::from_binary(&bytes_vector);
+        let transactions = expected.get_transactions();
+        let cosignatures = expected.get_cosignatures();
+
+        let mut build_object = expected.clone();
+        while !build_object.get_transactions().is_empty() {
+            build_object.remove_transaction(0).unwrap();
+        }
+        assert_eq!(build_object.get_transactions_hash(), Hash256Dto([0; 32]));
+        assert!(build_object.get_cosignatures().is_empty());
+
+        for transaction in transactions.iter().rev() {
+            build_object.insert_transaction(0, transaction.clone()).unwrap();
+        }
+        assert_eq!(build_object.get_transactions_hash(), expected.get_transactions_hash());
+
+        build_object.set_cosignatures(cosignatures.clone()).unwrap();
+        assert_eq!(build_object.get_size(), bytes_vector.len());
+        let serialized = build_object.serializer();
+        let cosignatures_offset = bytes_vector.len() - cosignatures.len() * 104;
+        assert_eq!(serialized[..cosignatures_offset], bytes_vector[..cosignatures_offset]);
+        let signers: Vec<_> = build_object.get_cosignatures().iter().map(|c| c.get_signer_public_key()).collect();
+        assert!(signers.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
+
+#[test]
+fn test_should_keep_size_and_padding_when_replacing_transactions() {
+    let bytes_vector = hex::decode(VECTOR_TRANSACTION_BUILDER[0]).unwrap();
+    let mut build_object = AggregateCompleteTransactionBuilder::from_binary(&bytes_vector);
+    let transactions = build_object.get_transactions();
+    assert_eq!(transactions.len(), 2);
+
+    let replaced = build_object.replace_transaction(0, transactions[1].clone()).unwrap();
+    assert_eq!(replaced.serializer(), transactions[0].serializer());
+    build_object.push_transaction(transactions[0].clone()).unwrap();
+    assert_eq!(build_object.get_transactions().len(), 3);
+
+    let serialized = build_object.serializer();
+    assert_eq!(serialized.len(), build_object.get_size());
+    let reloaded = AggregateCompleteTransactionBuilder::from_binary(&serialized);
+    assert_eq!(reloaded.serializer(), serialized);
+    assert_eq!(reloaded.get_transactions_hash(), build_object.body.calculate_transactions_hash());
+
+    assert_eq!(build_object.remove_transaction(3).unwrap_err(), AggregateError::InvalidIndex(3));
+    assert_eq!(build_object.insert_transaction(4, transactions[0].clone()).unwrap_err(), AggregateError::InvalidIndex(4));
+}
+
+#[test]
+fn test_should_reject_nested_aggregates_and_duplicate_cosignatures() {
+    let bytes_vector = hex::decode(VECTOR_TRANSACTION_BUILDER[4]).unwrap();
+    let mut build_object = AggregateCompleteTransactionBuilder::from_binary(&bytes_vector);
+
+    let mut aggregate_bytes = build_object.get_transactions()[0].serializer();
+    aggregate_bytes[46..48].copy_from_slice(&0x4141u16.to_le_bytes());
+    let nested = Box::new(EmbeddedAggregate(aggregate_bytes));
+    assert_eq!(build_object.push_transaction(nested).unwrap_err(),
+               AggregateError::NestedAggregate(EntityTypeDto::AGGREGATE_COMPLETE_TRANSACTION));
+    assert_eq!(build_object.serializer(), bytes_vector);
+
+    let cosignature = build_object.get_cosignatures()[0].clone();
+    assert_eq!(build_object.set_cosignatures(vec![cosignature.clone(), cosignature.clone()]).unwrap_err(),
+               AggregateError::DuplicateCosignature(cosignature.get_signer_public_key()));
+    assert_eq!(build_object.get_cosignatures().len(), 2);
+
+    let signer_public_key = build_object.super_object.signer_public_key;
+    let signer_cosignature = CosignatureBuilder::from_binary(&[vec![0; 8], signer_public_key.0.to_vec(), vec![0; 64]].concat());
+    assert_eq!(build_object.set_cosignatures(vec![cosignature, signer_cosignature]).unwrap_err(),
+               AggregateError::DuplicateCosignature(signer_public_key));
+    assert_eq!(build_object.get_cosignatures().len(), 2);
+}
+
+#[test]
+fn test_should_validate_aggregate_and_inner_transactions() {
+    let bytes_vector = hex::decode(VECTOR_TRANSACTION_BUILDER[4]).unwrap();
+    let mut build_object = AggregateCompleteTransactionBuilder::from_binary(&bytes_vector);
+    let config = ValidationConfig { network: build_object.super_object.network, ..Default::default() };
+    assert_eq!(build_object.validate(&config), vec![]);
+
+    let mut aggregate_bytes = build_object.get_transactions()[0].serializer();
+    aggregate_bytes[46..48].copy_from_slice(&0x4141u16.to_le_bytes());
+    build_object.body.transactions.push(Box::new(EmbeddedAggregate(aggregate_bytes)));
+    let cosignature = build_object.get_cosignatures()[0].clone();
+    build_object.body.cosignatures.push(cosignature);
+    assert_eq!(build_object.validate(&config), vec![
+        ValidationFailure::Failure_Aggregate_Redundant_Cosignatures,
+        ValidationFailure::Failure_Aggregate_Nested_Aggregate,
+    ]);
+
+    build_object.body.transactions.clear();
+    build_object.body.cosignatures.clear();
+    let config = ValidationConfig::default();
+    assert_eq!(build_object.validate(&config), vec![
+        ValidationFailure::Failure_Core_Wrong_Network,
+        ValidationFailure::Failure_Aggregate_No_Transactions,
+    ]);
+}
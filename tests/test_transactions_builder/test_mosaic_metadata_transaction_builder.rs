@@ -19,7 +19,10 @@
  * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
  */
 
-use catbuffer_rust::mosaic_metadata_transaction_builder::MosaicMetadataTransactionBuilder;
+use catbuffer_rust::{
+    metadata_entry_builder::MetadataEntryBuilder,
+    mosaic_metadata_transaction_builder::MosaicMetadataTransactionBuilder,
+};
 
 const VECTOR_TRANSACTION_BUILDER: [&str; 2] = [
     "B6000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001A8444200000000000000002B0200000000000080D66C33420E5411995BACFCA2B28CF1C9F5DD7AB1A9C05CE8030000000000004CCCD78612DDF5CA01000A0000000000000000000000",
@@ -33,4 +36,20 @@ fn test_should_create_transactions() {
         let build_object = MosaicMetadataTransactionBuilder::from_binary(&*bytes_vector);
         assert_eq!(build_object.serializer(), bytes_vector);
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_should_set_value_update_from_entry() {
+    let entry = MetadataEntryBuilder::from_binary(&hex::decode("0100900E96DC85F6B24AC9C8DB5FFC59C35880C0B722C7A416A790FD35818960C7B18B72F49A5598FA9F712A354DB38EB0760A0000000000000068E0AE3A0168EDBD020B00536F6D6520537472696E67").unwrap());
+    let bytes_vector = hex::decode(VECTOR_TRANSACTION_BUILDER[1]).unwrap();
+    let mut build_object = MosaicMetadataTransactionBuilder::from_binary(&bytes_vector);
+
+    build_object.set_value_update(Some(&entry), b"Some").unwrap();
+    assert_eq!(build_object.get_value_size_delta(), (-7i16) as u16);
+    assert_eq!(build_object.get_value(), hex::decode("0000000020537472696E67").unwrap());
+    assert_eq!(build_object.get_value_update().apply(&entry.get_value().get_data()).unwrap(), b"Some".to_vec());
+
+    build_object.set_value_update(Some(&entry), b"Some String!").unwrap();
+    assert_eq!(build_object.get_value_size_delta(), 1);
+    assert_eq!(build_object.get_value(), hex::decode("000000000000000000000021").unwrap());
+}
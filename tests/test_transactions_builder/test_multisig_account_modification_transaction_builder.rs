@@ -19,7 +19,10 @@
  * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
  */
 
-use catbuffer_rust::multisig_account_modification_transaction_builder::MultisigAccountModificationTransactionBuilder;
+use catbuffer_rust::{
+    multisig_account_modification_transaction_builder::MultisigAccountModificationTransactionBuilder,
+    validation_config::ValidationConfig, validation_failure::ValidationFailure,
+};
 
 const VECTOR_TRANSACTION_BUILDER: [&str; 2] = [
     "B80000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000F6503F78FBF99544B906872DDB392F4BE707180D285E7919DBACEF2E9573B1E60000000001905541000000000000000001000000000000000102010100000000905ED2343582DFB4D14DC837BF18E3C9BE5271FF9B8A9EC1908760369DC78761E7EBCC6CFAEA44EE946ED0637B67EE55",
@@ -33,4 +36,21 @@ fn test_should_create_transactions() {
         let build_object = MultisigAccountModificationTransactionBuilder::from_binary(&*bytes_vector);
         assert_eq!(build_object.serializer(), bytes_vector);
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_should_validate_multisig_account_modification() {
+    let mut build_object = MultisigAccountModificationTransactionBuilder::from_binary(&hex::decode(VECTOR_TRANSACTION_BUILDER[0]).unwrap());
+    let config = ValidationConfig { network: build_object.super_object.network, ..Default::default() };
+    assert_eq!(build_object.validate(&config), vec![]);
+
+    let address = build_object.body.address_additions[0];
+    build_object.body.address_additions.push(address);
+    build_object.body.address_deletions.push(address);
+    build_object.body.min_approval_delta = (-26i8) as u8;
+    assert_eq!(build_object.validate(&config), vec![
+        ValidationFailure::Failure_Multisig_Account_In_Both_Sets,
+        ValidationFailure::Failure_Multisig_Redundant_Modification,
+        ValidationFailure::Failure_Multisig_Min_Setting_Out_Of_Range,
+    ]);
+}
@@ -19,7 +19,11 @@
  * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
  */
 
-use catbuffer_rust::namespace_registration_transaction_builder::NamespaceRegistrationTransactionBuilder;
+use catbuffer_rust::{
+    block_duration_dto::BlockDurationDto, namespace_id_dto::NamespaceIdDto,
+    namespace_registration_transaction_builder::NamespaceRegistrationTransactionBuilder,
+    validation_config::ValidationConfig, validation_failure::ValidationFailure,
+};
 
 const VECTOR_TRANSACTION_BUILDER: [&str; 3] = [
     "9E0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000F6503F78FBF99544B906872DDB392F4BE707180D285E7919DBACEF2E9573B1E60000000001904E410000000000000000010000000000000010270000000000007EE9B3B8AFDF53C0000C6E65776E616D657370616365",
@@ -34,4 +38,31 @@ fn test_should_create_transactions() {
         let build_object = NamespaceRegistrationTransactionBuilder::from_binary(&*bytes_vector);
         assert_eq!(build_object.serializer(), bytes_vector);
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_should_generate_namespace_ids() {
+    let symbol = NamespaceIdDto::from_name("symbol", None);
+    assert_eq!(symbol, NamespaceIdDto(0xA95F_1F8A_9615_9516));
+    assert_eq!(NamespaceIdDto::from_name("xym", Some(symbol)), NamespaceIdDto(0xE74B_99BA_41F4_AFEE));
+}
+
+#[test]
+fn test_should_validate_namespace_registration() {
+    let mut child = NamespaceRegistrationTransactionBuilder::from_binary(&hex::decode(VECTOR_TRANSACTION_BUILDER[1]).unwrap());
+    let config = ValidationConfig { network: child.super_object.network, ..Default::default() };
+    assert_eq!(child.validate(&config), vec![]);
+
+    child.body.name = b"other".to_vec();
+    assert_eq!(child.validate(&config), vec![ValidationFailure::Failure_Namespace_Name_Id_Mismatch]);
+    for name in [&b"Upper"[..], b"-dash", b"", b"dot.name", &[b'a'; 65]].iter() {
+        child.body.name = name.to_vec();
+        assert_eq!(child.validate(&config), vec![ValidationFailure::Failure_Namespace_Invalid_Name]);
+    }
+
+    let mut root = NamespaceRegistrationTransactionBuilder::from_binary(&hex::decode(VECTOR_TRANSACTION_BUILDER[2]).unwrap());
+    let config = ValidationConfig { network: root.super_object.network, ..Default::default() };
+    assert_eq!(root.validate(&config), vec![ValidationFailure::Failure_Namespace_Invalid_Duration]);
+    root.body.duration = Some(BlockDurationDto(config.min_namespace_duration));
+    assert_eq!(root.validate(&config), vec![]);
+}
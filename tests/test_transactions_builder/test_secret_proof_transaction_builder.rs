@@ -19,7 +19,11 @@
  * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
  */
 
-use catbuffer_rust::secret_proof_transaction_builder::SecretProofTransactionBuilder;
+use catbuffer_rust::{
+    lock_hash_algorithm_dto::LockHashAlgorithmDto,
+    secret_lock_transaction_builder::SecretLockTransactionBuilder,
+    secret_proof_transaction_builder::SecretProofTransactionBuilder,
+};
 
 const VECTOR_TRANSACTION_BUILDER: [&str; 2] = [
     "BF000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001905242000000000000000001000000000000009022D04812D05000F96C283657B0C17990932BC84926CDE63FC8BA10229AB5778D05D9C4B7F56676A88BF9295C185ACFC0F961DB5408CAFE0400009A493664",
@@ -33,4 +37,22 @@ fn test_should_create_transactions() {
         let build_object = SecretProofTransactionBuilder::from_binary(&*bytes_vector);
         assert_eq!(build_object.serializer(), bytes_vector);
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_should_match_secret_lock() {
+    const VECTOR_SECRET_LOCK: &str = "D1000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001A8524100000000000000002B02000000000000809D7AE580CA673E2365F9019C19A68FFE3F59F38910CD469B3155B37159DA50AA52D5967C509B410F5A36A3B1E31ECB5AC76675D79B4A5E44B262C46CEABB850A00000000000000640000000000000000";
+    let secret_lock = SecretLockTransactionBuilder::from_binary(&hex::decode(VECTOR_SECRET_LOCK).unwrap());
+    let mut build_object = SecretProofTransactionBuilder::from_binary(&hex::decode(VECTOR_TRANSACTION_BUILDER[1]).unwrap());
+    assert!(!build_object.matches(&secret_lock));
+
+    build_object.set_recipient_address(secret_lock.get_recipient_address());
+    assert!(build_object.matches(&secret_lock));
+
+    build_object.set_hash_algorithm(LockHashAlgorithmDto::HASH_256);
+    assert!(!build_object.matches(&secret_lock));
+
+    build_object.set_hash_algorithm(LockHashAlgorithmDto::SHA3_256);
+    build_object.set_proof(LockHashAlgorithmDto::generate_proof());
+    assert!(!build_object.matches(&secret_lock));
+}
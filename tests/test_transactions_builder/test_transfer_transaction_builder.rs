@@ -19,7 +19,11 @@
  * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
  */
 
-use catbuffer_rust::transfer_transaction_builder::TransferTransactionBuilder;
+use catbuffer_rust::{
+    key_dto::KeyDto, message_type_dto::MessageTypeDto, transfer_message::TransferMessage,
+    transfer_transaction_builder::TransferTransactionBuilder, validation_config::ValidationConfig,
+    validation_failure::ValidationFailure,
+};
 
 const VECTOR_TRANSACTION_BUILDER: [&str; 12] = [
     "C10000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000019054410000000000000000010000000000000090F36CA680C35D630662A0C38DC89D4978D10B511B3D241A010002000000000064000000000000000200000000000000C800000000000000010000000000000000",
@@ -44,3 +48,92 @@ fn test_should_create_transactions() {
         assert_eq!(build_object.serializer(), bytes_vector);
     }
 }
+
+#[test]
+fn test_should_decode_transfer_messages() {
+    for vector in VECTOR_TRANSACTION_BUILDER.iter() {
+        let bytes_vector = hex::decode(vector).unwrap();
+        let mut build_object = TransferTransactionBuilder::from_binary(&bytes_vector);
+        let message = build_object.get_transfer_message();
+        assert_eq!(message.serializer(), build_object.get_message());
+        build_object.set_transfer_message(&message);
+        assert_eq!(build_object.serializer(), bytes_vector);
+    }
+
+    let bytes_vector = hex::decode(VECTOR_TRANSACTION_BUILDER[1]).unwrap();
+    let message = TransferTransactionBuilder::from_binary(&bytes_vector).get_transfer_message();
+    assert_eq!(message.get_message_type(), Some(MessageTypeDto::PLAIN_MESSAGE));
+    assert_eq!(message.as_text(), Some("Some Message 漢字"));
+
+    let bytes_vector = hex::decode(VECTOR_TRANSACTION_BUILDER[7]).unwrap();
+    let message = TransferTransactionBuilder::from_binary(&bytes_vector).get_transfer_message();
+    assert_eq!(message, TransferMessage::Empty);
+}
+
+#[test]
+fn test_should_encode_transfer_messages() {
+    let bytes_vector = hex::decode(VECTOR_TRANSACTION_BUILDER[8]).unwrap();
+    let mut build_object = TransferTransactionBuilder::from_binary(&bytes_vector);
+    build_object.set_transfer_message(&TransferMessage::from_text("test-message"));
+    assert_eq!(build_object.get_message(), b"\0test-message".to_vec());
+    assert_eq!(build_object.get_size(), bytes_vector.len());
+
+    let encrypted = TransferMessage::Encrypted(b"0123456789ABCDEF".to_vec());
+    build_object.set_transfer_message(&encrypted);
+    assert_eq!(build_object.get_message()[0], 0x01);
+    assert_eq!(build_object.get_transfer_message(), encrypted);
+    assert_eq!(encrypted.as_text(), None);
+
+    let delegation = TransferMessage::PersistentHarvestingDelegation {
+        ephemeral_public_key: KeyDto([0x11; 32]),
+        encrypted_payload: vec![0x22; 92],
+    };
+    build_object.set_transfer_message(&delegation);
+    assert_eq!(hex::encode_upper(&build_object.get_message()[..8]), "FE2A8061577301E2");
+    assert_eq!(build_object.get_message().len(), 132);
+    assert_eq!(build_object.get_transfer_message(), delegation);
+
+    let invalid_text = TransferMessage::Plain(vec![0xFF, 0xFE]);
+    assert_eq!(invalid_text.as_text(), None);
+    let unknown = TransferMessage::from_binary(&[0xFE, 0x01, 0x02]);
+    assert_eq!(unknown, TransferMessage::Raw(vec![0xFE, 0x01, 0x02]));
+    assert_eq!(unknown.get_message_type(), None);
+}
+
+#[cfg(feature = "crypto")]
+#[test]
+fn test_should_set_and_decrypt_encrypted_message() {
+    use catbuffer_rust::key_pair::KeyPair;
+
+    let bytes_vector = hex::decode(VECTOR_TRANSACTION_BUILDER[8]).unwrap();
+    let mut build_object = TransferTransactionBuilder::from_binary(&bytes_vector);
+    let sender = KeyPair::random();
+    let recipient = KeyPair::random();
+    build_object.set_encrypted_message(&sender, &recipient.get_public_key(), b"secret").unwrap();
+    assert_eq!(build_object.get_transfer_message().get_message_type(), Some(MessageTypeDto::ENCRYPTED_MESSAGE));
+
+    let reloaded = TransferTransactionBuilder::from_binary(&build_object.serializer());
+    assert_eq!(reloaded.decrypt_message(&recipient, &sender.get_public_key()).unwrap(), b"secret".to_vec());
+}
+
+#[test]
+fn test_should_validate_transfer_transactions() {
+    let mut build_object = VECTOR_TRANSACTION_BUILDER.iter()
+        .map(|vector| TransferTransactionBuilder::from_binary(&hex::decode(vector).unwrap()))
+        .find(|build_object| build_object.get_mosaics().len() > 1)
+        .unwrap();
+    let config = ValidationConfig { network: build_object.super_object.network, ..Default::default() };
+    assert_eq!(build_object.validate(&config), vec![]);
+
+    build_object.body.mosaics.reverse();
+    build_object.set_message(vec![0; config.max_message_size + 1]);
+    assert_eq!(build_object.validate(&config), vec![
+        ValidationFailure::Failure_Transfer_Message_Too_Large,
+        ValidationFailure::Failure_Transfer_Out_Of_Order_Mosaics,
+    ]);
+
+    let mosaic = build_object.body.mosaics[0].clone();
+    build_object.body.mosaics = vec![mosaic.clone(), mosaic];
+    build_object.set_message(vec![]);
+    assert_eq!(build_object.validate(&config), vec![ValidationFailure::Failure_Transfer_Out_Of_Order_Mosaics]);
+}
@@ -19,7 +19,11 @@
  * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
  */
 
-use catbuffer_rust::voting_key_link_transaction_builder::VotingKeyLinkTransactionBuilder;
+use catbuffer_rust::{
+    finalization_epoch_dto::FinalizationEpochDto, validation_config::ValidationConfig,
+    validation_failure::ValidationFailure,
+    voting_key_link_transaction_builder::VotingKeyLinkTransactionBuilder,
+};
 
 const VECTOR_TRANSACTION_BUILDER: [&str; 1] = [
     "A9000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001A8434100000000000000002B02000000000000C614558647D02037384A2FECA80ACE95B235D9B9D90035FA46102FE79ECCBA75010000000300000001",
@@ -32,4 +36,16 @@ fn test_should_create_transactions() {
         let build_object = VotingKeyLinkTransactionBuilder::from_binary(&*bytes_vector);
         assert_eq!(build_object.serializer(), bytes_vector);
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_should_validate_voting_key_link_range() {
+    let mut build_object = VotingKeyLinkTransactionBuilder::from_binary(&hex::decode(VECTOR_TRANSACTION_BUILDER[0]).unwrap());
+    let config = ValidationConfig { network: build_object.super_object.network, ..Default::default() };
+    for (start_epoch, end_epoch, is_valid) in [(1, 112, true), (1, 360, true), (1, 111, false), (1, 361, false), (200, 1, false), (0, u32::MAX, false)].iter() {
+        build_object.body.start_epoch = FinalizationEpochDto(*start_epoch);
+        build_object.body.end_epoch = FinalizationEpochDto(*end_epoch);
+        let expected = if *is_valid { vec![] } else { vec![ValidationFailure::Failure_Core_Invalid_Link_Range] };
+        assert_eq!(build_object.validate(&config), expected);
+    }
+}
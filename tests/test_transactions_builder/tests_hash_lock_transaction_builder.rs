@@ -19,7 +19,12 @@
  * // along with Catapult. If not, see <http://www.gnu.org/licenses/>.
  */
 
-use catbuffer_rust::hash_lock_transaction_builder::HashLockTransactionBuilder;
+use catbuffer_rust::{
+    aggregate_bonded_transaction_builder::AggregateBondedTransactionBuilder,
+    block_duration_dto::BlockDurationDto, hash256_dto::Hash256Dto,
+    hash_lock_info_builder::HashLockInfoBuilder,
+    hash_lock_transaction_builder::HashLockTransactionBuilder,
+};
 
 const VECTOR_TRANSACTION_BUILDER: [&str; 1] = [
     "B800000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002134E47AEE6F2392A5B3D1238CD7714EABEB739361B7CCF24BAE127F10DF17F200000000019048410000000000000000010000000000000044B262C46CEABB85809698000000000064000000000000008498B38D89C1DC8A448EA5824938FF828926CD9F7747B1844B59B4B6807E878B"
@@ -32,4 +37,39 @@ fn test_should_create_transactions() {
         let build_object = HashLockTransactionBuilder::from_binary(&*bytes_vector);
         assert_eq!(build_object.serializer(), bytes_vector);
     }
-}
\ No newline at end of file
+}
+
+const VECTOR_AGGREGATE_BONDED: &str = "F00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000019041420A0000000000000001000000000000003856BB39656064ADA2971A0791F228069B1F2293DBF1344B687C8E743AEC6B23480000000000000041000000000000004E0184D19FD1D9427BB6888BDDC56756686829A1CAD6686D8D53339E9844CE000000000001904E43A487791451FDF1B60A000000000000000100000000000000";
+
+#[test]
+fn test_should_create_hash_lock_for_aggregate() {
+    let generation_hash_seed = Hash256Dto([0x57; 32]);
+    let aggregate = AggregateBondedTransactionBuilder::from_binary(&hex::decode(VECTOR_AGGREGATE_BONDED).unwrap());
+    let template = HashLockTransactionBuilder::from_binary(&hex::decode(VECTOR_TRANSACTION_BUILDER[0]).unwrap());
+    assert_eq!(template.get_mosaic().get_amount().get_amount(), HashLockTransactionBuilder::LOCKED_FUNDS_PER_AGGREGATE);
+
+    let build_object = HashLockTransactionBuilder::for_aggregate(&aggregate, &generation_hash_seed, template.get_mosaic(), BlockDurationDto(480));
+    assert_eq!(build_object.get_hash(), aggregate.get_transaction_hash(&generation_hash_seed));
+    assert_eq!(build_object.get_duration().get_block_duration(), 480);
+    assert_eq!(build_object.super_object.signer_public_key, aggregate.super_object.signer_public_key);
+    assert_eq!(build_object.super_object.network, aggregate.super_object.network);
+
+    let serialized = build_object.serializer();
+    assert_eq!(HashLockTransactionBuilder::from_binary(&serialized).serializer(), serialized);
+}
+
+#[test]
+fn test_should_match_hash_lock_info_with_aggregate() {
+    let generation_hash_seed = Hash256Dto([0x57; 32]);
+    let aggregate = AggregateBondedTransactionBuilder::from_binary(&hex::decode(VECTOR_AGGREGATE_BONDED).unwrap());
+    let hash = aggregate.get_transaction_hash(&generation_hash_seed);
+
+    // version, owner address, mosaic, end height and status precede the hash.
+    let header = hex::decode("0100984E5A3C57FEB2AA6D12B8CEE0EA85FA6BE5AE2D3E6E2A4D44B262C46CEABB858096980000000000E80300000000000000").unwrap();
+    let hash_lock_info = HashLockInfoBuilder::from_binary(&[header.clone(), hash.serializer()].concat());
+    assert!(hash_lock_info.matches(&aggregate, &generation_hash_seed));
+    assert!(!hash_lock_info.matches(&aggregate, &Hash256Dto([0x58; 32])));
+
+    let hash_lock_info = HashLockInfoBuilder::from_binary(&[header, vec![0u8; 32]].concat());
+    assert!(!hash_lock_info.matches(&aggregate, &generation_hash_seed));
+}